@@ -3,11 +3,13 @@ use std::collections::HashSet;
 use revolt_database::{
     events::client::{EventV1, ReadyPayloadFields},
     util::permissions::DatabasePermissionQuery,
-    Channel, Database, Member, MemberCompositeKey, Presence, RelationshipStatus,
+    voice::voice_state_model,
+    Category, Channel, Database, Member, MemberCompositeKey, PartialCategory, Presence,
+    RelationshipStatus, Server,
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
-use revolt_presence::filter_online;
+use revolt_presence::{filter_online, get_channel_voice_states};
 use revolt_result::Result;
 
 use super::state::{Cache, State};
@@ -115,7 +117,13 @@ impl State {
             .collect();
 
         let server_ids: Vec<String> = members.iter().map(|x| x.id.server.clone()).collect();
-        let servers = db.fetch_servers(&server_ids).await?;
+        let servers: Vec<Server> = db
+            .fetch_servers(&server_ids)
+            .await?
+            .into_iter()
+            // Servers pending deletion are hidden from everyone but their owner
+            .filter(|server| server.scheduled_deletion.is_none() || server.owner == user.id)
+            .collect();
         self.cache.servers = servers.iter().cloned().map(|x| (x.id.clone(), x)).collect();
 
         // Collect channel ids from servers.
@@ -186,6 +194,23 @@ impl State {
             None
         };
 
+        // Fetch voice states for calls we can see
+        let voice_states = if fields.contains(&ReadyPayloadFields::VoiceStates) {
+            let mut voice_states = vec![];
+            for channel in &channels {
+                if matches!(
+                    channel,
+                    Channel::VoiceChannel { .. } | Channel::Group { .. }
+                ) {
+                    voice_states.append(&mut get_channel_voice_states(channel.id()).await);
+                }
+            }
+
+            Some(voice_states)
+        } else {
+            None
+        };
+
         // Copy data into local state cache.
         self.cache.users = users.iter().cloned().map(|x| (x.id.clone(), x)).collect();
         self.cache
@@ -235,7 +260,21 @@ impl State {
                 None
             },
             servers: if fields.contains(&ReadyPayloadFields::Servers) {
-                Some(servers.into_iter().map(Into::into).collect())
+                let mut role_member_counts = db.fetch_role_member_counts(&server_ids).await?;
+                Some(
+                    servers
+                        .into_iter()
+                        .map(|server| {
+                            let counts = role_member_counts.remove(&server.id).unwrap_or_default();
+                            let mut server: v0::Server = server.into();
+                            for (role_id, role) in server.roles.iter_mut() {
+                                role.member_count = Some(counts.get(role_id).copied().unwrap_or(0));
+                            }
+
+                            server
+                        })
+                        .collect(),
+                )
             } else {
                 None
             },
@@ -253,6 +292,7 @@ impl State {
 
             user_settings,
             channel_unreads: channel_unreads.map(|vec| vec.into_iter().map(Into::into).collect()),
+            voice_states: voice_states.map(|vec| vec.into_iter().map(voice_state_model).collect()),
         })
     }
 
@@ -481,7 +521,7 @@ impl State {
                     server.apply_options(data.clone().into());
                 }
 
-                if data.default_permissions.is_some() {
+                if data.default_permissions.is_some() || data.categories.is_some() {
                     queue_server = Some(id.clone());
                 }
             }
@@ -552,6 +592,45 @@ impl State {
                     }
                 }
             }
+            EventV1::ServerCategoryUpdate {
+                id,
+                category_id,
+                data,
+                clear,
+            } => {
+                if let Some(server) = self.cache.servers.get_mut(id) {
+                    let categories = server.categories.get_or_insert_with(Vec::new);
+                    let partial: PartialCategory = data.clone().into();
+
+                    if let Some(category) = categories.iter_mut().find(|c| &c.id == category_id) {
+                        for field in clear {
+                            category.remove_field(&field.clone().into());
+                        }
+
+                        category.apply_options(partial);
+                    } else if let (Some(title), Some(channels)) = (partial.title, partial.channels)
+                    {
+                        categories.push(Category {
+                            id: category_id.clone(),
+                            title,
+                            channels,
+                            default_permissions: partial.default_permissions,
+                            role_permissions: partial.role_permissions.unwrap_or_default(),
+                        });
+                    }
+                }
+
+                queue_server = Some(id.clone());
+            }
+            EventV1::ServerCategoryDelete { id, category_id } => {
+                if let Some(server) = self.cache.servers.get_mut(id) {
+                    if let Some(categories) = &mut server.categories {
+                        categories.retain(|c| &c.id != category_id);
+                    }
+                }
+
+                queue_server = Some(id.clone());
+            }
             EventV1::ServerRoleDelete { id, role_id } => {
                 if let Some(server) = self.cache.servers.get_mut(id) {
                     server.roles.remove(role_id);
@@ -585,6 +664,17 @@ impl State {
                 }
             }
 
+            EventV1::Bulk { v } => {
+                let mut events = vec![];
+                for mut event in std::mem::take(v) {
+                    if Box::pin(self.handle_incoming_event_v1(db, &mut event)).await {
+                        events.push(event);
+                    }
+                }
+
+                *v = events;
+            }
+
             EventV1::Message(message) => {
                 // Since Message events are fanned out to many clients,
                 // we must reconstruct the relationship value at this end.
@@ -17,7 +17,7 @@ use redis_kiss::{PayloadType, REDIS_PAYLOAD_TYPE, REDIS_URI};
 use revolt_config::report_internal_error;
 use revolt_database::{
     events::{client::EventV1, server::ClientMessage},
-    Database, User, UserHint,
+    Database, Member, User, UserHint,
 };
 use revolt_presence::{create_session, delete_session};
 
@@ -177,6 +177,9 @@ pub async fn client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
     // If this was the last session, notify other users that we just went offline.
     if last_session {
         state.broadcast_presence_change(false).await;
+
+        // Drop any temporary memberships now that the user is gone.
+        report_internal_error!(Member::remove_temporary_memberships(db, &user_id).await).ok();
     }
 }
 
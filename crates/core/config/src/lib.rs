@@ -105,8 +105,6 @@ pub struct Hosts {
     pub events: String,
     pub autumn: String,
     pub january: String,
-    pub voso_legacy: String,
-    pub voso_legacy_ws: String,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -165,11 +163,25 @@ pub struct ApiSecurityCaptcha {
 #[derive(Deserialize, Debug, Clone)]
 pub struct ApiSecurity {
     pub authifier_shield_key: String,
-    pub voso_legacy_token: String,
     pub captcha: ApiSecurityCaptcha,
     pub trust_cloudflare: bool,
 }
 
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApiVoiceNode {
+    pub url: String,
+    pub key: String,
+    pub secret: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApiVoice {
+    pub token_ttl: u64,
+    pub default_region: String,
+    #[serde(default)]
+    pub nodes: HashMap<String, ApiVoiceNode>,
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct ApiWorkers {
     pub max_concurrent_connections: usize,
@@ -180,6 +192,7 @@ pub struct Api {
     pub registration: ApiRegistration,
     pub smtp: ApiSmtp,
     pub security: ApiSecurity,
+    pub voice: ApiVoice,
     pub workers: ApiWorkers,
 }
 
@@ -300,12 +313,18 @@ pub struct FeaturesLimitsCollection {
 pub struct FeaturesAdvanced {
     #[serde(default)]
     pub process_message_delay_limit: u16,
+    #[serde(default)]
+    pub audit_log_retention_days: u64,
+    #[serde(default)]
+    pub server_deletion_grace_days: u64,
 }
 
 impl Default for FeaturesAdvanced {
     fn default() -> Self {
         Self {
             process_message_delay_limit: 5,
+            audit_log_retention_days: 90,
+            server_deletion_grace_days: 14,
         }
     }
 }
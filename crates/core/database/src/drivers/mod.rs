@@ -97,6 +97,16 @@ impl DatabaseInfo {
 }
 
 impl Database {
+    /// Create an Authifier database handle without building its configuration
+    pub fn to_authifier_database(&self) -> authifier::Database {
+        match self {
+            Database::Reference(_) => Default::default(),
+            Database::MongoDb(MongoDb(client, _)) => authifier::Database::MongoDb(
+                authifier::database::MongoDb(client.database("revolt")),
+            ),
+        }
+    }
+
     /// Create an Authifier reference
     pub async fn to_authifier(self) -> Authifier {
         let config = config().await;
@@ -168,12 +178,7 @@ impl Database {
         }
 
         Authifier {
-            database: match self {
-                Database::Reference(_) => Default::default(),
-                Database::MongoDb(MongoDb(client, _)) => authifier::Database::MongoDb(
-                    authifier::database::MongoDb(client.database("revolt")),
-                ),
-            },
+            database: self.to_authifier_database(),
             config: auth_config,
             event_channel: Some(crate::tasks::authifier_relay::sender()),
         }
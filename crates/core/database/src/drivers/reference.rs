@@ -3,15 +3,18 @@ use std::{collections::HashMap, sync::Arc};
 use futures::lock::Mutex;
 
 use crate::{
-    Bot, Channel, ChannelCompositeKey, ChannelUnread, Emoji, File, FileHash, Invite, Member,
-    MemberCompositeKey, Message, RatelimitEvent, Report, Server, ServerBan, Snapshot, User,
-    UserSettings, Webhook,
+    AuditLogEntry, AutomodRule, Bot, Channel, ChannelCompositeKey, ChannelUnread, Emoji, File,
+    FileHash, Invite, Member, MemberCompositeKey, Message, ModerationRecord, NotificationSettings,
+    PruneJob, RatelimitEvent, Report, RoleAssignmentJob, Server, ServerBan, ServerEvent,
+    ServerInsights, ServerTemplate, Snapshot, User, UserSettings, Webhook,
 };
 
 database_derived!(
     /// Reference implementation
     #[derive(Default)]
     pub struct ReferenceDb {
+        pub audit_logs: Arc<Mutex<HashMap<String, AuditLogEntry>>>,
+        pub automod_rules: Arc<Mutex<HashMap<String, AutomodRule>>>,
         pub bots: Arc<Mutex<HashMap<String, Bot>>>,
         pub channels: Arc<Mutex<HashMap<String, Channel>>>,
         pub channel_invites: Arc<Mutex<HashMap<String, Invite>>>,
@@ -21,11 +24,18 @@ database_derived!(
         pub file_hashes: Arc<Mutex<HashMap<String, FileHash>>>,
         pub files: Arc<Mutex<HashMap<String, File>>>,
         pub messages: Arc<Mutex<HashMap<String, Message>>>,
+        pub moderation_records: Arc<Mutex<HashMap<String, ModerationRecord>>>,
+        pub notification_settings: Arc<Mutex<HashMap<String, NotificationSettings>>>,
+        pub prune_jobs: Arc<Mutex<HashMap<String, PruneJob>>>,
         pub ratelimit_events: Arc<Mutex<HashMap<String, RatelimitEvent>>>,
+        pub role_assignment_jobs: Arc<Mutex<HashMap<String, RoleAssignmentJob>>>,
         pub user_settings: Arc<Mutex<HashMap<String, UserSettings>>>,
         pub users: Arc<Mutex<HashMap<String, User>>>,
         pub server_bans: Arc<Mutex<HashMap<MemberCompositeKey, ServerBan>>>,
+        pub server_events: Arc<Mutex<HashMap<String, ServerEvent>>>,
+        pub server_insights: Arc<Mutex<HashMap<String, ServerInsights>>>,
         pub server_members: Arc<Mutex<HashMap<MemberCompositeKey, Member>>>,
+        pub server_templates: Arc<Mutex<HashMap<String, ServerTemplate>>>,
         pub servers: Arc<Mutex<HashMap<String, Server>>>,
         pub safety_reports: Arc<Mutex<HashMap<String, Report>>>,
         pub safety_snapshots: Arc<Mutex<HashMap<String, Snapshot>>>,
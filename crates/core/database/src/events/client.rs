@@ -3,10 +3,12 @@ use revolt_result::Error;
 use serde::{Deserialize, Serialize};
 
 use revolt_models::v0::{
-    AppendMessage, Channel, ChannelUnread, Emoji, FieldsChannel, FieldsMember, FieldsMessage,
-    FieldsRole, FieldsServer, FieldsUser, FieldsWebhook, Member, MemberCompositeKey, Message,
-    PartialChannel, PartialMember, PartialMessage, PartialRole, PartialServer, PartialUser,
-    PartialWebhook, RemovalIntention, Report, Server, User, UserSettings, Webhook,
+    AppendMessage, Channel, ChannelUnread, Emoji, FieldsCategory, FieldsChannel, FieldsMember,
+    FieldsMessage, FieldsRole, FieldsServer, FieldsServerEvent, FieldsUser, FieldsWebhook, Member,
+    MemberCompositeKey, Message, NotificationSettings, PartialCategory, PartialChannel,
+    PartialMember, PartialMessage, PartialRole, PartialServer, PartialServerEvent, PartialUser,
+    PartialWebhook, RemovalIntention, Report, Server, ServerEvent, User, UserSettings, VoiceState,
+    Webhook,
 };
 
 use crate::Database;
@@ -30,6 +32,7 @@ pub enum ReadyPayloadFields {
 
     UserSettings(Vec<String>),
     ChannelUnreads,
+    VoiceStates,
 }
 
 /// Protocol Events
@@ -62,6 +65,8 @@ pub enum EventV1 {
         user_settings: Option<UserSettings>,
         #[serde(skip_serializing_if = "Option::is_none")]
         channel_unreads: Option<Vec<ChannelUnread>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        voice_states: Option<Vec<VoiceState>>,
     },
 
     /// Ping response
@@ -151,6 +156,9 @@ pub enum EventV1 {
         reason: RemovalIntention,
     },
 
+    /// User ban lifted
+    ServerMemberUnban { id: String, user: String },
+
     /// Server role created or updated
     ServerRoleUpdate {
         id: String,
@@ -163,6 +171,47 @@ pub enum EventV1 {
     /// Server role deleted
     ServerRoleDelete { id: String, role_id: String },
 
+    /// Server category created or updated
+    ServerCategoryUpdate {
+        id: String,
+        category_id: String,
+        data: PartialCategory,
+        #[serde(default)]
+        clear: Vec<FieldsCategory>,
+    },
+
+    /// Server category deleted
+    ServerCategoryDelete { id: String, category_id: String },
+
+    /// New scheduled server event
+    ServerEventCreate(ServerEvent),
+
+    /// Update existing scheduled server event
+    ServerEventUpdate {
+        id: String,
+        server: String,
+        data: PartialServerEvent,
+        #[serde(default)]
+        clear: Vec<FieldsServerEvent>,
+    },
+
+    /// Delete scheduled server event
+    ServerEventDelete { id: String, server: String },
+
+    /// User marked themselves as interested in a server event
+    ServerEventInterestAdd {
+        id: String,
+        server: String,
+        user: String,
+    },
+
+    /// User is no longer interested in a server event
+    ServerEventInterestRemove {
+        id: String,
+        server: String,
+        user: String,
+    },
+
     /// Update existing user
     UserUpdate {
         id: String,
@@ -176,6 +225,11 @@ pub enum EventV1 {
     UserRelationship { id: String, user: User },
     /// Settings updated remotely
     UserSettingsUpdate { id: String, update: UserSettings },
+    /// Notification settings updated
+    UserNotificationSettingsUpdate {
+        id: String,
+        settings: NotificationSettings,
+    },
 
     /// User has been platform banned or deleted their account
     ///
@@ -228,6 +282,16 @@ pub enum EventV1 {
         message_id: String,
     },
 
+    /// User joined, left or changed state in a call
+    ///
+    /// State is omitted if the user left the call.
+    VoiceStateUpdate {
+        id: String,
+        user: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        state: Option<VoiceState>,
+    },
+
     /// New webhook
     WebhookCreate(Webhook),
 
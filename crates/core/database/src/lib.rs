@@ -104,6 +104,7 @@ pub use models::*;
 
 pub mod events;
 pub mod tasks;
+pub mod voice;
 
 mod amqp;
 pub use amqp::amqp::AMQP;
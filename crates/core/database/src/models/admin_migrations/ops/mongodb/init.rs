@@ -84,6 +84,42 @@ pub async fn create_database(db: &MongoDb) {
         .await
         .expect("Failed to create ratelimit_events collection.");
 
+    db.create_collection("server_templates", None)
+        .await
+        .expect("Failed to create server_templates collection.");
+
+    db.create_collection("audit_logs", None)
+        .await
+        .expect("Failed to create audit_logs collection.");
+
+    db.create_collection("automod_rules", None)
+        .await
+        .expect("Failed to create automod_rules collection.");
+
+    db.create_collection("server_insights", None)
+        .await
+        .expect("Failed to create server_insights collection.");
+
+    db.create_collection("moderation_records", None)
+        .await
+        .expect("Failed to create moderation_records collection.");
+
+    db.create_collection("server_events", None)
+        .await
+        .expect("Failed to create server_events collection.");
+
+    db.create_collection("notification_settings", None)
+        .await
+        .expect("Failed to create notification_settings collection.");
+
+    db.create_collection("role_assignment_jobs", None)
+        .await
+        .expect("Failed to create role_assignment_jobs collection.");
+
+    db.create_collection("prune_jobs", None)
+        .await
+        .expect("Failed to create prune_jobs collection.");
+
     db.create_collection(
         "pubsub",
         CreateCollectionOptions::builder()
@@ -283,5 +319,290 @@ pub async fn create_database(db: &MongoDb) {
     .await
     .expect("Failed to create ratelimit_events index.");
 
+    db.run_command(
+        doc! {
+            "createIndexes": "server_templates",
+            "indexes": [
+                {
+                    "key": {
+                        "server_id": 1_i32
+                    },
+                    "name": "server_id"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server_templates index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "audit_logs",
+            "indexes": [
+                {
+                    "key": {
+                        "server": 1_i32,
+                        "_id": -1_i32
+                    },
+                    "name": "server"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create audit_logs index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "automod_rules",
+            "indexes": [
+                {
+                    "key": {
+                        "server": 1_i32
+                    },
+                    "name": "server"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create automod_rules index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "servers",
+            "indexes": [
+                {
+                    "key": {
+                        "discoverable": 1_i32,
+                        "featured": 1_i32
+                    },
+                    "name": "discovery"
+                },
+                {
+                    "key": {
+                        "discoverable": 1_i32,
+                        "member_count": -1_i32
+                    },
+                    "name": "popularity"
+                },
+                {
+                    "key": {
+                        "name": "text",
+                        "description": "text",
+                        "tags": "text"
+                    },
+                    "name": "search"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create servers index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "bots",
+            "indexes": [
+                {
+                    "key": {
+                        "public": 1_i32,
+                        "discoverable": 1_i32
+                    },
+                    "name": "directory"
+                },
+                {
+                    "key": {
+                        "verification": 1_i32
+                    },
+                    "name": "verification"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create bots indexes.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_insights",
+            "indexes": [
+                {
+                    "key": {
+                        "server": 1_i32,
+                        "day": 1_i32
+                    },
+                    "name": "server"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server_insights index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_bans",
+            "indexes": [
+                {
+                    "key": {
+                        "_id.server": 1_i32,
+                        "_id.user": 1_i32
+                    },
+                    "name": "compound_id"
+                },
+                {
+                    "key": {
+                        "expires_at": 1_i32
+                    },
+                    "name": "expires_at",
+                    "sparse": true
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server_bans indexes.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "moderation_records",
+            "indexes": [
+                {
+                    "key": {
+                        "server": 1_i32,
+                        "user": 1_i32,
+                        "_id": -1_i32
+                    },
+                    "name": "member"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create moderation_records index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_members",
+            "indexes": [
+                {
+                    "key": {
+                        "timeout": 1_i32
+                    },
+                    "name": "timeout",
+                    "sparse": true
+                },
+                {
+                    "key": {
+                        "_id.server": 1_i32,
+                        "roles": 1_i32
+                    },
+                    "name": "roles"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server_members indexes.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_events",
+            "indexes": [
+                {
+                    "key": {
+                        "server": 1_i32,
+                        "starts_at": 1_i32
+                    },
+                    "name": "server"
+                },
+                {
+                    "key": {
+                        "reminded": 1_i32,
+                        "starts_at": 1_i32
+                    },
+                    "name": "reminder"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server_events indexes.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "notification_settings",
+            "indexes": [
+                {
+                    "key": {
+                        "servers.$**": 1_i32
+                    },
+                    "name": "servers"
+                },
+                {
+                    "key": {
+                        "channels.$**": 1_i32
+                    },
+                    "name": "channels"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create notification_settings indexes.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "role_assignment_jobs",
+            "indexes": [
+                {
+                    "key": {
+                        "server": 1_i32
+                    },
+                    "name": "server"
+                },
+                {
+                    "key": {
+                        "status": 1_i32,
+                        "updated_at": 1_i32
+                    },
+                    "name": "progress"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create role_assignment_jobs indexes.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "prune_jobs",
+            "indexes": [
+                {
+                    "key": {
+                        "status": 1_i32
+                    },
+                    "name": "status"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create prune_jobs indexes.");
+
     info!("Created database.");
 }
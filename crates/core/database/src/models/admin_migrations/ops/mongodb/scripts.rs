@@ -21,7 +21,7 @@ struct MigrationInfo {
     revision: i32,
 }
 
-pub const LATEST_REVISION: i32 = 31;
+pub const LATEST_REVISION: i32 = 49;
 
 pub async fn migrate_database(db: &MongoDb) {
     let migrations = db.col::<Document>("migrations");
@@ -1039,6 +1039,11 @@ pub async fn run_migrations(db: &MongoDb, revision: i32) -> i32 {
                     server,
                     creator,
                     channel,
+                    max_uses: None,
+                    expires_at: None,
+                    temporary: false,
+                    uses: 0,
+                    vanity: false,
                 },
                 OldInvite::Group {
                     code,
@@ -1048,6 +1053,9 @@ pub async fn run_migrations(db: &MongoDb, revision: i32) -> i32 {
                     code,
                     creator,
                     channel,
+                    max_uses: None,
+                    expires_at: None,
+                    uses: 0,
                 },
             })
             .collect::<Vec<Invite>>();
@@ -1227,6 +1235,544 @@ pub async fn run_migrations(db: &MongoDb, revision: i32) -> i32 {
         }
     }
 
+    if revision <= 32 {
+        info!("Running migration [revision 32 / 18-10-2026]: Add collection `server_templates`.");
+
+        db.db()
+            .create_collection("server_templates", None)
+            .await
+            .ok();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_templates",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server_id": 1_i32
+                            },
+                            "name": "server_id"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_templates index.");
+    }
+
+    if revision <= 33 {
+        info!("Running migration [revision 33 / 18-10-2026]: Add collection `audit_logs`.");
+
+        db.db().create_collection("audit_logs", None).await.ok();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "audit_logs",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server": 1_i32,
+                                "_id": -1_i32
+                            },
+                            "name": "server"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create audit_logs index.");
+    }
+
+    if revision <= 34 {
+        info!("Running migration [revision 34 / 18-10-2026]: Add collection `automod_rules`.");
+
+        db.db().create_collection("automod_rules", None).await.ok();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "automod_rules",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server": 1_i32
+                            },
+                            "name": "server"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create automod_rules index.");
+    }
+
+    if revision <= 35 {
+        info!("Running migration [revision 35 / 18-10-2026]: Add discovery index to `servers`.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "servers",
+                    "indexes": [
+                        {
+                            "key": {
+                                "discoverable": 1_i32,
+                                "featured": 1_i32
+                            },
+                            "name": "discovery"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create servers discovery index.");
+    }
+
+    if revision <= 36 {
+        info!("Running migration [revision 36 / 18-10-2026]: Add directory indexes to `bots`.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "bots",
+                    "indexes": [
+                        {
+                            "key": {
+                                "public": 1_i32,
+                                "discoverable": 1_i32
+                            },
+                            "name": "directory"
+                        },
+                        {
+                            "key": {
+                                "verification": 1_i32
+                            },
+                            "name": "verification"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create bots indexes.");
+    }
+
+    if revision <= 37 {
+        info!("Running migration [revision 37 / 18-10-2026]: Add collection `server_insights`.");
+
+        db.db()
+            .create_collection("server_insights", None)
+            .await
+            .ok();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_insights",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server": 1_i32,
+                                "day": 1_i32
+                            },
+                            "name": "server"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_insights index.");
+    }
+
+    if revision <= 38 {
+        info!("Running migration [revision 38 / 19-10-2026]: Add indexes to `server_bans`.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_bans",
+                    "indexes": [
+                        {
+                            "key": {
+                                "_id.server": 1_i32,
+                                "_id.user": 1_i32
+                            },
+                            "name": "compound_id"
+                        },
+                        {
+                            "key": {
+                                "expires_at": 1_i32
+                            },
+                            "name": "expires_at",
+                            "sparse": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_bans indexes.");
+    }
+
+    if revision <= 39 {
+        info!("Running migration [revision 39 / 19-10-2026]: Add collection `moderation_records` and timeout index to `server_members`.");
+
+        db.db()
+            .create_collection("moderation_records", None)
+            .await
+            .ok();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "moderation_records",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server": 1_i32,
+                                "user": 1_i32,
+                                "_id": -1_i32
+                            },
+                            "name": "member"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create moderation_records index.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_members",
+                    "indexes": [
+                        {
+                            "key": {
+                                "timeout": 1_i32
+                            },
+                            "name": "timeout",
+                            "sparse": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_members timeout index.");
+    }
+
+    if revision <= 40 {
+        info!("Running migration [revision 40 / 19-10-2026]: Add role index to `server_members`.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_members",
+                    "indexes": [
+                        {
+                            "key": {
+                                "_id.server": 1_i32,
+                                "roles": 1_i32
+                            },
+                            "name": "roles"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_members roles index.");
+    }
+
+    if revision <= 41 {
+        info!("Running migration [revision 41 / 19-10-2026]: Add collection `server_events`.");
+
+        db.db().create_collection("server_events", None).await.ok();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_events",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server": 1_i32,
+                                "starts_at": 1_i32
+                            },
+                            "name": "server"
+                        },
+                        {
+                            "key": {
+                                "reminded": 1_i32
+                            },
+                            "name": "reminded"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_events indexes.");
+    }
+
+    if revision <= 42 {
+        info!(
+            "Running migration [revision 42 / 19-10-2026]: Add collection `notification_settings`."
+        );
+
+        db.db()
+            .create_collection("notification_settings", None)
+            .await
+            .ok();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "notification_settings",
+                    "indexes": [
+                        {
+                            "key": {
+                                "servers.$**": 1_i32
+                            },
+                            "name": "servers"
+                        },
+                        {
+                            "key": {
+                                "channels.$**": 1_i32
+                            },
+                            "name": "channels"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create notification_settings indexes.");
+    }
+
+    if revision <= 43 {
+        info!(
+            "Running migration [revision 43 / 19-10-2026]: Add collection `role_assignment_jobs`."
+        );
+
+        db.db()
+            .create_collection("role_assignment_jobs", None)
+            .await
+            .ok();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "role_assignment_jobs",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server": 1_i32
+                            },
+                            "name": "server"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create role_assignment_jobs indexes.");
+    }
+
+    if revision <= 44 {
+        info!("Running migration [revision 44 / 19-10-2026]: Add search index to `servers`.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "servers",
+                    "indexes": [
+                        {
+                            "key": {
+                                "name": "text",
+                                "description": "text",
+                                "tags": "text"
+                            },
+                            "name": "search"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create servers search index.");
+    }
+
+    if revision <= 45 {
+        info!(
+            "Running migration [revision 45 / 19-10-2026]: Index `server_events` by reminder time."
+        );
+
+        db.db()
+            .run_command(
+                doc! {
+                    "dropIndexes": "server_events",
+                    "index": ["reminded"]
+                },
+                None,
+            )
+            .await
+            .ok();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_events",
+                    "indexes": [
+                        {
+                            "key": {
+                                "reminded": 1_i32,
+                                "starts_at": 1_i32
+                            },
+                            "name": "reminder"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_events indexes.");
+    }
+
+    if revision <= 46 {
+        info!("Running migration [revision 46 / 19-10-2026]: Store member counts on servers.");
+
+        db.col::<Document>("server_members")
+            .aggregate(
+                vec![
+                    doc! {
+                        "$group": {
+                            "_id": "$_id.server",
+                            "member_count": {
+                                "$sum": 1_i32
+                            }
+                        }
+                    },
+                    doc! {
+                        "$merge": {
+                            "into": "servers",
+                            "on": "_id",
+                            "whenMatched": "merge",
+                            "whenNotMatched": "discard"
+                        }
+                    },
+                ],
+                None,
+            )
+            .await
+            .expect("Failed to count server members.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "servers",
+                    "indexes": [
+                        {
+                            "key": {
+                                "discoverable": 1_i32,
+                                "member_count": -1_i32
+                            },
+                            "name": "popularity"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create servers indexes.");
+    }
+
+    if revision <= 47 {
+        info!("Running migration [revision 47 / 19-10-2026]: Store server counts on bots.");
+
+        db.col::<Document>("server_members")
+            .aggregate(
+                vec![
+                    doc! {
+                        "$group": {
+                            "_id": "$_id.user",
+                            "server_count": {
+                                "$sum": 1_i32
+                            }
+                        }
+                    },
+                    doc! {
+                        "$merge": {
+                            "into": "bots",
+                            "on": "_id",
+                            "whenMatched": "merge",
+                            "whenNotMatched": "discard"
+                        }
+                    },
+                ],
+                None,
+            )
+            .await
+            .expect("Failed to count bot servers.");
+    }
+
+    if revision <= 48 {
+        info!("Running migration [revision 48 / 19-10-2026]: Add collection `prune_jobs`.");
+
+        db.db().create_collection("prune_jobs", None).await.ok();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "prune_jobs",
+                    "indexes": [
+                        {
+                            "key": {
+                                "status": 1_i32
+                            },
+                            "name": "status"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create prune_jobs indexes.");
+    }
+
+    if revision <= 49 {
+        info!(
+            "Running migration [revision 49 / 19-10-2026]: Index `role_assignment_jobs` by progress."
+        );
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "role_assignment_jobs",
+                    "indexes": [
+                        {
+                            "key": {
+                                "status": 1_i32,
+                                "updated_at": 1_i32
+                            },
+                            "name": "progress"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create role_assignment_jobs indexes.");
+    }
+
     // Reminder to update LATEST_REVISION when adding new migrations.
     LATEST_REVISION.max(revision)
 }
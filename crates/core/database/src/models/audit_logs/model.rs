@@ -0,0 +1,265 @@
+use std::collections::BTreeSet;
+
+use revolt_config::report_internal_error;
+use serde::Serialize;
+use serde_json::Value;
+use ulid::Ulid;
+
+use crate::Database;
+
+/// Fields which should never be recorded in the audit log
+static REDACTED_FIELDS: [&str; 2] = ["_id", "token"];
+
+auto_derived!(
+    /// Audit Log Entry
+    pub struct AuditLogEntry {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server this entry belongs to
+        pub server: String,
+        /// Id of the user who performed the action
+        pub actor: String,
+        /// Action that was performed
+        pub action: AuditLogAction,
+        /// Id of the object the action was performed on
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub target: Option<String>,
+        /// Fields changed by this action
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub changes: Vec<AuditLogChange>,
+        /// Reason given for this action
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+    }
+
+    /// Change made to a single field
+    pub struct AuditLogChange {
+        /// Name of the field
+        pub key: String,
+        /// JSON encoded value before the change
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub before: Option<String>,
+        /// JSON encoded value after the change
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub after: Option<String>,
+    }
+
+    /// Action recorded in the audit log
+    pub enum AuditLogAction {
+        ServerUpdate,
+        ChannelCreate,
+        ChannelUpdate,
+        ChannelDelete,
+        CategoryCreate,
+        CategoryUpdate,
+        CategoryDelete,
+        RoleCreate,
+        RoleUpdate,
+        RoleDelete,
+        RoleAssignmentCreate,
+        MemberUpdate,
+        MemberKick,
+        MemberBan,
+        MemberUnban,
+        MemberPrune,
+        InviteDelete,
+        WebhookCreate,
+        WebhookUpdate,
+        WebhookDelete,
+        EmojiCreate,
+        EmojiDelete,
+        AutomodRuleCreate,
+        AutomodRuleUpdate,
+        AutomodRuleDelete,
+        VanityInviteUpdate,
+        VanityInviteDelete,
+        ServerEventCreate,
+        ServerEventUpdate,
+        ServerEventDelete,
+    }
+);
+
+/// Filter used when fetching audit log entries
+#[derive(Default)]
+pub struct AuditLogQuery {
+    /// Only include entries performed by this user
+    pub actor: Option<String>,
+    /// Only include entries of this type
+    pub action: Option<AuditLogAction>,
+    /// Only include entries targeting this object
+    pub target: Option<String>,
+    /// Only include entries created before this id
+    pub before: Option<String>,
+    /// Only include entries created after this id
+    pub after: Option<String>,
+    /// Maximum number of entries to fetch
+    pub limit: Option<i64>,
+}
+
+impl AuditLogEntry {
+    /// Record a new entry in a server's audit log
+    ///
+    /// Failures are reported but never interrupt the action being recorded.
+    pub async fn record(
+        db: &Database,
+        server: &str,
+        actor: &str,
+        action: AuditLogAction,
+        target: Option<&str>,
+        changes: Vec<AuditLogChange>,
+        reason: Option<String>,
+    ) {
+        let entry = AuditLogEntry {
+            id: Ulid::new().to_string(),
+            server: server.to_string(),
+            actor: actor.to_string(),
+            action,
+            target: target.map(|target| target.to_string()),
+            changes,
+            reason,
+        };
+
+        report_internal_error!(db.insert_audit_log_entry(&entry).await).ok();
+    }
+}
+
+impl AuditLogChange {
+    /// Compute which top-level fields differ between two serialisable values
+    ///
+    /// Pass `None` for `before` or `after` when an object is created or deleted.
+    pub fn diff<T: Serialize>(before: Option<&T>, after: Option<&T>) -> Vec<AuditLogChange> {
+        let to_fields = |value: Option<&T>| match value.map(serde_json::to_value) {
+            Some(Ok(Value::Object(fields))) => fields,
+            _ => Default::default(),
+        };
+
+        let before = to_fields(before);
+        let after = to_fields(after);
+
+        before
+            .keys()
+            .chain(after.keys())
+            .filter(|key| !REDACTED_FIELDS.contains(&key.as_str()))
+            .collect::<BTreeSet<&String>>()
+            .into_iter()
+            .filter(|key| before.get(*key) != after.get(*key))
+            .map(|key| AuditLogChange {
+                key: key.to_string(),
+                before: before.get(key).map(Value::to_string),
+                after: after.get(key).map(Value::to_string),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fixture, AuditLogAction, AuditLogChange, AuditLogEntry, AuditLogQuery, Role};
+
+    #[test]
+    fn diff_only_includes_changed_fields() {
+        let before = Role {
+            name: "Moderator".to_string(),
+            permissions: Default::default(),
+            colour: None,
+            hoist: false,
+            rank: 1,
+            icon: None,
+            emoji: None,
+            mentionable: false,
+        };
+
+        let after = Role {
+            name: "Admin".to_string(),
+            ..before.clone()
+        };
+
+        assert_eq!(
+            AuditLogChange::diff(Some(&before), Some(&after)),
+            vec![AuditLogChange {
+                key: "name".to_string(),
+                before: Some("\"Moderator\"".to_string()),
+                after: Some("\"Admin\"".to_string()),
+            }]
+        );
+
+        assert!(AuditLogChange::diff(None, Some(&after))
+            .iter()
+            .all(|change| change.before.is_none() && change.after.is_some()));
+    }
+
+    #[async_std::test]
+    async fn filter_audit_log() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_audit_log",
+                server server 2
+                other server 3);
+
+            let ids = |entries: Vec<AuditLogEntry>| {
+                entries
+                    .into_iter()
+                    .map(|entry| entry.id)
+                    .collect::<Vec<String>>()
+            };
+
+            let first = "00000000010000000000000000".to_string();
+            let second = "00000000020000000000000000".to_string();
+            let third = "00000000030000000000000000".to_string();
+
+            // Newest entries come first
+            assert_eq!(
+                ids(db
+                    .fetch_audit_log_entries(&server.id, AuditLogQuery::default())
+                    .await
+                    .unwrap()),
+                vec![second.clone(), first.clone()]
+            );
+
+            assert_eq!(
+                ids(db
+                    .fetch_audit_log_entries(
+                        &server.id,
+                        AuditLogQuery {
+                            action: Some(AuditLogAction::MemberKick),
+                            ..Default::default()
+                        }
+                    )
+                    .await
+                    .unwrap()),
+                vec![second.clone()]
+            );
+
+            assert_eq!(
+                ids(db
+                    .fetch_audit_log_entries(
+                        &server.id,
+                        AuditLogQuery {
+                            before: Some(second),
+                            ..Default::default()
+                        }
+                    )
+                    .await
+                    .unwrap()),
+                vec![first]
+            );
+
+            // Expired entries are removed across every server
+            db.delete_audit_log_entries_before(&third).await.unwrap();
+
+            assert!(db
+                .fetch_audit_log_entries(&server.id, AuditLogQuery::default())
+                .await
+                .unwrap()
+                .is_empty());
+
+            assert_eq!(
+                ids(db
+                    .fetch_audit_log_entries(&other.id, AuditLogQuery::default())
+                    .await
+                    .unwrap()),
+                vec![third]
+            );
+        });
+    }
+}
@@ -0,0 +1,22 @@
+use revolt_result::Result;
+
+use crate::{AuditLogEntry, AuditLogQuery};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractAuditLogs: Sync + Send {
+    /// Insert a new audit log entry into the database
+    async fn insert_audit_log_entry(&self, entry: &AuditLogEntry) -> Result<()>;
+
+    /// Fetch audit log entries for a server, newest first
+    async fn fetch_audit_log_entries(
+        &self,
+        server_id: &str,
+        query: AuditLogQuery,
+    ) -> Result<Vec<AuditLogEntry>>;
+
+    /// Delete all audit log entries created before the given id
+    async fn delete_audit_log_entries_before(&self, id: &str) -> Result<()>;
+}
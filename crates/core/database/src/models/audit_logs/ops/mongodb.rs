@@ -0,0 +1,98 @@
+use mongodb::options::FindOptions;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{AuditLogEntry, AuditLogQuery};
+
+use super::AbstractAuditLogs;
+
+static COL: &str = "audit_logs";
+
+#[async_trait]
+impl AbstractAuditLogs for MongoDb {
+    /// Insert a new audit log entry into the database
+    async fn insert_audit_log_entry(&self, entry: &AuditLogEntry) -> Result<()> {
+        query!(self, insert_one, COL, &entry).map(|_| ())
+    }
+
+    /// Fetch audit log entries for a server, newest first
+    async fn fetch_audit_log_entries(
+        &self,
+        server_id: &str,
+        query: AuditLogQuery,
+    ) -> Result<Vec<AuditLogEntry>> {
+        let mut filter = doc! {
+            "server": server_id
+        };
+
+        if let Some(actor) = query.actor {
+            filter.insert("actor", actor);
+        }
+
+        if let Some(action) = query.action {
+            filter.insert(
+                "action",
+                bson::to_bson(&action).map_err(|_| create_database_error!("to_bson", COL))?,
+            );
+        }
+
+        if let Some(target) = query.target {
+            filter.insert("target", target);
+        }
+
+        // Paginating forwards needs the entries closest to `after`
+        let forwards = query.before.is_none() && query.after.is_some();
+
+        if let Some(doc) = match (query.before, query.after) {
+            (Some(before), Some(after)) => Some(doc! {
+                "$lt": before,
+                "$gt": after
+            }),
+            (Some(before), _) => Some(doc! {
+                "$lt": before
+            }),
+            (_, Some(after)) => Some(doc! {
+                "$gt": after
+            }),
+            _ => None,
+        } {
+            filter.insert("_id", doc);
+        }
+
+        let mut entries: Vec<AuditLogEntry> = self
+            .find_with_options(
+                COL,
+                filter,
+                FindOptions::builder()
+                    .limit(query.limit.unwrap_or(50))
+                    .sort(doc! {
+                        "_id": if forwards { 1_i32 } else { -1_i32 }
+                    })
+                    .build(),
+            )
+            .await
+            .map_err(|_| create_database_error!("find", COL))?;
+
+        if forwards {
+            entries.reverse();
+        }
+
+        Ok(entries)
+    }
+
+    /// Delete all audit log entries created before the given id
+    async fn delete_audit_log_entries_before(&self, id: &str) -> Result<()> {
+        self.col::<AuditLogEntry>(COL)
+            .delete_many(
+                doc! {
+                    "_id": {
+                        "$lt": id
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("delete_many", COL))
+    }
+}
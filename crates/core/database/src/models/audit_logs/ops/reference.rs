@@ -0,0 +1,64 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{AuditLogEntry, AuditLogQuery};
+
+use super::AbstractAuditLogs;
+
+#[async_trait]
+impl AbstractAuditLogs for ReferenceDb {
+    /// Insert a new audit log entry into the database
+    async fn insert_audit_log_entry(&self, entry: &AuditLogEntry) -> Result<()> {
+        let mut audit_logs = self.audit_logs.lock().await;
+        if audit_logs.contains_key(&entry.id) {
+            Err(create_database_error!("insert", "audit_log"))
+        } else {
+            audit_logs.insert(entry.id.to_string(), entry.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch audit log entries for a server, newest first
+    async fn fetch_audit_log_entries(
+        &self,
+        server_id: &str,
+        query: AuditLogQuery,
+    ) -> Result<Vec<AuditLogEntry>> {
+        let audit_logs = self.audit_logs.lock().await;
+        let mut entries: Vec<AuditLogEntry> = audit_logs
+            .values()
+            .filter(|entry| {
+                entry.server == server_id
+                    && query.actor.as_ref().map_or(true, |v| &entry.actor == v)
+                    && query.action.as_ref().map_or(true, |v| &entry.action == v)
+                    && query
+                        .target
+                        .as_ref()
+                        .map_or(true, |v| entry.target.as_ref() == Some(v))
+                    && query.before.as_ref().map_or(true, |v| &entry.id < v)
+                    && query.after.as_ref().map_or(true, |v| &entry.id > v)
+            })
+            .cloned()
+            .collect();
+
+        let limit = query.limit.unwrap_or(50) as usize;
+        if query.before.is_none() && query.after.is_some() {
+            // Paginating forwards needs the entries closest to `after`
+            entries.sort_by(|a, b| a.id.cmp(&b.id));
+            entries.truncate(limit);
+            entries.reverse();
+        } else {
+            entries.sort_by(|a, b| b.id.cmp(&a.id));
+            entries.truncate(limit);
+        }
+
+        Ok(entries)
+    }
+
+    /// Delete all audit log entries created before the given id
+    async fn delete_audit_log_entries_before(&self, id: &str) -> Result<()> {
+        let mut audit_logs = self.audit_logs.lock().await;
+        audit_logs.retain(|_, entry| entry.id.as_str() >= id);
+        Ok(())
+    }
+}
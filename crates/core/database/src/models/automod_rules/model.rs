@@ -0,0 +1,194 @@
+use regex::{Regex, RegexBuilder};
+use revolt_result::{create_error, Result};
+
+use crate::{util::automod, Database, Server};
+
+auto_derived_partial!(
+    /// Automod Rule
+    pub struct AutomodRule {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server this rule belongs to
+        pub server: String,
+        /// Name of the rule
+        pub name: String,
+        /// Whether this rule is currently being enforced
+        #[serde(default)]
+        pub enabled: bool,
+
+        /// Condition which causes this rule to trigger
+        pub trigger: AutomodTrigger,
+        /// Actions taken when this rule triggers
+        pub actions: Vec<AutomodAction>,
+
+        /// Roles which are not affected by this rule
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub exempt_roles: Vec<String>,
+        /// Channels in which this rule is not enforced
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub exempt_channels: Vec<String>,
+    },
+    "PartialAutomodRule"
+);
+
+auto_derived!(
+    /// Condition which causes an automod rule to trigger
+    #[serde(tag = "type")]
+    pub enum AutomodTrigger {
+        /// Message contains a blocked word or matches a blocked pattern
+        Keyword {
+            /// Case-insensitive words or phrases to block
+            #[serde(skip_serializing_if = "Vec::is_empty", default)]
+            keywords: Vec<String>,
+            /// Regular expressions to block
+            #[serde(skip_serializing_if = "Vec::is_empty", default)]
+            patterns: Vec<String>,
+        },
+        /// Message mentions too many users
+        MentionSpam {
+            /// Maximum number of unique mentions allowed
+            max_mentions: usize,
+        },
+        /// Message contains links or invites
+        Links {
+            /// Whether to block invites to other servers
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            block_invites: bool,
+            /// Whether to block links to other websites
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            block_links: bool,
+            /// Domains which may always be linked to
+            #[serde(skip_serializing_if = "Vec::is_empty", default)]
+            allowed_domains: Vec<String>,
+        },
+        /// Author is sending messages too quickly
+        Flood {
+            /// Maximum number of messages allowed within the window
+            max_messages: usize,
+            /// Length of the window in seconds
+            window: u64,
+        },
+        /// Author is repeating the same message
+        Repeated {
+            /// Maximum number of identical messages allowed within the window
+            max_duplicates: usize,
+            /// Length of the window in seconds
+            window: u64,
+        },
+        /// Message is mostly written in capital letters
+        Caps {
+            /// Minimum number of letters before this rule applies
+            min_length: usize,
+            /// Maximum percentage of letters which may be capitals
+            max_percentage: u8,
+        },
+        /// Message contains excessive combining characters
+        Zalgo {
+            /// Maximum number of combining characters allowed on a single character
+            max_combining: usize,
+        },
+    }
+
+    /// Action taken when an automod rule triggers
+    #[serde(tag = "type")]
+    pub enum AutomodAction {
+        /// Reject the message and tell the author why
+        Block,
+        /// Silently discard the message
+        Delete,
+        /// Time out the author
+        Timeout {
+            /// Length of the timeout in seconds
+            duration: u64,
+        },
+        /// Notify moderators in a channel
+        Alert {
+            /// Id of the channel to send the alert to
+            channel: String,
+        },
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl AutomodRule {
+    /// Create a new automod rule
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        self.validate()?;
+        db.insert_automod_rule(self).await?;
+        automod::invalidate_rules(&self.server).await;
+        Ok(())
+    }
+
+    /// Update rule data
+    pub async fn update(&mut self, db: &Database, partial: PartialAutomodRule) -> Result<()> {
+        self.apply_options(partial.clone());
+        self.validate()?;
+        db.update_automod_rule(&self.id, &partial).await?;
+        automod::invalidate_rules(&self.server).await;
+        Ok(())
+    }
+
+    /// Delete this rule
+    pub async fn delete(self, db: &Database) -> Result<()> {
+        db.delete_automod_rule(&self.id).await?;
+        automod::invalidate_rules(&self.server).await;
+        Ok(())
+    }
+
+    /// Ensure the rule can be evaluated
+    pub fn validate(&self) -> Result<()> {
+        if self.actions.is_empty() {
+            return Err(create_error!(InvalidProperty));
+        }
+
+        if let AutomodTrigger::Keyword { patterns, .. } = &self.trigger {
+            for pattern in patterns {
+                AutomodTrigger::compile_pattern(pattern).map_err(|error| {
+                    create_error!(FailedValidation {
+                        error: error.to_string()
+                    })
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensure all referenced roles and channels belong to the given server
+    pub fn validate_targets(&self, server: &Server) -> Result<()> {
+        let alert_channels = self.actions.iter().filter_map(|action| match action {
+            AutomodAction::Alert { channel } => Some(channel),
+            _ => None,
+        });
+
+        if self
+            .exempt_channels
+            .iter()
+            .chain(alert_channels)
+            .any(|channel| !server.channels.contains(channel))
+        {
+            return Err(create_error!(UnknownChannel));
+        }
+
+        if self
+            .exempt_roles
+            .iter()
+            .any(|role| !server.roles.contains_key(role))
+        {
+            return Err(create_error!(NotFound));
+        }
+
+        Ok(())
+    }
+}
+
+impl AutomodTrigger {
+    /// Compile a user-provided pattern with limits on its size
+    pub(crate) fn compile_pattern(pattern: &str) -> std::result::Result<Regex, regex::Error> {
+        RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .size_limit(1 << 16)
+            .build()
+    }
+}
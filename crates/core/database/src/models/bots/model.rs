@@ -2,6 +2,8 @@ use revolt_config::config;
 use revolt_result::Result;
 use ulid::Ulid;
 
+use revolt_models::v0::BotFlags;
+
 use crate::{events::client::EventV1, BotInformation, Database, PartialUser, User};
 
 auto_derived_partial!(
@@ -39,6 +41,25 @@ auto_derived_partial!(
         /// Enum of bot flags
         #[serde(skip_serializing_if = "Option::is_none")]
         pub flags: Option<i32>,
+
+        /// Tags describing this bot on the directory
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub tags: Vec<String>,
+        /// Short summary shown on the directory
+        #[serde(skip_serializing_if = "String::is_empty", default)]
+        pub short_description: String,
+        /// Full description shown on the directory
+        #[serde(skip_serializing_if = "String::is_empty", default)]
+        pub long_description: String,
+        /// Whether this bot has been removed from the directory by platform moderators
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub delisted: bool,
+        /// State of this bot's verification request
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub verification: Option<BotVerificationStatus>,
+        /// Number of servers this bot is a member of
+        #[serde(default)]
+        pub server_count: u32,
     },
     "PartialBot"
 );
@@ -49,8 +70,33 @@ auto_derived!(
         Token,
         InteractionsURL,
     }
+
+    /// State of a bot verification request
+    pub enum BotVerificationStatus {
+        /// Waiting for review by platform moderators
+        Pending,
+        /// Request was approved and the bot is verified
+        Approved,
+        /// Request was rejected
+        Rejected,
+    }
 );
 
+/// Filter used when browsing the bot directory
+#[derive(Default)]
+pub struct BotDirectoryQuery {
+    /// Text to search for in names, descriptions and tags
+    pub query: Option<String>,
+    /// Only include bots with all of these tags
+    pub tags: Vec<String>,
+    /// Only include verified bots
+    pub verified: bool,
+    /// Number of bots to skip
+    pub offset: Option<i64>,
+    /// Maximum number of bots to fetch
+    pub limit: Option<i64>,
+}
+
 #[allow(clippy::derivable_impls)]
 impl Default for Bot {
     fn default() -> Self {
@@ -65,6 +111,12 @@ impl Default for Bot {
             terms_of_service_url: Default::default(),
             privacy_policy_url: Default::default(),
             flags: Default::default(),
+            tags: Default::default(),
+            short_description: Default::default(),
+            long_description: Default::default(),
+            delisted: Default::default(),
+            verification: Default::default(),
+            server_count: Default::default(),
         }
     }
 }
@@ -119,6 +171,22 @@ impl Bot {
         Ok((bot, user))
     }
 
+    /// Whether this bot has the verified flag
+    pub fn is_verified(&self) -> bool {
+        self.flags.unwrap_or_default() & BotFlags::Verified as i32 == BotFlags::Verified as i32
+    }
+
+    /// Browse the bot directory
+    ///
+    /// Returns a page of listed bots with their users and server counts, most popular first,
+    /// along with the total number of matches.
+    pub async fn directory(
+        db: &Database,
+        query: &BotDirectoryQuery,
+    ) -> Result<(Vec<(Bot, User, usize)>, usize)> {
+        db.fetch_bot_directory(query).await
+    }
+
     /// Remove a field from this object
     pub fn remove_field(&mut self, field: &FieldsBot) {
         match field {
@@ -163,7 +231,7 @@ impl Bot {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Bot, FieldsBot, PartialBot, User};
+    use crate::{fixture, Bot, BotDirectoryQuery, FieldsBot, Member, PartialBot, User};
 
     #[async_std::test]
     async fn crud() {
@@ -220,4 +288,80 @@ mod tests {
             assert_eq!(db.fetch_user(&bot.id).await.unwrap().flags, Some(2))
         });
     }
+
+    #[async_std::test]
+    async fn directory() {
+        database_test!(|db| async move {
+            let owner = User::create(&db, "Owner".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let (mut bot, user) = Bot::create(&db, "Helper".to_string(), &owner, None)
+                .await
+                .unwrap();
+
+            let search = BotDirectoryQuery {
+                query: Some("moderation".to_string()),
+                ..Default::default()
+            };
+
+            assert!(Bot::directory(&db, &search).await.unwrap().0.is_empty());
+
+            bot.update(
+                &db,
+                PartialBot {
+                    public: Some(true),
+                    discoverable: Some(true),
+                    tags: Some(vec!["moderation".to_string()]),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .unwrap();
+
+            let (results, total) = Bot::directory(&db, &search).await.unwrap();
+            assert_eq!(results.len(), 1);
+            assert_eq!(total, 1);
+            assert_eq!(results[0].0.id, bot.id);
+            assert_eq!(results[0].2, 0);
+
+            // Server counts are kept up to date as the bot joins servers
+            fixture!(db, "server_with_roles", server server 4);
+            Member::create(&db, &server, &user, None).await.unwrap();
+
+            let (results, _) = Bot::directory(&db, &search).await.unwrap();
+            assert_eq!(results[0].2, 1);
+
+            let next_page = BotDirectoryQuery {
+                query: Some("moderation".to_string()),
+                offset: Some(1),
+                ..Default::default()
+            };
+
+            let (results, total) = Bot::directory(&db, &next_page).await.unwrap();
+            assert!(results.is_empty());
+            assert_eq!(total, 1);
+
+            let verified = BotDirectoryQuery {
+                verified: true,
+                ..Default::default()
+            };
+
+            assert!(Bot::directory(&db, &verified).await.unwrap().0.is_empty());
+
+            bot.update(
+                &db,
+                PartialBot {
+                    delisted: Some(true),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .unwrap();
+
+            assert!(Bot::directory(&db, &search).await.unwrap().0.is_empty());
+        });
+    }
 }
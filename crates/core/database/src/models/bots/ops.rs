@@ -1,6 +1,6 @@
 use revolt_result::Result;
 
-use crate::{Bot, FieldsBot, PartialBot};
+use crate::{Bot, BotDirectoryQuery, BotVerificationStatus, FieldsBot, PartialBot, User};
 
 mod mongodb;
 mod reference;
@@ -19,6 +19,17 @@ pub trait AbstractBots: Sync + Send {
     /// Fetch bots owned by a user
     async fn fetch_bots_by_user(&self, user_id: &str) -> Result<Vec<Bot>>;
 
+    /// Fetch a page of listed bots matching a query along with their users and server counts
+    ///
+    /// Also returns the total number of matching bots.
+    async fn fetch_bot_directory(
+        &self,
+        query: &BotDirectoryQuery,
+    ) -> Result<(Vec<(Bot, User, usize)>, usize)>;
+
+    /// Fetch bots with a given verification status
+    async fn fetch_bots_by_verification(&self, status: &BotVerificationStatus) -> Result<Vec<Bot>>;
+
     /// Get the number of bots owned by a user
     async fn get_number_of_bots_by_user(&self, user_id: &str) -> Result<usize>;
 
@@ -30,6 +41,11 @@ pub trait AbstractBots: Sync + Send {
         remove: Vec<FieldsBot>,
     ) -> Result<()>;
 
+    /// Adjust the stored server count of a bot
+    ///
+    /// Has no effect if the user is not a bot.
+    async fn update_bot_server_count(&self, id: &str, delta: i32) -> Result<()>;
+
     /// Delete a bot from the database
     async fn delete_bot(&self, id: &str) -> Result<()>;
 }
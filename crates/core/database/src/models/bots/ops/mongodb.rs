@@ -1,6 +1,9 @@
+use bson::{Bson, Document};
+use futures::StreamExt;
+use revolt_models::v0::BotFlags;
 use revolt_result::Result;
 
-use crate::{Bot, FieldsBot, PartialBot};
+use crate::{Bot, BotDirectoryQuery, BotVerificationStatus, FieldsBot, PartialBot, User};
 use crate::{IntoDocumentPath, MongoDb};
 
 use super::AbstractBots;
@@ -44,6 +47,134 @@ impl AbstractBots for MongoDb {
         )
     }
 
+    /// Fetch a page of listed bots matching a query along with their users and server counts
+    async fn fetch_bot_directory(
+        &self,
+        query: &BotDirectoryQuery,
+    ) -> Result<(Vec<(Bot, User, usize)>, usize)> {
+        let mut filter = doc! {
+            "public": true,
+            "discoverable": true,
+            "delisted": {
+                "$ne": true
+            }
+        };
+
+        if query.verified {
+            filter.insert(
+                "flags",
+                doc! {
+                    "$bitsAllSet": BotFlags::Verified as i32
+                },
+            );
+        }
+
+        if !query.tags.is_empty() {
+            filter.insert("tags", doc! { "$all": query.tags.clone() });
+        }
+
+        let mut pipeline = vec![
+            doc! {
+                "$match": filter
+            },
+            doc! {
+                "$lookup": {
+                    "from": "users",
+                    "localField": "_id",
+                    "foreignField": "_id",
+                    "as": "user"
+                }
+            },
+            doc! {
+                "$unwind": "$user"
+            },
+        ];
+
+        if let Some(text) = &query.query {
+            let pattern = regex::escape(text);
+            pipeline.push(doc! {
+                "$match": {
+                    "$or": [
+                        { "user.username": { "$regex": pattern.as_str(), "$options": "i" } },
+                        { "short_description": { "$regex": pattern.as_str(), "$options": "i" } },
+                        { "long_description": { "$regex": pattern.as_str(), "$options": "i" } },
+                        { "tags": text.to_lowercase() }
+                    ]
+                }
+            });
+        }
+
+        pipeline.push(doc! {
+            "$facet": {
+                "total": [
+                    {
+                        "$count": "count"
+                    }
+                ],
+                "bots": [
+                    {
+                        "$sort": {
+                            "server_count": -1_i32,
+                            "_id": 1_i32
+                        }
+                    },
+                    {
+                        "$skip": query.offset.unwrap_or_default()
+                    },
+                    {
+                        "$limit": query.limit.unwrap_or(50)
+                    }
+                ]
+            }
+        });
+
+        let result = self
+            .col::<Document>(COL)
+            .aggregate(pipeline, None)
+            .await
+            .map_err(|_| create_database_error!("aggregate", COL))?
+            .next()
+            .await
+            .and_then(|result| result.ok())
+            .ok_or_else(|| create_database_error!("aggregate", COL))?;
+
+        let total = result
+            .get_array("total")
+            .ok()
+            .and_then(|total| total.first())
+            .and_then(Bson::as_document)
+            .and_then(|total| total.get_i32("count").ok())
+            .unwrap_or_default() as usize;
+
+        let bots = result
+            .get_array("bots")
+            .map_err(|_| create_database_error!("aggregate", COL))?
+            .iter()
+            .filter_map(Bson::as_document)
+            .filter_map(|document| {
+                let user = bson::from_document(document.get_document("user").ok()?.clone()).ok()?;
+                let bot: Bot = bson::from_document(document.clone()).ok()?;
+                let count = bot.server_count as usize;
+                Some((bot, user, count))
+            })
+            .collect();
+
+        Ok((bots, total))
+    }
+
+    /// Fetch bots with a given verification status
+    async fn fetch_bots_by_verification(&self, status: &BotVerificationStatus) -> Result<Vec<Bot>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "verification": bson::to_bson(status)
+                    .map_err(|_| create_database_error!("to_bson", "verification"))?
+            }
+        )
+    }
+
     /// Get the number of bots owned by a user
     async fn get_number_of_bots_by_user(&self, user_id: &str) -> Result<usize> {
         query!(
@@ -76,6 +207,27 @@ impl AbstractBots for MongoDb {
         .map(|_| ())
     }
 
+    /// Adjust the stored server count of a bot
+    ///
+    /// Has no effect if the user is not a bot.
+    async fn update_bot_server_count(&self, id: &str, delta: i32) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$inc": {
+                        "server_count": delta
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
     /// Delete a bot from the database
     async fn delete_bot(&self, id: &str) -> Result<()> {
         query!(self, delete_one_by_id, COL, id).map(|_| ())
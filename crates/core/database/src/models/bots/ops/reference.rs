@@ -1,7 +1,7 @@
 use revolt_result::Result;
 
 use crate::ReferenceDb;
-use crate::{Bot, FieldsBot, PartialBot};
+use crate::{Bot, BotDirectoryQuery, BotVerificationStatus, FieldsBot, PartialBot, User};
 
 use super::AbstractBots;
 
@@ -43,6 +43,58 @@ impl AbstractBots for ReferenceDb {
             .collect())
     }
 
+    /// Fetch a page of listed bots matching a query along with their users and server counts
+    async fn fetch_bot_directory(
+        &self,
+        query: &BotDirectoryQuery,
+    ) -> Result<(Vec<(Bot, User, usize)>, usize)> {
+        let text = query.query.as_ref().map(|text| text.to_lowercase());
+        let bots = self.bots.lock().await;
+        let users = self.users.lock().await;
+
+        let mut results: Vec<(Bot, User, usize)> = bots
+            .values()
+            .filter(|bot| bot.public && bot.discoverable && !bot.delisted)
+            .filter(|bot| !query.verified || bot.is_verified())
+            .filter(|bot| query.tags.iter().all(|tag| bot.tags.contains(tag)))
+            .filter_map(|bot| {
+                let user = users.get(&bot.id)?.clone();
+                let count = bot.server_count as usize;
+                Some((bot.clone(), user, count))
+            })
+            .filter(|(bot, user, _)| {
+                text.as_ref().map_or(true, |text| {
+                    user.username.to_lowercase().contains(text)
+                        || bot.short_description.to_lowercase().contains(text)
+                        || bot.long_description.to_lowercase().contains(text)
+                        || bot.tags.contains(text)
+                })
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.2.cmp(&a.2));
+
+        let total = results.len();
+        Ok((
+            results
+                .into_iter()
+                .skip(query.offset.unwrap_or_default() as usize)
+                .take(query.limit.unwrap_or(50) as usize)
+                .collect(),
+            total,
+        ))
+    }
+
+    /// Fetch bots with a given verification status
+    async fn fetch_bots_by_verification(&self, status: &BotVerificationStatus) -> Result<Vec<Bot>> {
+        let bots = self.bots.lock().await;
+        Ok(bots
+            .values()
+            .filter(|bot| bot.verification.as_ref() == Some(status))
+            .cloned()
+            .collect())
+    }
+
     /// Get the number of bots owned by a user
     async fn get_number_of_bots_by_user(&self, user_id: &str) -> Result<usize> {
         let bots = self.bots.lock().await;
@@ -70,6 +122,18 @@ impl AbstractBots for ReferenceDb {
         }
     }
 
+    /// Adjust the stored server count of a bot
+    ///
+    /// Has no effect if the user is not a bot.
+    async fn update_bot_server_count(&self, id: &str, delta: i32) -> Result<()> {
+        let mut bots = self.bots.lock().await;
+        if let Some(bot) = bots.get_mut(id) {
+            bot.server_count = bot.server_count.saturating_add_signed(delta);
+        }
+
+        Ok(())
+    }
+
     /// Delete a bot from the database
     async fn delete_bot(&self, id: &str) -> Result<()> {
         let mut bots = self.bots.lock().await;
@@ -1,6 +1,7 @@
+use iso8601_timestamp::Timestamp;
 use revolt_result::{create_error, Result};
 
-use crate::{Channel, Database, User};
+use crate::{Channel, Database, Server, User};
 
 static ALPHABET: [char; 54] = [
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J',
@@ -23,6 +24,22 @@ auto_derived!(
             creator: String,
             /// Id of the server channel this invite points to
             channel: String,
+
+            /// Maximum number of times this invite can be used
+            #[serde(skip_serializing_if = "Option::is_none")]
+            max_uses: Option<u32>,
+            /// Time at which this invite expires
+            #[serde(skip_serializing_if = "Option::is_none")]
+            expires_at: Option<Timestamp>,
+            /// Whether members joining through this invite are removed once they go offline
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            temporary: bool,
+            /// Number of times this invite has been used
+            #[serde(default)]
+            uses: u32,
+            /// Whether this is the server's custom vanity invite
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            vanity: bool,
         },
         /// Invite to a group channel
         Group {
@@ -33,6 +50,16 @@ auto_derived!(
             creator: String,
             /// Id of the group channel this invite points to
             channel: String,
+
+            /// Maximum number of times this invite can be used
+            #[serde(skip_serializing_if = "Option::is_none")]
+            max_uses: Option<u32>,
+            /// Time at which this invite expires
+            #[serde(skip_serializing_if = "Option::is_none")]
+            expires_at: Option<Timestamp>,
+            /// Number of times this invite has been used
+            #[serde(default)]
+            uses: u32,
         }, /* User {
                code: String,
                user: String
@@ -56,18 +83,45 @@ impl Invite {
         }
     }
 
+    /// Check whether this invite has expired or run out of uses
+    pub fn is_expired(&self) -> bool {
+        let (max_uses, expires_at, uses) = match self {
+            Invite::Server {
+                max_uses,
+                expires_at,
+                uses,
+                ..
+            }
+            | Invite::Group {
+                max_uses,
+                expires_at,
+                uses,
+                ..
+            } => (max_uses, expires_at, uses),
+        };
+
+        max_uses.is_some_and(|max_uses| *uses >= max_uses)
+            || expires_at.is_some_and(|expires_at| *expires_at <= *Timestamp::now_utc())
+    }
+
     /// Create a new invite from given information
     pub async fn create_channel_invite(
         db: &Database,
         creator: &User,
         channel: &Channel,
+        max_uses: Option<u32>,
+        expires_at: Option<Timestamp>,
+        temporary: bool,
     ) -> Result<Invite> {
-        let code = nanoid::nanoid!(8, &ALPHABET);
+        let code = Invite::generate_code();
         let invite = match &channel {
             Channel::Group { id, .. } => Ok(Invite::Group {
                 code,
                 creator: creator.id.clone(),
                 channel: id.clone(),
+                max_uses,
+                expires_at,
+                uses: 0,
             }),
             Channel::TextChannel { id, server, .. } | Channel::VoiceChannel { id, server, .. } => {
                 Ok(Invite::Server {
@@ -75,6 +129,11 @@ impl Invite {
                     creator: creator.id.clone(),
                     server: server.clone(),
                     channel: id.clone(),
+                    max_uses,
+                    expires_at,
+                    temporary,
+                    uses: 0,
+                    vanity: false,
                 })
             }
             _ => Err(create_error!(InvalidOperation)),
@@ -84,10 +143,78 @@ impl Invite {
         Ok(invite)
     }
 
+    /// Generate a random code
+    pub(crate) fn generate_code() -> String {
+        nanoid::nanoid!(8, &ALPHABET)
+    }
+
+    /// Check whether a code could have been randomly generated
+    pub fn is_random_code(code: &str) -> bool {
+        code.len() == 8 && code.chars().all(|c| ALPHABET.contains(&c))
+    }
+
+    /// Fetch the vanity invite for a server
+    pub async fn fetch_vanity(db: &Database, server: &Server) -> Result<Invite> {
+        db.fetch_invites_for_server(&server.id)
+            .await?
+            .into_iter()
+            .find(|invite| matches!(invite, Invite::Server { vanity: true, .. }))
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Claim a vanity invite for a server, replacing any existing one
+    pub async fn set_vanity(
+        db: &Database,
+        server: &Server,
+        creator: &User,
+        channel: &str,
+        code: String,
+    ) -> Result<Invite> {
+        if !server.channels.iter().any(|id| id == channel) {
+            return Err(create_error!(UnknownChannel));
+        }
+
+        // Codes which look randomly generated are reserved for regular invites
+        if Invite::is_random_code(&code) {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        let existing = Invite::fetch_vanity(db, server).await.ok();
+        let invite = Invite::Server {
+            code,
+            server: server.id.clone(),
+            creator: creator.id.clone(),
+            channel: channel.to_string(),
+            max_uses: None,
+            expires_at: None,
+            temporary: false,
+            uses: 0,
+            vanity: true,
+        };
+
+        if let Some(existing) = existing {
+            if existing.code() == invite.code() {
+                // Same code, just pointed somewhere else
+                db.delete_invite(existing.code()).await?;
+                db.insert_invite(&invite).await?;
+            } else {
+                // The unique code rejects the insert if it is already taken
+                db.insert_invite(&invite).await?;
+                db.delete_invite(existing.code()).await?;
+            }
+        } else {
+            db.insert_invite(&invite).await?;
+        }
+
+        Ok(invite)
+    }
+
     /// Resolve an invite by its ID or by a public server ID
     pub async fn find(db: &Database, code: &str) -> Result<Invite> {
         if let Ok(invite) = db.fetch_invite(code).await {
-            return Ok(invite);
+            if !invite.is_expired() {
+                return Ok(invite);
+            }
         } else if let Ok(server) = db.fetch_server(code).await {
             if server.discoverable {
                 if let Some(channel) = server.channels.into_iter().next() {
@@ -96,6 +223,11 @@ impl Invite {
                         server: server.id,
                         creator: server.owner,
                         channel,
+                        max_uses: None,
+                        expires_at: None,
+                        temporary: false,
+                        uses: 0,
+                        vanity: false,
                     });
                 }
             }
@@ -104,3 +236,50 @@ impl Invite {
         Err(create_error!(NotFound))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use revolt_result::ErrorType;
+
+    use crate::{fixture, Invite};
+
+    #[async_std::test]
+    async fn invite_expiry() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_invites",
+                unlimited invite 3
+                limited invite 4
+                used_up invite 5
+                expired invite 6);
+
+            assert!(!unlimited.is_expired());
+            assert!(!limited.is_expired());
+            assert!(used_up.is_expired());
+            assert!(expired.is_expired());
+        });
+    }
+
+    #[async_std::test]
+    async fn claim_uses() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_invites", invite invite 4);
+
+            db.claim_invite_use(invite.code()).await.unwrap();
+            assert!(db.claim_invite_use(invite.code()).await.is_err());
+
+            db.release_invite_use(invite.code()).await.unwrap();
+            db.claim_invite_use(invite.code()).await.unwrap();
+
+            let error = db.insert_invite(&invite).await.unwrap_err();
+            assert!(matches!(error.error_type, ErrorType::InviteCodeTaken));
+        });
+    }
+
+    #[test]
+    fn random_codes() {
+        assert!(Invite::is_random_code("Ab3dEf9h"));
+        assert!(!Invite::is_random_code("rust-lang"));
+        assert!(!Invite::is_random_code("revolt"));
+        assert!(!Invite::is_random_code("illusion"));
+    }
+}
@@ -1,3 +1,4 @@
+use iso8601_timestamp::Timestamp;
 use revolt_result::Result;
 
 use crate::Invite;
@@ -16,6 +17,18 @@ pub trait AbstractChannelInvites: Sync + Send {
     /// Fetch all invites for a server
     async fn fetch_invites_for_server(&self, server_id: &str) -> Result<Vec<Invite>>;
 
+    /// Fetch up to `limit` invites which have run out of uses or expired by the given time
+    async fn fetch_expired_invites(&self, now: Timestamp, limit: i64) -> Result<Vec<Invite>>;
+
+    /// Record a use of an invite, failing if it has no uses left
+    async fn claim_invite_use(&self, code: &str) -> Result<()>;
+
+    /// Give back a use of an invite which did not result in a join
+    async fn release_invite_use(&self, code: &str) -> Result<()>;
+
     /// Delete an invite by its id
     async fn delete_invite(&self, code: &str) -> Result<()>;
+
+    /// Delete invites by their ids
+    async fn delete_invites(&self, codes: &[String]) -> Result<()>;
 }
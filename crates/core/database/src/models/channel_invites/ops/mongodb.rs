@@ -1,4 +1,8 @@
+use bson::Document;
 use futures::StreamExt;
+use iso8601_timestamp::Timestamp;
+use mongodb::error::{ErrorKind, WriteError, WriteFailure};
+use mongodb::options::FindOptions;
 use revolt_result::Result;
 
 use crate::Invite;
@@ -12,7 +16,16 @@ static COL: &str = "channel_invites";
 impl AbstractChannelInvites for MongoDb {
     /// Insert a new invite into the database
     async fn insert_invite(&self, invite: &Invite) -> Result<()> {
-        query!(self, insert_one, COL, &invite).map(|_| ())
+        self.col::<Invite>(COL)
+            .insert_one(invite, None)
+            .await
+            .map(|_| ())
+            .map_err(|error| match *error.kind {
+                ErrorKind::Write(WriteFailure::WriteError(WriteError { code: 11000, .. })) => {
+                    create_error!(InviteCodeTaken)
+                }
+                _ => create_database_error!("insert_one", COL),
+            })
     }
 
     /// Fetch an invite by the code
@@ -43,8 +56,97 @@ impl AbstractChannelInvites for MongoDb {
             .await)
     }
 
+    /// Fetch up to `limit` invites which have run out of uses or expired by the given time
+    async fn fetch_expired_invites(&self, now: Timestamp, limit: i64) -> Result<Vec<Invite>> {
+        self.find_with_options(
+            COL,
+            doc! {
+                "$or": [
+                    {
+                        "expires_at": {
+                            "$lte": bson::to_bson(&now)
+                                .map_err(|_| create_database_error!("to_bson", "expires_at"))?
+                        }
+                    },
+                    {
+                        "max_uses": { "$ne": null },
+                        "$expr": { "$gte": [ "$uses", "$max_uses" ] }
+                    }
+                ]
+            },
+            FindOptions::builder().limit(limit).build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
+    /// Record a use of an invite, failing if it has no uses left
+    async fn claim_invite_use(&self, code: &str) -> Result<()> {
+        let result = self
+            .col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": code,
+                    "$or": [
+                        { "max_uses": null },
+                        { "$expr": { "$lt": [ "$uses", "$max_uses" ] } }
+                    ]
+                },
+                doc! {
+                    "$inc": {
+                        "uses": 1
+                    }
+                },
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("update_one", COL))?;
+
+        if result.matched_count == 0 {
+            return Err(create_error!(NotFound));
+        }
+
+        Ok(())
+    }
+
+    /// Give back a use of an invite which did not result in a join
+    async fn release_invite_use(&self, code: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": code,
+                    "uses": { "$gt": 0 }
+                },
+                doc! {
+                    "$inc": {
+                        "uses": -1
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
     /// Delete an invite by its code
     async fn delete_invite(&self, code: &str) -> Result<()> {
         query!(self, delete_one_by_id, COL, code).map(|_| ())
     }
+
+    /// Delete invites by their codes
+    async fn delete_invites(&self, codes: &[String]) -> Result<()> {
+        self.col::<Document>(COL)
+            .delete_many(
+                doc! {
+                    "_id": {
+                        "$in": codes
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("delete_many", COL))
+    }
 }
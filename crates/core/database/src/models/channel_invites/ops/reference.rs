@@ -1,3 +1,4 @@
+use iso8601_timestamp::Timestamp;
 use revolt_result::Result;
 
 use crate::Invite;
@@ -11,7 +12,7 @@ impl AbstractChannelInvites for ReferenceDb {
     async fn insert_invite(&self, invite: &Invite) -> Result<()> {
         let mut invites = self.channel_invites.lock().await;
         if invites.contains_key(invite.code()) {
-            Err(create_database_error!("insert", "invite"))
+            Err(create_error!(InviteCodeTaken))
         } else {
             invites.insert(invite.code().to_string(), invite.clone());
             Ok(())
@@ -40,6 +41,59 @@ impl AbstractChannelInvites for ReferenceDb {
             .collect())
     }
 
+    /// Fetch up to `limit` invites which have run out of uses or expired by the given time
+    async fn fetch_expired_invites(&self, now: Timestamp, limit: i64) -> Result<Vec<Invite>> {
+        let invites = self.channel_invites.lock().await;
+        Ok(invites
+            .values()
+            .filter(|invite| match invite {
+                Invite::Server {
+                    max_uses,
+                    expires_at,
+                    uses,
+                    ..
+                }
+                | Invite::Group {
+                    max_uses,
+                    expires_at,
+                    uses,
+                    ..
+                } => {
+                    max_uses.is_some_and(|max_uses| *uses >= max_uses)
+                        || expires_at.is_some_and(|expires_at| *expires_at <= *now)
+                }
+            })
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    /// Record a use of an invite, failing if it has no uses left
+    async fn claim_invite_use(&self, code: &str) -> Result<()> {
+        let mut invites = self.channel_invites.lock().await;
+        match invites.get_mut(code) {
+            Some(Invite::Server { uses, max_uses, .. } | Invite::Group { uses, max_uses, .. })
+                if max_uses.map_or(true, |max_uses| *uses < max_uses) =>
+            {
+                *uses += 1;
+                Ok(())
+            }
+            _ => Err(create_error!(NotFound)),
+        }
+    }
+
+    /// Give back a use of an invite which did not result in a join
+    async fn release_invite_use(&self, code: &str) -> Result<()> {
+        let mut invites = self.channel_invites.lock().await;
+        match invites.get_mut(code) {
+            Some(Invite::Server { uses, .. } | Invite::Group { uses, .. }) => {
+                *uses = uses.saturating_sub(1);
+                Ok(())
+            }
+            None => Err(create_error!(NotFound)),
+        }
+    }
+
     /// Delete an invite by its code
     async fn delete_invite(&self, code: &str) -> Result<()> {
         let mut invites = self.channel_invites.lock().await;
@@ -49,4 +103,14 @@ impl AbstractChannelInvites for ReferenceDb {
             Err(create_error!(NotFound))
         }
     }
+
+    /// Delete invites by their codes
+    async fn delete_invites(&self, codes: &[String]) -> Result<()> {
+        let mut invites = self.channel_invites.lock().await;
+        for code in codes {
+            invites.remove(code);
+        }
+
+        Ok(())
+    }
 }
@@ -29,7 +29,7 @@ pub trait AbstractChannelUnreads: Sync + Send {
     /// Fetch all unreads with mentions for a user.
     async fn fetch_unread_mentions(&self, user_id: &str) -> Result<Vec<ChannelUnread>>;
 
-    /// Fetch all channel unreads for a user.
+    /// Fetch all channel unreads for a user, excluding archived channels.
     async fn fetch_unreads(&self, user_id: &str) -> Result<Vec<ChannelUnread>>;
 
     /// Fetch unread for a specific user in a channel.
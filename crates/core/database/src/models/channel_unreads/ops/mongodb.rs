@@ -1,11 +1,16 @@
+use std::collections::HashSet;
+
 use bson::Document;
+use futures::StreamExt;
 use mongodb::options::FindOneAndUpdateOptions;
+use mongodb::options::FindOptions;
 use mongodb::options::ReturnDocument;
 use mongodb::options::UpdateOptions;
 use revolt_result::Result;
 use ulid::Ulid;
 
 use crate::ChannelUnread;
+use crate::DocumentId;
 use crate::MongoDb;
 
 use super::AbstractChannelUnreads;
@@ -113,14 +118,39 @@ impl AbstractChannelUnreads for MongoDb {
 
     /// Fetch all channel unreads for a user.
     async fn fetch_unreads(&self, user_id: &str) -> Result<Vec<ChannelUnread>> {
-        query!(
+        let unreads: Vec<ChannelUnread> = query!(
             self,
             find,
             COL,
             doc! {
                 "_id.user": user_id
             }
-        )
+        )?;
+
+        // Archived channels are not counted towards unreads
+        let channel_ids: Vec<&String> = unreads.iter().map(|unread| &unread.id.channel).collect();
+        let archived: HashSet<String> = self
+            .col::<DocumentId>("channels")
+            .find(
+                doc! {
+                    "_id": {
+                        "$in": channel_ids
+                    },
+                    "archived": true
+                },
+                FindOptions::builder().projection(doc! { "_id": 1 }).build(),
+            )
+            .await
+            .map_err(|_| create_database_error!("find", "channels"))?
+            .filter_map(|s| async { s.ok() })
+            .map(|channel| channel.id)
+            .collect()
+            .await;
+
+        Ok(unreads
+            .into_iter()
+            .filter(|unread| !archived.contains(&unread.id.channel))
+            .collect())
     }
 
     async fn fetch_unread_mentions(&self, user_id: &str) -> Result<Vec<ChannelUnread>> {
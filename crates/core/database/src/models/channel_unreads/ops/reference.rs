@@ -90,9 +90,15 @@ impl AbstractChannelUnreads for ReferenceDb {
     /// Fetch all channel unreads for a user.
     async fn fetch_unreads(&self, user_id: &str) -> Result<Vec<ChannelUnread>> {
         let unreads = self.channel_unreads.lock().await;
+        let channels = self.channels.lock().await;
         Ok(unreads
             .values()
             .filter(|unread| unread.id.user == user_id)
+            .filter(|unread| {
+                !channels
+                    .get(&unread.id.channel)
+                    .is_some_and(|channel| channel.is_archived())
+            })
             .cloned()
             .collect())
     }
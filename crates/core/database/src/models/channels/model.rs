@@ -99,10 +99,16 @@ auto_derived!(
                 skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
             )]
             role_permissions: HashMap<String, OverrideField>,
+            /// Whether this channel inherits permissions from its category
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            sync_permissions: bool,
 
             /// Whether this channel is marked as not safe for work
             #[serde(skip_serializing_if = "crate::if_false", default)]
             nsfw: bool,
+            /// Whether this channel is archived and no longer accepts new messages
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            archived: bool,
         },
         /// Voice channel belonging to a server
         VoiceChannel {
@@ -130,6 +136,9 @@ auto_derived!(
                 skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
             )]
             role_permissions: HashMap<String, OverrideField>,
+            /// Whether this channel inherits permissions from its category
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            sync_permissions: bool,
 
             /// Whether this channel is marked as not safe for work
             #[serde(skip_serializing_if = "crate::if_false", default)]
@@ -160,6 +169,10 @@ auto_derived!(
         #[serde(skip_serializing_if = "Option::is_none")]
         pub default_permissions: Option<OverrideField>,
         #[serde(skip_serializing_if = "Option::is_none")]
+        pub sync_permissions: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub archived: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub last_message_id: Option<String>,
     }
 
@@ -218,7 +231,9 @@ impl Channel {
                 last_message_id: None,
                 default_permissions: None,
                 role_permissions: HashMap::new(),
+                sync_permissions: false,
                 nsfw: data.nsfw.unwrap_or(false),
+                archived: false,
             },
             v0::LegacyServerChannelType::Voice => Channel::VoiceChannel {
                 id: id.clone(),
@@ -228,6 +243,7 @@ impl Channel {
                 icon: None,
                 default_permissions: None,
                 role_permissions: HashMap::new(),
+                sync_permissions: false,
                 nsfw: data.nsfw.unwrap_or(false),
             },
         };
@@ -418,6 +434,11 @@ impl Channel {
         }
     }
 
+    /// Check whether this channel is archived
+    pub fn is_archived(&self) -> bool {
+        matches!(self, Channel::TextChannel { archived: true, .. })
+    }
+
     /// Clone this channel's id
     pub fn id(&self) -> &str {
         match self {
@@ -429,6 +450,16 @@ impl Channel {
         }
     }
 
+    /// Get the id of the server this channel belongs to, if any
+    pub fn server(&self) -> Option<&str> {
+        match self {
+            Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => {
+                Some(server)
+            }
+            _ => None,
+        }
+    }
+
     /// Set role permission on a channel
     pub async fn set_role_permission(
         &mut self,
@@ -593,6 +624,7 @@ impl Channel {
                 nsfw,
                 default_permissions,
                 role_permissions,
+                sync_permissions,
                 ..
             }
             | Self::VoiceChannel {
@@ -602,6 +634,7 @@ impl Channel {
                 nsfw,
                 default_permissions,
                 role_permissions,
+                sync_permissions,
                 ..
             } => {
                 if let Some(v) = partial.name {
@@ -627,8 +660,16 @@ impl Channel {
                 if let Some(v) = partial.default_permissions {
                     default_permissions.replace(v);
                 }
+
+                if let Some(v) = partial.sync_permissions {
+                    *sync_permissions = v;
+                }
             }
         }
+
+        if let (Self::TextChannel { archived, .. }, Some(v)) = (self, partial.archived) {
+            *archived = v;
+        }
     }
 
     /// Acknowledge a message
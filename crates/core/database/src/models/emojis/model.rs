@@ -75,6 +75,11 @@ impl Emoji {
         db.detach_emoji(&self).await
     }
 
+    /// Check whether a given string is a permissible unicode emoji
+    pub fn is_unicode(emoji: &str) -> bool {
+        PERMISSIBLE_EMOJIS.contains(emoji)
+    }
+
     /// Check whether we can use a given emoji
     pub async fn can_use(db: &Database, emoji: &str) -> Result<bool> {
         if Ulid::from_str(emoji).is_ok() {
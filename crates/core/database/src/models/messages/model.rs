@@ -15,8 +15,12 @@ use validator::Validate;
 use crate::{
     events::client::EventV1,
     tasks::{self, ack::AckEvent},
-    util::{bulk_permissions::BulkDatabasePermissionQuery, idempotency::IdempotencyKey},
-    Channel, Database, Emoji, File, User, AMQP,
+    util::{
+        automod::{self, AutomodContext, AutomodVerdict},
+        bulk_permissions::BulkDatabasePermissionQuery,
+        idempotency::IdempotencyKey,
+    },
+    Channel, Database, Emoji, File, NotificationSettings, User, AMQP,
 };
 
 auto_derived_partial!(
@@ -106,6 +110,8 @@ auto_derived!(
         MessagePinned { id: String, by: String },
         #[serde(rename = "message_unpinned")]
         MessageUnpinned { id: String, by: String },
+        #[serde(rename = "server_ownership_changed")]
+        ServerOwnershipChanged { from: String, to: String },
     }
 
     /// Name and / or avatar override information
@@ -306,6 +312,33 @@ impl Message {
             ..Default::default()
         };
 
+        // Run the message past the server's automod rules
+        if let (Some(server), false) = (
+            channel.server(),
+            matches!(author, MessageAuthor::System { .. }),
+        ) {
+            let context = AutomodContext {
+                server,
+                channel: &message.channel,
+                author: &message.author,
+                roles: member
+                    .as_ref()
+                    .map(|member| member.roles.as_slice())
+                    .unwrap_or_default(),
+                content: data.content.as_deref().unwrap_or_default(),
+            };
+
+            let triggered = automod::evaluate(db, &context).await?;
+            if !triggered.is_empty()
+                && automod::enforce(db, &context, &triggered).await? == AutomodVerdict::Discard
+            {
+                // Pretend the message was sent so the author is none the wiser
+                message.content = data.content;
+                message.nonce = Some(idempotency.into_key());
+                return Ok(message);
+            }
+        }
+
         // Parse mentions in message.
         let mut mentions = HashSet::new();
         if allow_mentions {
@@ -515,19 +548,47 @@ impl Message {
         .await?;
 
         if !self.has_suppressed_notifications() {
+            // Only notify users whose notification settings allow it,
+            // falling back to every candidate if settings can't be resolved
+            let mentions = self.mentions.as_deref().unwrap_or_default();
+            let candidates = match self.push_candidates(db, channel).await {
+                Ok(candidates) => candidates,
+                Err(err) => {
+                    error!("Failed to find push candidates for {}: {err:?}", self.id);
+                    mentions.to_vec()
+                }
+            };
+
+            let push_recipients = match NotificationSettings::filter_push_recipients(
+                db,
+                channel.server(),
+                channel.id(),
+                mentions,
+                candidates.clone(),
+            )
+            .await
+            {
+                Ok(recipients) => recipients,
+                Err(err) => {
+                    error!("Failed to filter push recipients for {}: {err:?}", self.id);
+                    candidates
+                }
+            };
+
             // send Push notifications
             tasks::ack::queue_message(
                 self.channel.to_string(),
                 AckEvent::ProcessMessage {
                     messages: vec![(
-                        Some(
+                        Some((
                             PushNotification::from(
                                 self.clone().into_model(user, member),
                                 Some(author),
                                 channel.to_owned().into(),
                             )
                             .await,
-                        ),
+                            push_recipients,
+                        )),
                         self.clone(),
                         match channel {
                             Channel::DirectMessage { recipients, .. }
@@ -547,6 +608,49 @@ impl Message {
         Ok(())
     }
 
+    /// Find users who may want a push notification for this message
+    ///
+    /// In server channels, this includes members who asked to be
+    /// notified of every message and can see the channel.
+    async fn push_candidates(&self, db: &Database, channel: &Channel) -> Result<Vec<String>> {
+        match channel {
+            Channel::DirectMessage { recipients, .. } | Channel::Group { recipients, .. } => {
+                Ok(recipients.clone())
+            }
+            Channel::TextChannel { id, server, .. } => {
+                let mut candidates: HashSet<String> =
+                    self.mentions.iter().flatten().cloned().collect();
+
+                let subscribers: Vec<String> = db
+                    .fetch_notification_subscribers(server, id)
+                    .await?
+                    .into_iter()
+                    .map(|settings| settings.id)
+                    .filter(|user| user != &self.author && !candidates.contains(user))
+                    .collect();
+
+                if !subscribers.is_empty() {
+                    let members = db.fetch_members(server, &subscribers).await?;
+                    let can_see_channel = BulkDatabasePermissionQuery::from_server_id(db, server)
+                        .await
+                        .channel(channel)
+                        .members(&members)
+                        .members_can_see_channel()
+                        .await;
+
+                    candidates.extend(
+                        subscribers
+                            .into_iter()
+                            .filter(|user| *can_see_channel.get(user).unwrap_or(&false)),
+                    );
+                }
+
+                Ok(candidates.into_iter().collect())
+            }
+            _ => Ok(vec![]),
+        }
+    }
+
     /// Create text embed from sendable embed
     pub async fn create_embed(&self, db: &Database, embed: SendableEmbed) -> Result<Embed> {
         embed.validate().map_err(|error| {
@@ -571,6 +675,13 @@ impl Message {
         }))
     }
 
+    /// Lowest message id which could be assigned at the given Unix time in milliseconds
+    ///
+    /// Message ids are ULIDs, so ranges of time can be bounded by id alone.
+    pub fn id_from_time(time_ms: u64) -> String {
+        Ulid::from_parts(time_ms, 0).to_string()
+    }
+
     /// Whether this message has suppressed notifications
     pub fn has_suppressed_notifications(&self) -> bool {
         if let Some(flags) = self.flags {
@@ -635,7 +746,8 @@ impl Message {
                                 users.push(by.clone())
                             }
                             v0::SystemMessage::ChannelIconChanged { by } => users.push(by.clone()),
-                            v0::SystemMessage::ChannelOwnershipChanged { from, to, .. } => {
+                            v0::SystemMessage::ChannelOwnershipChanged { from, to, .. }
+                            | v0::SystemMessage::ServerOwnershipChanged { from, to, .. } => {
                                 users.push(from.clone());
                                 users.push(to.clone())
                             }
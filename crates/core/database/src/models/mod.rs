@@ -1,4 +1,6 @@
 mod admin_migrations;
+mod audit_logs;
+mod automod_rules;
 mod bots;
 mod channel_invites;
 mod channel_unreads;
@@ -8,16 +10,25 @@ mod emojis;
 mod file_hashes;
 mod files;
 mod messages;
+mod moderation_records;
+mod notification_settings;
+mod prune_jobs;
 mod ratelimit_events;
+mod role_assignment_jobs;
 mod safety_reports;
 mod safety_snapshots;
 mod server_bans;
+mod server_events;
+mod server_insights;
 mod server_members;
+mod server_templates;
 mod servers;
 mod user_settings;
 mod users;
 
 pub use admin_migrations::*;
+pub use audit_logs::*;
+pub use automod_rules::*;
 pub use bots::*;
 pub use channel_invites::*;
 pub use channel_unreads::*;
@@ -27,11 +38,18 @@ pub use emojis::*;
 pub use file_hashes::*;
 pub use files::*;
 pub use messages::*;
+pub use moderation_records::*;
+pub use notification_settings::*;
+pub use prune_jobs::*;
 pub use ratelimit_events::*;
+pub use role_assignment_jobs::*;
 pub use safety_reports::*;
 pub use safety_snapshots::*;
 pub use server_bans::*;
+pub use server_events::*;
+pub use server_insights::*;
 pub use server_members::*;
+pub use server_templates::*;
 pub use servers::*;
 pub use user_settings::*;
 pub use users::*;
@@ -42,6 +60,8 @@ pub trait AbstractDatabase:
     Sync
     + Send
     + admin_migrations::AbstractMigrations
+    + audit_logs::AbstractAuditLogs
+    + automod_rules::AbstractAutomodRules
     + bots::AbstractBots
     + channels::AbstractChannels
     + channel_invites::AbstractChannelInvites
@@ -51,11 +71,18 @@ pub trait AbstractDatabase:
     + file_hashes::AbstractAttachmentHashes
     + files::AbstractAttachments
     + messages::AbstractMessages
+    + moderation_records::AbstractModerationRecords
+    + notification_settings::AbstractNotificationSettings
+    + prune_jobs::AbstractPruneJobs
     + ratelimit_events::AbstractRatelimitEvents
+    + role_assignment_jobs::AbstractRoleAssignmentJobs
     + safety_reports::AbstractReport
     + safety_snapshots::AbstractSnapshot
     + server_bans::AbstractServerBans
+    + server_events::AbstractServerEvents
+    + server_insights::AbstractServerInsights
     + server_members::AbstractServerMembers
+    + server_templates::AbstractServerTemplates
     + servers::AbstractServers
     + user_settings::AbstractUserSettings
     + users::AbstractUsers
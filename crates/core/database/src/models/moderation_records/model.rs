@@ -0,0 +1,65 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::Database;
+
+auto_derived!(
+    /// Moderation action taken against a server member
+    pub struct ModerationRecord {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server this action was taken in
+        pub server: String,
+        /// Id of the user this action was taken against
+        pub user: String,
+        /// Id of the moderator who took this action
+        ///
+        /// Not present if the action was taken by automod.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub moderator: Option<String>,
+        /// Action that was taken
+        pub action: ModerationAction,
+        /// Reason given for this action
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+        /// Time at which this timeout or ban lapses
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub expires_at: Option<Timestamp>,
+    }
+
+    /// Moderation action taken against a server member
+    pub enum ModerationAction {
+        Timeout,
+        Kick,
+        Ban,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl ModerationRecord {
+    /// Record a new moderation action in a member's history
+    pub async fn create(
+        db: &Database,
+        server: &str,
+        user: &str,
+        moderator: Option<&str>,
+        action: ModerationAction,
+        reason: Option<String>,
+        expires_at: Option<Timestamp>,
+    ) -> Result<ModerationRecord> {
+        let record = ModerationRecord {
+            id: Ulid::new().to_string(),
+            server: server.to_string(),
+            user: user.to_string(),
+            moderator: moderator.map(|moderator| moderator.to_string()),
+            action,
+            reason,
+            expires_at,
+        };
+
+        db.insert_moderation_record(&record).await?;
+        Ok(record)
+    }
+}
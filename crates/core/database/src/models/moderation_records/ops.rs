@@ -0,0 +1,21 @@
+use revolt_result::Result;
+
+use crate::ModerationRecord;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractModerationRecords: Sync + Send {
+    /// Insert a new moderation record into the database
+    async fn insert_moderation_record(&self, record: &ModerationRecord) -> Result<()>;
+
+    /// Fetch moderation records for a member, newest first
+    async fn fetch_moderation_records(
+        &self,
+        server_id: &str,
+        user_id: &str,
+        before: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<ModerationRecord>>;
+}
@@ -0,0 +1,48 @@
+use mongodb::options::FindOptions;
+use revolt_result::Result;
+
+use crate::ModerationRecord;
+use crate::MongoDb;
+
+use super::AbstractModerationRecords;
+
+static COL: &str = "moderation_records";
+
+#[async_trait]
+impl AbstractModerationRecords for MongoDb {
+    /// Insert a new moderation record into the database
+    async fn insert_moderation_record(&self, record: &ModerationRecord) -> Result<()> {
+        query!(self, insert_one, COL, &record).map(|_| ())
+    }
+
+    /// Fetch moderation records for a member, newest first
+    async fn fetch_moderation_records(
+        &self,
+        server_id: &str,
+        user_id: &str,
+        before: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<ModerationRecord>> {
+        let mut filter = doc! {
+            "server": server_id,
+            "user": user_id
+        };
+
+        if let Some(before) = before {
+            filter.insert("_id", doc! { "$lt": before });
+        }
+
+        self.find_with_options(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(limit)
+                .sort(doc! {
+                    "_id": -1_i32
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+}
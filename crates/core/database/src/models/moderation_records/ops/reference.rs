@@ -0,0 +1,44 @@
+use revolt_result::Result;
+
+use crate::ModerationRecord;
+use crate::ReferenceDb;
+
+use super::AbstractModerationRecords;
+
+#[async_trait]
+impl AbstractModerationRecords for ReferenceDb {
+    /// Insert a new moderation record into the database
+    async fn insert_moderation_record(&self, record: &ModerationRecord) -> Result<()> {
+        let mut moderation_records = self.moderation_records.lock().await;
+        if moderation_records.contains_key(&record.id) {
+            Err(create_database_error!("insert", "moderation_record"))
+        } else {
+            moderation_records.insert(record.id.to_string(), record.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch moderation records for a member, newest first
+    async fn fetch_moderation_records(
+        &self,
+        server_id: &str,
+        user_id: &str,
+        before: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<ModerationRecord>> {
+        let moderation_records = self.moderation_records.lock().await;
+        let mut records: Vec<ModerationRecord> = moderation_records
+            .values()
+            .filter(|record| {
+                record.server == server_id
+                    && record.user == user_id
+                    && before.as_ref().map_or(true, |v| &record.id < v)
+            })
+            .cloned()
+            .collect();
+
+        records.sort_by(|a, b| b.id.cmp(&a.id));
+        records.truncate(limit as usize);
+        Ok(records)
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::{events::client::EventV1, Database};
+
+auto_derived!(
+    /// Which messages a user wants to be notified about
+    pub enum NotificationLevel {
+        /// Every message
+        All,
+        /// Only messages which mention the user
+        Mentions,
+        /// No messages at all
+        None,
+    }
+
+    /// Notification preference for a single server or channel
+    #[derive(Default)]
+    pub struct NotificationPreference {
+        /// Which messages to be notified about
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub level: Option<NotificationLevel>,
+        /// Time until which notifications are muted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub muted_until: Option<Timestamp>,
+    }
+
+    /// User's notification settings
+    #[derive(Default)]
+    pub struct NotificationSettings {
+        /// Id of the user these settings belong to
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Preferences for entire servers
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub servers: HashMap<String, NotificationPreference>,
+        /// Preferences for individual channels, taking priority over servers
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub channels: HashMap<String, NotificationPreference>,
+    }
+);
+
+impl NotificationPreference {
+    /// Whether notifications are currently muted
+    pub fn is_muted(&self) -> bool {
+        self.muted_until
+            .is_some_and(|muted_until| *muted_until > *Timestamp::now_utc())
+    }
+}
+
+impl NotificationSettings {
+    /// Save notification settings
+    pub async fn set(self, db: &Database) -> Result<()> {
+        db.set_notification_settings(&self).await?;
+
+        EventV1::UserNotificationSettingsUpdate {
+            id: self.id.clone(),
+            settings: self.clone().into(),
+        }
+        .private(self.id)
+        .await;
+
+        Ok(())
+    }
+
+    /// Resolve which messages the user wants to be notified about in a channel
+    ///
+    /// Direct messages and groups notify for everything by default,
+    /// server channels only notify for mentions.
+    pub fn level(&self, server: Option<&str>, channel: &str) -> NotificationLevel {
+        self.channels
+            .get(channel)
+            .and_then(|preference| preference.level.clone())
+            .or_else(|| {
+                server
+                    .and_then(|server| self.servers.get(server))
+                    .and_then(|preference| preference.level.clone())
+            })
+            .unwrap_or(if server.is_some() {
+                NotificationLevel::Mentions
+            } else {
+                NotificationLevel::All
+            })
+    }
+
+    /// Whether the channel or its server is currently muted
+    pub fn is_muted(&self, server: Option<&str>, channel: &str) -> bool {
+        self.channels
+            .get(channel)
+            .is_some_and(NotificationPreference::is_muted)
+            || server
+                .and_then(|server| self.servers.get(server))
+                .is_some_and(NotificationPreference::is_muted)
+    }
+
+    /// Whether a push notification should be sent for a message in a channel
+    pub fn should_push(&self, server: Option<&str>, channel: &str, mentioned: bool) -> bool {
+        if self.is_muted(server, channel) {
+            return false;
+        }
+
+        match self.level(server, channel) {
+            NotificationLevel::All => true,
+            NotificationLevel::Mentions => mentioned,
+            NotificationLevel::None => false,
+        }
+    }
+
+    /// Filter push notification recipients down to users who want to be notified
+    pub async fn filter_push_recipients(
+        db: &Database,
+        server: Option<&str>,
+        channel: &str,
+        mentions: &[String],
+        users: Vec<String>,
+    ) -> Result<Vec<String>> {
+        if users.is_empty() {
+            return Ok(users);
+        }
+
+        let settings: HashMap<String, NotificationSettings> = db
+            .fetch_many_notification_settings(&users)
+            .await?
+            .into_iter()
+            .map(|settings| (settings.id.clone(), settings))
+            .collect();
+
+        let default = NotificationSettings::default();
+        Ok(users
+            .into_iter()
+            .filter(|user| {
+                settings.get(user).unwrap_or(&default).should_push(
+                    server,
+                    channel,
+                    mentions.contains(user),
+                )
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso8601_timestamp::{Duration, Timestamp};
+
+    use crate::{NotificationLevel, NotificationPreference, NotificationSettings};
+
+    #[test]
+    fn defaults() {
+        let settings = NotificationSettings::default();
+
+        assert!(settings.should_push(None, "dm", false));
+        assert!(!settings.should_push(Some("server"), "channel", false));
+        assert!(settings.should_push(Some("server"), "channel", true));
+    }
+
+    #[test]
+    fn channel_overrides_server() {
+        let mut settings = NotificationSettings::default();
+        settings.servers.insert(
+            "server".to_string(),
+            NotificationPreference {
+                level: Some(NotificationLevel::None),
+                muted_until: None,
+            },
+        );
+
+        settings.channels.insert(
+            "channel".to_string(),
+            NotificationPreference {
+                level: Some(NotificationLevel::All),
+                muted_until: None,
+            },
+        );
+
+        assert!(settings.should_push(Some("server"), "channel", false));
+        assert!(!settings.should_push(Some("server"), "other", true));
+    }
+
+    #[test]
+    fn mute_until() {
+        let mut settings = NotificationSettings::default();
+        settings.servers.insert(
+            "server".to_string(),
+            NotificationPreference {
+                level: None,
+                muted_until: Timestamp::now_utc().checked_add(Duration::seconds(60)),
+            },
+        );
+
+        settings.channels.insert(
+            "expired".to_string(),
+            NotificationPreference {
+                level: None,
+                muted_until: Timestamp::now_utc().checked_sub(Duration::seconds(60)),
+            },
+        );
+
+        assert!(!settings.should_push(Some("server"), "channel", true));
+        assert!(settings.should_push(None, "expired", true));
+    }
+}
@@ -0,0 +1,30 @@
+use revolt_result::Result;
+
+use crate::NotificationSettings;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractNotificationSettings: Sync + Send {
+    /// Fetch a user's notification settings
+    async fn fetch_notification_settings(&self, user_id: &str) -> Result<NotificationSettings>;
+
+    /// Fetch notification settings for many users
+    ///
+    /// Users without any settings are omitted.
+    async fn fetch_many_notification_settings(
+        &self,
+        user_ids: &[String],
+    ) -> Result<Vec<NotificationSettings>>;
+
+    /// Fetch settings of users who want to be notified of every message in a channel
+    async fn fetch_notification_subscribers(
+        &self,
+        server_id: &str,
+        channel_id: &str,
+    ) -> Result<Vec<NotificationSettings>>;
+
+    /// Save a user's notification settings
+    async fn set_notification_settings(&self, settings: &NotificationSettings) -> Result<()>;
+}
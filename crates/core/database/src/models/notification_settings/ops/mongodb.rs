@@ -0,0 +1,82 @@
+use bson::{to_bson, Document};
+use mongodb::options::UpdateOptions;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::NotificationSettings;
+
+use super::AbstractNotificationSettings;
+
+static COL: &str = "notification_settings";
+
+#[async_trait]
+impl AbstractNotificationSettings for MongoDb {
+    /// Fetch a user's notification settings
+    async fn fetch_notification_settings(&self, user_id: &str) -> Result<NotificationSettings> {
+        let settings: Option<NotificationSettings> = query!(self, find_one_by_id, COL, user_id)?;
+        Ok(settings.unwrap_or_else(|| NotificationSettings {
+            id: user_id.to_string(),
+            ..Default::default()
+        }))
+    }
+
+    /// Fetch notification settings for many users
+    async fn fetch_many_notification_settings(
+        &self,
+        user_ids: &[String],
+    ) -> Result<Vec<NotificationSettings>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "_id": {
+                    "$in": user_ids
+                }
+            }
+        )
+    }
+
+    /// Fetch settings of users who want to be notified of every message in a channel
+    async fn fetch_notification_subscribers(
+        &self,
+        server_id: &str,
+        channel_id: &str,
+    ) -> Result<Vec<NotificationSettings>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "$or": [
+                    {
+                        format!("servers.{server_id}.level"): "All"
+                    },
+                    {
+                        format!("channels.{channel_id}.level"): "All"
+                    }
+                ]
+            }
+        )
+    }
+
+    /// Save a user's notification settings
+    async fn set_notification_settings(&self, settings: &NotificationSettings) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": &settings.id
+                },
+                doc! {
+                    "$set": {
+                        "servers": to_bson(&settings.servers).unwrap(),
+                        "channels": to_bson(&settings.channels).unwrap()
+                    }
+                },
+                UpdateOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+}
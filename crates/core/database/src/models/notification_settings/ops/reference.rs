@@ -0,0 +1,63 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{NotificationLevel, NotificationSettings};
+
+use super::AbstractNotificationSettings;
+
+#[async_trait]
+impl AbstractNotificationSettings for ReferenceDb {
+    /// Fetch a user's notification settings
+    async fn fetch_notification_settings(&self, user_id: &str) -> Result<NotificationSettings> {
+        let notification_settings = self.notification_settings.lock().await;
+        Ok(notification_settings
+            .get(user_id)
+            .cloned()
+            .unwrap_or_else(|| NotificationSettings {
+                id: user_id.to_string(),
+                ..Default::default()
+            }))
+    }
+
+    /// Fetch notification settings for many users
+    async fn fetch_many_notification_settings(
+        &self,
+        user_ids: &[String],
+    ) -> Result<Vec<NotificationSettings>> {
+        let notification_settings = self.notification_settings.lock().await;
+        Ok(user_ids
+            .iter()
+            .filter_map(|id| notification_settings.get(id))
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch settings of users who want to be notified of every message in a channel
+    async fn fetch_notification_subscribers(
+        &self,
+        server_id: &str,
+        channel_id: &str,
+    ) -> Result<Vec<NotificationSettings>> {
+        let notification_settings = self.notification_settings.lock().await;
+        Ok(notification_settings
+            .values()
+            .filter(|settings| {
+                [
+                    settings.servers.get(server_id),
+                    settings.channels.get(channel_id),
+                ]
+                .into_iter()
+                .flatten()
+                .any(|preference| preference.level == Some(NotificationLevel::All))
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Save a user's notification settings
+    async fn set_notification_settings(&self, settings: &NotificationSettings) -> Result<()> {
+        let mut notification_settings = self.notification_settings.lock().await;
+        notification_settings.insert(settings.id.to_string(), settings.clone());
+        Ok(())
+    }
+}
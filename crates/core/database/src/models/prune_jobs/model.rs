@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::{Database, Member};
+
+/// Number of members kicked at a time
+static BATCH_SIZE: usize = 100;
+
+auto_derived_partial!(
+    /// Member prune job
+    pub struct PruneJob {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server this job belongs to
+        pub server: String,
+        /// Id of the user who started this job
+        pub creator: String,
+        /// Ids of the members being kicked
+        pub members: Vec<String>,
+
+        /// Number of members processed so far
+        #[serde(default)]
+        pub processed: usize,
+        /// Number of members kicked so far
+        #[serde(default)]
+        pub kicked: usize,
+        /// Current state of this job
+        pub status: PruneStatus,
+    },
+    "PartialPruneJob"
+);
+
+auto_derived!(
+    /// State of a prune job
+    pub enum PruneStatus {
+        Pending,
+        Running,
+        Completed,
+        Failed,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl PruneJob {
+    /// Create a new prune job, it will be picked up by crond
+    pub async fn create(
+        db: &Database,
+        server: &str,
+        creator: &str,
+        members: Vec<String>,
+    ) -> Result<PruneJob> {
+        let job = PruneJob {
+            id: Ulid::new().to_string(),
+            server: server.to_string(),
+            creator: creator.to_string(),
+            members,
+            processed: 0,
+            kicked: 0,
+            status: PruneStatus::Pending,
+        };
+
+        db.insert_prune_job(&job).await?;
+        Ok(job)
+    }
+
+    /// Kick this job's remaining members in batches, recording progress as it goes
+    ///
+    /// Jobs which were interrupted resume from the last recorded batch.
+    pub async fn run(mut self, db: &Database) -> Result<()> {
+        let server = match db.fetch_server(&self.server).await {
+            Ok(server) => server,
+            Err(err) => {
+                self.save(db, PruneStatus::Failed).await?;
+                return Err(err);
+            }
+        };
+
+        while self.processed < self.members.len() {
+            let end = (self.processed + BATCH_SIZE).min(self.members.len());
+            let batch = self.members[self.processed..end].to_vec();
+
+            match Member::prune(db, &server, batch).await {
+                Ok(count) => self.kicked += count,
+                Err(err) => {
+                    self.save(db, PruneStatus::Failed).await?;
+                    return Err(err);
+                }
+            }
+
+            self.processed = end;
+            self.save(db, PruneStatus::Running).await?;
+
+            async_std::task::sleep(Duration::from_secs(1)).await;
+        }
+
+        self.save(db, PruneStatus::Completed).await
+    }
+
+    /// Record this job's progress
+    async fn save(&mut self, db: &Database, status: PruneStatus) -> Result<()> {
+        db.update_prune_job(
+            &self.id,
+            &PartialPruneJob {
+                processed: Some(self.processed),
+                kicked: Some(self.kicked),
+                status: Some(status.clone()),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        self.status = status;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fixture, PruneJob, PruneStatus};
+
+    #[async_std::test]
+    async fn resume() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_roles",
+                owner user 0
+                moderator user 1
+                user user 2
+                server server 4);
+
+            let job = PruneJob::create(
+                &db,
+                &server.id,
+                &owner.id,
+                vec![moderator.id.clone(), user.id.clone()],
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(
+                db.fetch_unfinished_prune_jobs().await.unwrap(),
+                vec![job.clone()]
+            );
+
+            // Simulate a job which was interrupted after its first member
+            let job = PruneJob {
+                processed: 1,
+                status: PruneStatus::Running,
+                ..job
+            };
+
+            job.clone().run(&db).await.unwrap();
+
+            let job = db.fetch_prune_job(&job.id).await.unwrap();
+            assert_eq!(job.status, PruneStatus::Completed);
+            assert_eq!(job.processed, 2);
+            assert_eq!(job.kicked, 1);
+
+            assert!(db.fetch_member(&server.id, &moderator.id).await.is_ok());
+            assert!(db.fetch_member(&server.id, &user.id).await.is_err());
+            assert!(db.fetch_unfinished_prune_jobs().await.unwrap().is_empty());
+        });
+    }
+}
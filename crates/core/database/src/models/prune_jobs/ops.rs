@@ -0,0 +1,21 @@
+use revolt_result::Result;
+
+use crate::{PartialPruneJob, PruneJob};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractPruneJobs: Sync + Send {
+    /// Insert a new prune job into the database
+    async fn insert_prune_job(&self, job: &PruneJob) -> Result<()>;
+
+    /// Fetch a prune job by its id
+    async fn fetch_prune_job(&self, id: &str) -> Result<PruneJob>;
+
+    /// Fetch prune jobs which are pending or were interrupted
+    async fn fetch_unfinished_prune_jobs(&self) -> Result<Vec<PruneJob>>;
+
+    /// Update a prune job with new information
+    async fn update_prune_job(&self, id: &str, partial: &PartialPruneJob) -> Result<()>;
+}
@@ -0,0 +1,40 @@
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{PartialPruneJob, PruneJob};
+
+use super::AbstractPruneJobs;
+
+static COL: &str = "prune_jobs";
+
+#[async_trait]
+impl AbstractPruneJobs for MongoDb {
+    /// Insert a new prune job into the database
+    async fn insert_prune_job(&self, job: &PruneJob) -> Result<()> {
+        query!(self, insert_one, COL, &job).map(|_| ())
+    }
+
+    /// Fetch a prune job by its id
+    async fn fetch_prune_job(&self, id: &str) -> Result<PruneJob> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch prune jobs which are pending or were interrupted
+    async fn fetch_unfinished_prune_jobs(&self) -> Result<Vec<PruneJob>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "status": {
+                    "$in": ["Pending", "Running"]
+                }
+            }
+        )
+    }
+
+    /// Update a prune job with new information
+    async fn update_prune_job(&self, id: &str, partial: &PartialPruneJob) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+}
@@ -0,0 +1,50 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{PartialPruneJob, PruneJob, PruneStatus};
+
+use super::AbstractPruneJobs;
+
+#[async_trait]
+impl AbstractPruneJobs for ReferenceDb {
+    /// Insert a new prune job into the database
+    async fn insert_prune_job(&self, job: &PruneJob) -> Result<()> {
+        let mut prune_jobs = self.prune_jobs.lock().await;
+        if prune_jobs.contains_key(&job.id) {
+            Err(create_database_error!("insert", "prune_job"))
+        } else {
+            prune_jobs.insert(job.id.to_string(), job.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a prune job by its id
+    async fn fetch_prune_job(&self, id: &str) -> Result<PruneJob> {
+        let prune_jobs = self.prune_jobs.lock().await;
+        prune_jobs
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch prune jobs which are pending or were interrupted
+    async fn fetch_unfinished_prune_jobs(&self) -> Result<Vec<PruneJob>> {
+        let prune_jobs = self.prune_jobs.lock().await;
+        Ok(prune_jobs
+            .values()
+            .filter(|job| matches!(job.status, PruneStatus::Pending | PruneStatus::Running))
+            .cloned()
+            .collect())
+    }
+
+    /// Update a prune job with new information
+    async fn update_prune_job(&self, id: &str, partial: &PartialPruneJob) -> Result<()> {
+        let mut prune_jobs = self.prune_jobs.lock().await;
+        if let Some(job) = prune_jobs.get_mut(id) {
+            job.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::{events::client::EventV1, Database, Member, PartialMember};
+
+/// Number of members updated at a time
+static BATCH_SIZE: usize = 250;
+
+auto_derived_partial!(
+    /// Bulk role assignment job
+    pub struct RoleAssignmentJob {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server this job belongs to
+        pub server: String,
+        /// Id of the role being assigned or removed
+        pub role: String,
+        /// Id of the user who started this job
+        pub creator: String,
+        /// Whether the role is being assigned or removed
+        pub action: RoleAssignmentAction,
+        /// Ids of the members this job applies to
+        #[serde(default)]
+        pub members: Vec<String>,
+
+        /// Number of members this job applies to
+        pub total: usize,
+        /// Number of members processed so far
+        #[serde(default)]
+        pub processed: usize,
+        /// Current state of this job
+        pub status: RoleAssignmentStatus,
+        /// Time at which progress was last recorded
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub updated_at: Option<Timestamp>,
+    },
+    "PartialRoleAssignmentJob"
+);
+
+auto_derived!(
+    /// Change made to each member's roles
+    pub enum RoleAssignmentAction {
+        Add,
+        Remove,
+    }
+
+    /// State of a role assignment job
+    pub enum RoleAssignmentStatus {
+        Running,
+        Completed,
+        Failed,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl RoleAssignmentJob {
+    /// Create a new role assignment job
+    pub async fn create(
+        db: &Database,
+        server: &str,
+        role: &str,
+        creator: &str,
+        action: RoleAssignmentAction,
+        members: Vec<String>,
+    ) -> Result<RoleAssignmentJob> {
+        let job = RoleAssignmentJob {
+            id: Ulid::new().to_string(),
+            server: server.to_string(),
+            role: role.to_string(),
+            creator: creator.to_string(),
+            action,
+            total: members.len(),
+            members,
+            processed: 0,
+            status: RoleAssignmentStatus::Running,
+            updated_at: Some(Timestamp::now_utc()),
+        };
+
+        db.insert_role_assignment_job(&job).await?;
+        Ok(job)
+    }
+
+    /// Apply this job to its remaining members in batches, recording progress as it goes
+    pub async fn run(self, db: &Database) -> Result<()> {
+        self.run_in_batches(db, BATCH_SIZE).await
+    }
+
+    /// Resume a job which was interrupted
+    ///
+    /// Jobs created before member ids were stored cannot be resumed and are failed instead.
+    pub async fn resume(mut self, db: &Database) -> Result<()> {
+        if self.members.len() != self.total {
+            return self.save(db, RoleAssignmentStatus::Failed).await;
+        }
+
+        self.run(db).await
+    }
+
+    /// Apply this job to its remaining members in batches of a given size
+    async fn run_in_batches(mut self, db: &Database, batch_size: usize) -> Result<()> {
+        let members = std::mem::take(&mut self.members);
+        for batch in members[self.processed.min(members.len())..].chunks(batch_size) {
+            if let Err(err) = self.apply(db, batch).await {
+                self.save(db, RoleAssignmentStatus::Failed).await?;
+                return Err(err);
+            }
+
+            self.processed += batch.len();
+            self.save(db, RoleAssignmentStatus::Running).await?;
+        }
+
+        self.save(db, RoleAssignmentStatus::Completed).await
+    }
+
+    /// Apply the role change to a single batch of members
+    async fn apply(&self, db: &Database, user_ids: &[String]) -> Result<()> {
+        let members: Vec<Member> = db
+            .fetch_members(&self.server, user_ids)
+            .await?
+            .into_iter()
+            .filter(|member| self.action.changes(member, &self.role))
+            .collect();
+
+        if members.is_empty() {
+            return Ok(());
+        }
+
+        let ids: Vec<String> = members
+            .iter()
+            .map(|member| member.id.user.clone())
+            .collect();
+
+        match self.action {
+            RoleAssignmentAction::Add => {
+                db.add_role_to_members(&self.server, &self.role, &ids)
+                    .await?
+            }
+            RoleAssignmentAction::Remove => {
+                db.remove_role_from_members(&self.server, &self.role, &ids)
+                    .await?
+            }
+        }
+
+        EventV1::Bulk {
+            v: members
+                .into_iter()
+                .map(|mut member| {
+                    match self.action {
+                        RoleAssignmentAction::Add => member.roles.push(self.role.clone()),
+                        RoleAssignmentAction::Remove => member.roles.retain(|id| id != &self.role),
+                    }
+
+                    EventV1::ServerMemberUpdate {
+                        id: member.id.into(),
+                        data: PartialMember {
+                            roles: Some(member.roles),
+                            ..Default::default()
+                        }
+                        .into(),
+                        clear: vec![],
+                    }
+                })
+                .collect(),
+        }
+        .p(self.server.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Record this job's progress
+    async fn save(&mut self, db: &Database, status: RoleAssignmentStatus) -> Result<()> {
+        db.update_role_assignment_job(
+            &self.id,
+            &PartialRoleAssignmentJob {
+                processed: Some(self.processed),
+                status: Some(status.clone()),
+                updated_at: Some(Timestamp::now_utc()),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        self.status = status;
+        Ok(())
+    }
+}
+
+impl RoleAssignmentAction {
+    /// Check whether this action would change a member's roles
+    pub fn changes(&self, member: &Member, role: &str) -> bool {
+        let has_role = member.roles.iter().any(|id| id == role);
+        match self {
+            RoleAssignmentAction::Add => !has_role,
+            RoleAssignmentAction::Remove => has_role,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso8601_timestamp::Timestamp;
+
+    use crate::{fixture, Member, RoleAssignmentAction, RoleAssignmentJob, RoleAssignmentStatus};
+
+    #[test]
+    fn changes() {
+        let member = Member {
+            roles: vec!["role".to_string()],
+            ..Default::default()
+        };
+
+        assert!(!RoleAssignmentAction::Add.changes(&member, "role"));
+        assert!(RoleAssignmentAction::Add.changes(&member, "other"));
+        assert!(RoleAssignmentAction::Remove.changes(&member, "role"));
+        assert!(!RoleAssignmentAction::Remove.changes(&member, "other"));
+    }
+
+    #[async_std::test]
+    async fn run() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_roles",
+                owner user 0
+                moderator user 1
+                user user 2
+                server server 4);
+
+            let role = server
+                .roles
+                .iter()
+                .find(|(_, role)| role.name == "Moderator")
+                .map(|(id, _)| id.clone())
+                .unwrap();
+
+            let members = vec![owner.id.clone(), moderator.id.clone(), user.id.clone()];
+            let has_role = |id: String| {
+                let db = db.clone();
+                let server = server.id.clone();
+                let role = role.clone();
+                async move {
+                    db.fetch_member(&server, &id)
+                        .await
+                        .unwrap()
+                        .roles
+                        .contains(&role)
+                }
+            };
+
+            // Members are processed in batches, recording progress after each one
+            let job = RoleAssignmentJob::create(
+                &db,
+                &server.id,
+                &role,
+                &owner.id,
+                RoleAssignmentAction::Add,
+                members.clone(),
+            )
+            .await
+            .unwrap();
+
+            job.clone().run_in_batches(&db, 2).await.unwrap();
+
+            let job = db.fetch_role_assignment_job(&job.id).await.unwrap();
+            assert_eq!(job.status, RoleAssignmentStatus::Completed);
+            assert_eq!(job.total, 3);
+            assert_eq!(job.processed, 3);
+
+            for id in &members {
+                assert!(has_role(id.clone()).await);
+            }
+
+            // Orphaned jobs resume from their last recorded batch
+            let job = RoleAssignmentJob::create(
+                &db,
+                &server.id,
+                &role,
+                &owner.id,
+                RoleAssignmentAction::Remove,
+                members,
+            )
+            .await
+            .unwrap();
+
+            let stale: Vec<String> = db
+                .fetch_stale_role_assignment_jobs(Timestamp::now_utc())
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|job| job.id)
+                .collect();
+            assert_eq!(stale, vec![job.id.clone()]);
+
+            RoleAssignmentJob {
+                processed: 1,
+                ..job.clone()
+            }
+            .resume(&db)
+            .await
+            .unwrap();
+
+            let job = db.fetch_role_assignment_job(&job.id).await.unwrap();
+            assert_eq!(job.status, RoleAssignmentStatus::Completed);
+            assert_eq!(job.processed, 3);
+
+            assert!(has_role(owner.id.clone()).await);
+            assert!(!has_role(moderator.id.clone()).await);
+            assert!(!has_role(user.id.clone()).await);
+
+            assert!(db
+                .fetch_stale_role_assignment_jobs(Timestamp::now_utc())
+                .await
+                .unwrap()
+                .is_empty());
+        });
+    }
+}
@@ -0,0 +1,29 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::{PartialRoleAssignmentJob, RoleAssignmentJob};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractRoleAssignmentJobs: Sync + Send {
+    /// Insert a new role assignment job into the database
+    async fn insert_role_assignment_job(&self, job: &RoleAssignmentJob) -> Result<()>;
+
+    /// Fetch a role assignment job by its id
+    async fn fetch_role_assignment_job(&self, id: &str) -> Result<RoleAssignmentJob>;
+
+    /// Fetch running role assignment jobs which have not recorded progress since a given time
+    async fn fetch_stale_role_assignment_jobs(
+        &self,
+        before: Timestamp,
+    ) -> Result<Vec<RoleAssignmentJob>>;
+
+    /// Update a role assignment job with new information
+    async fn update_role_assignment_job(
+        &self,
+        id: &str,
+        partial: &PartialRoleAssignmentJob,
+    ) -> Result<()>;
+}
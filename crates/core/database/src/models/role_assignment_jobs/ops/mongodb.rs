@@ -0,0 +1,59 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{PartialRoleAssignmentJob, RoleAssignmentJob};
+
+use super::AbstractRoleAssignmentJobs;
+
+static COL: &str = "role_assignment_jobs";
+
+#[async_trait]
+impl AbstractRoleAssignmentJobs for MongoDb {
+    /// Insert a new role assignment job into the database
+    async fn insert_role_assignment_job(&self, job: &RoleAssignmentJob) -> Result<()> {
+        query!(self, insert_one, COL, &job).map(|_| ())
+    }
+
+    /// Fetch a role assignment job by its id
+    async fn fetch_role_assignment_job(&self, id: &str) -> Result<RoleAssignmentJob> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch running role assignment jobs which have not recorded progress since a given time
+    async fn fetch_stale_role_assignment_jobs(
+        &self,
+        before: Timestamp,
+    ) -> Result<Vec<RoleAssignmentJob>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "status": "Running",
+                "$or": [
+                    {
+                        "updated_at": {
+                            "$lte": bson::to_bson(&before)
+                                .map_err(|_| create_database_error!("to_bson", "updated_at"))?
+                        }
+                    },
+                    {
+                        "updated_at": {
+                            "$exists": false
+                        }
+                    }
+                ]
+            }
+        )
+    }
+
+    /// Update a role assignment job with new information
+    async fn update_role_assignment_job(
+        &self,
+        id: &str,
+        partial: &PartialRoleAssignmentJob,
+    ) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+}
@@ -0,0 +1,63 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{PartialRoleAssignmentJob, RoleAssignmentJob, RoleAssignmentStatus};
+
+use super::AbstractRoleAssignmentJobs;
+
+#[async_trait]
+impl AbstractRoleAssignmentJobs for ReferenceDb {
+    /// Insert a new role assignment job into the database
+    async fn insert_role_assignment_job(&self, job: &RoleAssignmentJob) -> Result<()> {
+        let mut role_assignment_jobs = self.role_assignment_jobs.lock().await;
+        if role_assignment_jobs.contains_key(&job.id) {
+            Err(create_database_error!("insert", "role_assignment_job"))
+        } else {
+            role_assignment_jobs.insert(job.id.to_string(), job.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a role assignment job by its id
+    async fn fetch_role_assignment_job(&self, id: &str) -> Result<RoleAssignmentJob> {
+        let role_assignment_jobs = self.role_assignment_jobs.lock().await;
+        role_assignment_jobs
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch running role assignment jobs which have not recorded progress since a given time
+    async fn fetch_stale_role_assignment_jobs(
+        &self,
+        before: Timestamp,
+    ) -> Result<Vec<RoleAssignmentJob>> {
+        let role_assignment_jobs = self.role_assignment_jobs.lock().await;
+        Ok(role_assignment_jobs
+            .values()
+            .filter(|job| {
+                job.status == RoleAssignmentStatus::Running
+                    && job
+                        .updated_at
+                        .map_or(true, |updated_at| *updated_at <= *before)
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Update a role assignment job with new information
+    async fn update_role_assignment_job(
+        &self,
+        id: &str,
+        partial: &PartialRoleAssignmentJob,
+    ) -> Result<()> {
+        let mut role_assignment_jobs = self.role_assignment_jobs.lock().await;
+        if let Some(job) = role_assignment_jobs.get_mut(id) {
+            job.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
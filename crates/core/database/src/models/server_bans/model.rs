@@ -1,6 +1,7 @@
+use iso8601_timestamp::Timestamp;
 use revolt_result::Result;
 
-use crate::{Database, MemberCompositeKey, Server};
+use crate::{events::client::EventV1, Database, MemberCompositeKey, Server};
 
 auto_derived!(
     /// Server Ban
@@ -10,6 +11,16 @@ auto_derived!(
         pub id: MemberCompositeKey,
         /// Reason for ban creation
         pub reason: Option<String>,
+
+        /// Id of the moderator who issued this ban
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub moderator: Option<String>,
+        /// Time at which this ban was created
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub created_at: Option<Timestamp>,
+        /// Time at which this ban is lifted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub expires_at: Option<Timestamp>,
     }
 );
 
@@ -19,7 +30,9 @@ impl ServerBan {
         db: &Database,
         server: &Server,
         user_id: &str,
+        moderator: &str,
         reason: Option<String>,
+        expires_at: Option<Timestamp>,
     ) -> Result<ServerBan> {
         let ban = ServerBan {
             id: MemberCompositeKey {
@@ -27,9 +40,61 @@ impl ServerBan {
                 user: user_id.to_string(),
             },
             reason,
+            moderator: Some(moderator.to_string()),
+            created_at: Some(Timestamp::now_utc()),
+            expires_at,
         };
 
         db.insert_ban(&ban).await?;
         Ok(ban)
     }
+
+    /// Check whether this ban has expired
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| *expires_at <= *Timestamp::now_utc())
+    }
+
+    /// Lift this ban
+    pub async fn delete(self, db: &Database) -> Result<()> {
+        db.delete_ban(&self.id).await?;
+
+        EventV1::ServerMemberUnban {
+            id: self.id.server.clone(),
+            user: self.id.user,
+        }
+        .p(self.id.server)
+        .await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso8601_timestamp::Timestamp;
+
+    use crate::fixture;
+
+    #[async_std::test]
+    async fn ban_expiry() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_bans",
+                server server 2
+                banned user 3
+                suspended user 4
+                pardoned user 5);
+
+            let banned = db.fetch_ban(&server.id, &banned.id).await.unwrap();
+            let suspended = db.fetch_ban(&server.id, &suspended.id).await.unwrap();
+            let pardoned = db.fetch_ban(&server.id, &pardoned.id).await.unwrap();
+
+            assert!(!banned.is_expired());
+            assert!(!suspended.is_expired());
+            assert!(pardoned.is_expired());
+
+            let expired = db.fetch_expired_bans(Timestamp::now_utc()).await.unwrap();
+            assert_eq!(expired, vec![pardoned]);
+        });
+    }
 }
@@ -1,3 +1,4 @@
+use iso8601_timestamp::Timestamp;
 use revolt_result::Result;
 
 use crate::{MemberCompositeKey, ServerBan};
@@ -13,8 +14,16 @@ pub trait AbstractServerBans: Sync + Send {
     /// Fetch a server ban by server and user id
     async fn fetch_ban(&self, server_id: &str, user_id: &str) -> Result<ServerBan>;
 
-    /// Fetch all bans in a server
-    async fn fetch_bans(&self, server_id: &str) -> Result<Vec<ServerBan>>;
+    /// Fetch bans in a server, ordered by user id
+    async fn fetch_bans(
+        &self,
+        server_id: &str,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<ServerBan>>;
+
+    /// Fetch all bans which expired by the given time
+    async fn fetch_expired_bans(&self, now: Timestamp) -> Result<Vec<ServerBan>>;
 
     /// Delete a ban from the database
     async fn delete_ban(&self, id: &MemberCompositeKey) -> Result<()>;
@@ -1,3 +1,5 @@
+use iso8601_timestamp::Timestamp;
+use mongodb::options::FindOptions;
 use revolt_result::Result;
 
 use crate::MongoDb;
@@ -28,14 +30,46 @@ impl AbstractServerBans for MongoDb {
         .ok_or_else(|| create_error!(NotFound))
     }
 
-    /// Fetch all bans in a server
-    async fn fetch_bans(&self, server_id: &str) -> Result<Vec<ServerBan>> {
+    /// Fetch bans in a server, ordered by user id
+    async fn fetch_bans(
+        &self,
+        server_id: &str,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<ServerBan>> {
+        let mut filter = doc! {
+            "_id.server": server_id
+        };
+
+        if let Some(after) = after {
+            filter.insert("_id.user", doc! { "$gt": after });
+        }
+
+        self.find_with_options(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(limit)
+                .sort(doc! {
+                    "_id.user": 1_i32
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
+    /// Fetch all bans which expired by the given time
+    async fn fetch_expired_bans(&self, now: Timestamp) -> Result<Vec<ServerBan>> {
         query!(
             self,
             find,
             COL,
             doc! {
-                "_id.server": server_id
+                "expires_at": {
+                    "$lte": bson::to_bson(&now)
+                        .map_err(|_| create_database_error!("to_bson", "expires_at"))?
+                }
             }
         )
     }
@@ -1,3 +1,4 @@
+use iso8601_timestamp::Timestamp;
 use revolt_result::Result;
 
 use crate::ReferenceDb;
@@ -30,12 +31,33 @@ impl AbstractServerBans for ReferenceDb {
             .ok_or_else(|| create_error!(NotFound))
     }
 
-    /// Fetch all bans in a server
-    async fn fetch_bans(&self, server_id: &str) -> Result<Vec<ServerBan>> {
+    /// Fetch bans in a server, ordered by user id
+    async fn fetch_bans(
+        &self,
+        server_id: &str,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<ServerBan>> {
+        let server_bans = self.server_bans.lock().await;
+        let mut bans: Vec<ServerBan> = server_bans
+            .values()
+            .filter(|ban| {
+                ban.id.server == server_id && after.as_ref().map_or(true, |v| &ban.id.user > v)
+            })
+            .cloned()
+            .collect();
+
+        bans.sort_by(|a, b| a.id.user.cmp(&b.id.user));
+        bans.truncate(limit as usize);
+        Ok(bans)
+    }
+
+    /// Fetch all bans which expired by the given time
+    async fn fetch_expired_bans(&self, now: Timestamp) -> Result<Vec<ServerBan>> {
         let server_bans = self.server_bans.lock().await;
         Ok(server_bans
             .values()
-            .filter(|member| member.id.server == server_id)
+            .filter(|ban| ban.expires_at.is_some_and(|expires_at| *expires_at <= *now))
             .cloned()
             .collect())
     }
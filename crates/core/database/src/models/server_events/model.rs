@@ -0,0 +1,363 @@
+use indexmap::IndexSet;
+use iso8601_timestamp::{Duration, Timestamp};
+use revolt_result::Result;
+
+use crate::{events::client::EventV1, Channel, Database, File, Server, AMQP};
+
+/// How long before an event starts that interested members are reminded
+pub static REMINDER_WINDOW: i64 = 15 * 60;
+
+auto_derived_partial!(
+    /// Scheduled Server Event
+    pub struct ServerEvent {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server this event belongs to
+        pub server: String,
+        /// Id of the user who created this event
+        pub creator: String,
+
+        /// Title of the event
+        pub title: String,
+        /// Description of the event
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Where the event takes place
+        pub location: ServerEventLocation,
+        /// Cover image
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub cover: Option<File>,
+
+        /// Time at which the event starts
+        pub starts_at: Timestamp,
+        /// Time at which the event ends
+        pub ends_at: Timestamp,
+
+        /// Ids of members interested in this event
+        #[serde(skip_serializing_if = "IndexSet::is_empty", default)]
+        pub interested: IndexSet<String>,
+        /// Whether interested members have been reminded of this event
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub reminded: bool,
+    },
+    "PartialServerEvent"
+);
+
+auto_derived!(
+    /// Where a server event takes place
+    #[serde(tag = "type")]
+    pub enum ServerEventLocation {
+        /// Voice channel within the server
+        Channel {
+            /// Channel Id
+            id: String,
+        },
+        /// Anywhere else
+        Text {
+            /// Description of the location
+            name: String,
+        },
+    }
+
+    /// Optional fields on server event object
+    pub enum FieldsServerEvent {
+        Description,
+        Cover,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl ServerEvent {
+    /// Create a new server event
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        self.validate()?;
+        db.insert_server_event(self).await?;
+
+        EventV1::ServerEventCreate(self.clone().into())
+            .p(self.server.clone())
+            .await;
+
+        Ok(())
+    }
+
+    /// Update event data
+    pub async fn update(
+        &mut self,
+        db: &Database,
+        mut partial: PartialServerEvent,
+        remove: Vec<FieldsServerEvent>,
+    ) -> Result<()> {
+        // Remind members again if the event has been rescheduled
+        if partial
+            .starts_at
+            .is_some_and(|starts_at| starts_at != self.starts_at)
+        {
+            partial.reminded = Some(false);
+        }
+
+        let mut updated = self.clone();
+        for field in &remove {
+            updated.remove_field(field);
+        }
+
+        updated.apply_options(partial.clone());
+        updated.validate()?;
+
+        db.update_server_event(&self.id, &partial, &remove).await?;
+        *self = updated;
+
+        EventV1::ServerEventUpdate {
+            id: self.id.clone(),
+            server: self.server.clone(),
+            data: partial.into(),
+            clear: remove.into_iter().map(|v| v.into()).collect(),
+        }
+        .p(self.server.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Remove a field from this object
+    pub fn remove_field(&mut self, field: &FieldsServerEvent) {
+        match field {
+            FieldsServerEvent::Description => self.description = None,
+            FieldsServerEvent::Cover => self.cover = None,
+        }
+    }
+
+    /// Delete this event
+    pub async fn delete(self, db: &Database) -> Result<()> {
+        if let Some(cover) = &self.cover {
+            db.mark_attachment_as_deleted(&cover.id).await?;
+        }
+
+        db.delete_server_event(&self.id).await?;
+
+        EventV1::ServerEventDelete {
+            id: self.id,
+            server: self.server.clone(),
+        }
+        .p(self.server)
+        .await;
+
+        Ok(())
+    }
+
+    /// Mark a member as interested in this event
+    pub async fn add_interest(&mut self, db: &Database, user_id: &str) -> Result<()> {
+        if self.interested.contains(user_id) {
+            return Err(create_error!(NoEffect));
+        }
+
+        db.add_server_event_interest(&self.id, user_id).await?;
+        self.interested.insert(user_id.to_string());
+
+        EventV1::ServerEventInterestAdd {
+            id: self.id.clone(),
+            server: self.server.clone(),
+            user: user_id.to_string(),
+        }
+        .p(self.server.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Remove a member's interest in this event
+    pub async fn remove_interest(&mut self, db: &Database, user_id: &str) -> Result<()> {
+        if !self.interested.contains(user_id) {
+            return Err(create_error!(NoEffect));
+        }
+
+        db.remove_server_event_interest(&self.id, user_id).await?;
+        self.interested.shift_remove(user_id);
+
+        EventV1::ServerEventInterestRemove {
+            id: self.id.clone(),
+            server: self.server.clone(),
+            user: user_id.to_string(),
+        }
+        .p(self.server.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Ensure the event is well formed
+    pub fn validate(&self) -> Result<()> {
+        if *self.ends_at <= *self.starts_at {
+            return Err(create_error!(FailedValidation {
+                error: "event must end after it starts".to_string()
+            }));
+        }
+
+        if let ServerEventLocation::Text { name } = &self.location {
+            if name.is_empty() || name.len() > 128 {
+                return Err(create_error!(FailedValidation {
+                    error: "location must be between 1 and 128 characters".to_string()
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensure the event location belongs to the given server
+    pub async fn validate_location(&self, db: &Database, server: &Server) -> Result<()> {
+        if let ServerEventLocation::Channel { id } = &self.location {
+            if !server.channels.contains(id) {
+                return Err(create_error!(UnknownChannel));
+            }
+
+            if !matches!(db.fetch_channel(id).await?, Channel::VoiceChannel { .. }) {
+                return Err(create_error!(InvalidOperation));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check whether interested members should now be reminded of this event
+    pub fn is_due_for_reminder(&self) -> bool {
+        !self.reminded && *self.starts_at <= *ServerEvent::reminder_threshold()
+    }
+
+    /// Fetch all events whose interested members should now be reminded
+    pub async fn fetch_due_for_reminder(db: &Database) -> Result<Vec<ServerEvent>> {
+        db.fetch_unreminded_server_events(ServerEvent::reminder_threshold())
+            .await
+    }
+
+    /// Latest start time of events which are due for a reminder
+    fn reminder_threshold() -> Timestamp {
+        Timestamp::now_utc()
+            .checked_add(Duration::seconds(REMINDER_WINDOW))
+            .unwrap_or_else(Timestamp::now_utc)
+    }
+
+    /// Push a reminder to every interested member
+    ///
+    /// Reminders are skipped if the event has already started.
+    pub async fn remind(&mut self, db: &Database, amqp: &AMQP) -> Result<()> {
+        if *self.starts_at > *Timestamp::now_utc() && !self.interested.is_empty() {
+            let server = db.fetch_server(&self.server).await?;
+            let config = revolt_config::config().await;
+            let icon = self
+                .cover
+                .as_ref()
+                .map(|cover| format!("{}/banners/{}", config.hosts.autumn, cover.id));
+
+            let ids: Vec<String> = self.interested.iter().cloned().collect();
+            for user in db.fetch_users(&ids).await? {
+                amqp.generic_message(
+                    &user,
+                    self.title.clone(),
+                    format!("Starting soon in {}", server.name),
+                    icon.clone(),
+                )
+                .await
+                .ok();
+            }
+        }
+
+        db.update_server_event(
+            &self.id,
+            &PartialServerEvent {
+                reminded: Some(true),
+                ..Default::default()
+            },
+            &[],
+        )
+        .await?;
+
+        self.reminded = true;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso8601_timestamp::{Duration, Timestamp};
+
+    use crate::{fixture, PartialServerEvent, ServerEvent, ServerEventLocation};
+
+    #[async_std::test]
+    async fn validate() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_events",
+                event server_event 3
+                voice_event server_event 4);
+
+            assert!(event.validate().is_ok());
+            assert!(voice_event.validate().is_ok());
+
+            let mut backwards = event.clone();
+            backwards.ends_at = event.starts_at.checked_sub(Duration::hours(1)).unwrap();
+            assert!(backwards.validate().is_err());
+
+            let mut instant = event.clone();
+            instant.ends_at = event.starts_at;
+            assert!(instant.validate().is_err());
+
+            let mut unnamed = event;
+            unnamed.location = ServerEventLocation::Text {
+                name: String::new(),
+            };
+            assert!(unnamed.validate().is_err());
+        });
+    }
+
+    #[async_std::test]
+    async fn reminder_window() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_events",
+                upcoming server_event 3
+                reminded server_event 4
+                started server_event 5);
+
+            assert!(!upcoming.is_due_for_reminder());
+            assert!(!reminded.is_due_for_reminder());
+            assert!(started.is_due_for_reminder());
+
+            let mut soon = upcoming;
+            soon.starts_at = Timestamp::now_utc()
+                .checked_add(Duration::seconds(60))
+                .unwrap();
+            assert!(soon.is_due_for_reminder());
+        });
+    }
+
+    #[async_std::test]
+    async fn reschedule() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_events",
+                reminded server_event 4
+                started server_event 5);
+
+            let due = ServerEvent::fetch_due_for_reminder(&db).await.unwrap();
+            assert_eq!(due.len(), 1);
+            assert_eq!(due[0].id, started.id);
+
+            // Moving the event should remind members about the new time
+            let mut moved = reminded;
+            moved
+                .update(
+                    &db,
+                    PartialServerEvent {
+                        starts_at: Timestamp::now_utc().checked_add(Duration::seconds(60)),
+                        ends_at: Timestamp::now_utc().checked_add(Duration::hours(2)),
+                        ..Default::default()
+                    },
+                    vec![],
+                )
+                .await
+                .unwrap();
+
+            let due = ServerEvent::fetch_due_for_reminder(&db).await.unwrap();
+            assert_eq!(due.len(), 2);
+            assert!(due.iter().any(|event| event.id == moved.id));
+        });
+    }
+}
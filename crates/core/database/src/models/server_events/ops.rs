@@ -0,0 +1,39 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::{FieldsServerEvent, PartialServerEvent, ServerEvent};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractServerEvents: Sync + Send {
+    /// Insert a new server event into the database
+    async fn insert_server_event(&self, event: &ServerEvent) -> Result<()>;
+
+    /// Fetch a server event by its id
+    async fn fetch_server_event(&self, id: &str) -> Result<ServerEvent>;
+
+    /// Fetch all events for a server, soonest first
+    async fn fetch_server_events(&self, server_id: &str) -> Result<Vec<ServerEvent>>;
+
+    /// Fetch events starting before the given time which have not yet sent out reminders
+    async fn fetch_unreminded_server_events(&self, before: Timestamp) -> Result<Vec<ServerEvent>>;
+
+    /// Update a server event with new information
+    async fn update_server_event(
+        &self,
+        id: &str,
+        partial: &PartialServerEvent,
+        remove: &[FieldsServerEvent],
+    ) -> Result<()>;
+
+    /// Mark a user as interested in a server event
+    async fn add_server_event_interest(&self, id: &str, user_id: &str) -> Result<()>;
+
+    /// Remove a user's interest in a server event
+    async fn remove_server_event_interest(&self, id: &str, user_id: &str) -> Result<()>;
+
+    /// Delete a server event by its id
+    async fn delete_server_event(&self, id: &str) -> Result<()>;
+}
@@ -0,0 +1,130 @@
+use bson::Document;
+use iso8601_timestamp::Timestamp;
+use mongodb::options::FindOptions;
+use revolt_result::Result;
+
+use crate::{FieldsServerEvent, PartialServerEvent, ServerEvent};
+use crate::{IntoDocumentPath, MongoDb};
+
+use super::AbstractServerEvents;
+
+static COL: &str = "server_events";
+
+#[async_trait]
+impl AbstractServerEvents for MongoDb {
+    /// Insert a new server event into the database
+    async fn insert_server_event(&self, event: &ServerEvent) -> Result<()> {
+        query!(self, insert_one, COL, &event).map(|_| ())
+    }
+
+    /// Fetch a server event by its id
+    async fn fetch_server_event(&self, id: &str) -> Result<ServerEvent> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all events for a server, soonest first
+    async fn fetch_server_events(&self, server_id: &str) -> Result<Vec<ServerEvent>> {
+        self.find_with_options(
+            COL,
+            doc! {
+                "server": server_id
+            },
+            FindOptions::builder()
+                .sort(doc! {
+                    "starts_at": 1_i32
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
+    /// Fetch events starting before the given time which have not yet sent out reminders
+    async fn fetch_unreminded_server_events(&self, before: Timestamp) -> Result<Vec<ServerEvent>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "reminded": {
+                    "$ne": true
+                },
+                "starts_at": {
+                    "$lte": bson::to_bson(&before)
+                        .map_err(|_| create_database_error!("to_bson", "starts_at"))?
+                }
+            }
+        )
+    }
+
+    /// Update a server event with new information
+    async fn update_server_event(
+        &self,
+        id: &str,
+        partial: &PartialServerEvent,
+        remove: &[FieldsServerEvent],
+    ) -> Result<()> {
+        query!(
+            self,
+            update_one_by_id,
+            COL,
+            id,
+            partial,
+            remove.iter().map(|x| x as &dyn IntoDocumentPath).collect(),
+            None
+        )
+        .map(|_| ())
+    }
+
+    /// Mark a user as interested in a server event
+    async fn add_server_event_interest(&self, id: &str, user_id: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$addToSet": {
+                        "interested": user_id
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Remove a user's interest in a server event
+    async fn remove_server_event_interest(&self, id: &str, user_id: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$pull": {
+                        "interested": user_id
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Delete a server event by its id
+    async fn delete_server_event(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
+
+impl IntoDocumentPath for FieldsServerEvent {
+    fn as_path(&self) -> Option<&'static str> {
+        Some(match self {
+            FieldsServerEvent::Description => "description",
+            FieldsServerEvent::Cover => "cover",
+        })
+    }
+}
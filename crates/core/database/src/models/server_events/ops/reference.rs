@@ -0,0 +1,106 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{FieldsServerEvent, PartialServerEvent, ServerEvent};
+
+use super::AbstractServerEvents;
+
+#[async_trait]
+impl AbstractServerEvents for ReferenceDb {
+    /// Insert a new server event into the database
+    async fn insert_server_event(&self, event: &ServerEvent) -> Result<()> {
+        let mut server_events = self.server_events.lock().await;
+        if server_events.contains_key(&event.id) {
+            Err(create_database_error!("insert", "server_event"))
+        } else {
+            server_events.insert(event.id.to_string(), event.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a server event by its id
+    async fn fetch_server_event(&self, id: &str) -> Result<ServerEvent> {
+        let server_events = self.server_events.lock().await;
+        server_events
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all events for a server, soonest first
+    async fn fetch_server_events(&self, server_id: &str) -> Result<Vec<ServerEvent>> {
+        let server_events = self.server_events.lock().await;
+        let mut events: Vec<ServerEvent> = server_events
+            .values()
+            .filter(|event| event.server == server_id)
+            .cloned()
+            .collect();
+
+        events.sort_by(|a, b| a.starts_at.cmp(&b.starts_at));
+        Ok(events)
+    }
+
+    /// Fetch events starting before the given time which have not yet sent out reminders
+    async fn fetch_unreminded_server_events(&self, before: Timestamp) -> Result<Vec<ServerEvent>> {
+        let server_events = self.server_events.lock().await;
+        Ok(server_events
+            .values()
+            .filter(|event| !event.reminded && *event.starts_at <= *before)
+            .cloned()
+            .collect())
+    }
+
+    /// Update a server event with new information
+    async fn update_server_event(
+        &self,
+        id: &str,
+        partial: &PartialServerEvent,
+        remove: &[FieldsServerEvent],
+    ) -> Result<()> {
+        let mut server_events = self.server_events.lock().await;
+        if let Some(event) = server_events.get_mut(id) {
+            for field in remove {
+                #[allow(clippy::disallowed_methods)]
+                event.remove_field(field);
+            }
+
+            event.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Mark a user as interested in a server event
+    async fn add_server_event_interest(&self, id: &str, user_id: &str) -> Result<()> {
+        let mut server_events = self.server_events.lock().await;
+        if let Some(event) = server_events.get_mut(id) {
+            event.interested.insert(user_id.to_string());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Remove a user's interest in a server event
+    async fn remove_server_event_interest(&self, id: &str, user_id: &str) -> Result<()> {
+        let mut server_events = self.server_events.lock().await;
+        if let Some(event) = server_events.get_mut(id) {
+            event.interested.shift_remove(user_id);
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete a server event by its id
+    async fn delete_server_event(&self, id: &str) -> Result<()> {
+        let mut server_events = self.server_events.lock().await;
+        if server_events.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
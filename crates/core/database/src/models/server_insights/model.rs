@@ -0,0 +1,204 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use revolt_config::report_internal_error;
+use revolt_presence::filter_online;
+use revolt_result::Result;
+
+use crate::{Database, Message, Server};
+
+/// Number of milliseconds in a day
+const DAY_MS: u64 = 86_400_000;
+
+/// Number of members checked for presence at once
+const SAMPLE_PAGE_SIZE: i64 = 1_000;
+
+auto_derived!(
+    /// Daily activity rollup for a server
+    pub struct ServerInsights {
+        /// Unique Id, made up of the server id and day
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server these insights belong to
+        pub server: String,
+        /// Day covered by this rollup, counted in days since the Unix epoch
+        pub day: i64,
+
+        /// Number of users who joined
+        #[serde(default)]
+        pub joins: i64,
+        /// Number of members who left
+        #[serde(default)]
+        pub leaves: i64,
+        /// Number of members who were kicked or banned
+        #[serde(default)]
+        pub kicks: i64,
+
+        /// Number of messages sent in each channel
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub messages: HashMap<String, i64>,
+        /// Number of members who sent at least one message
+        #[serde(default)]
+        pub active_members: i64,
+        /// Highest number of members seen online at once
+        #[serde(default)]
+        pub peak_online: i64,
+
+        /// Views and joins for each invite code
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub invites: HashMap<String, InviteConversion>,
+    }
+
+    /// Views and joins recorded for an invite
+    #[derive(Default)]
+    pub struct InviteConversion {
+        /// Number of times the invite was viewed
+        #[serde(default)]
+        pub views: i64,
+        /// Number of users who joined through the invite
+        #[serde(default)]
+        pub joins: i64,
+    }
+);
+
+/// Counter which is incremented as events happen
+#[derive(Debug, Clone)]
+pub enum InsightsCounter {
+    Join,
+    Leave,
+    Kick,
+    InviteView(String),
+    InviteJoin(String),
+}
+
+impl ServerInsights {
+    /// Create empty insights for a server on a given day
+    pub fn new(server_id: &str, day: i64) -> ServerInsights {
+        ServerInsights {
+            id: format!("{server_id}:{day}"),
+            server: server_id.to_string(),
+            day,
+            joins: 0,
+            leaves: 0,
+            kicks: 0,
+            messages: HashMap::new(),
+            active_members: 0,
+            peak_online: 0,
+            invites: HashMap::new(),
+        }
+    }
+
+    /// Get the current day, counted in days since the Unix epoch
+    pub fn today() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64 / DAY_MS)
+            .unwrap_or_default() as i64
+    }
+
+    /// Record an event against today's insights if the server has analytics enabled
+    ///
+    /// Failures are reported but never interrupt the action being recorded.
+    pub async fn record(db: &Database, server: &Server, counter: InsightsCounter) {
+        if server.analytics {
+            report_internal_error!(
+                db.increment_server_insights(&server.id, Self::today(), &counter)
+                    .await
+            )
+            .ok();
+        }
+    }
+
+    /// Recount messages sent in a server on a given day
+    pub async fn rollup_activity(db: &Database, server: &Server, day: i64) -> Result<()> {
+        let after = Message::id_from_time(day as u64 * DAY_MS);
+        let before = Message::id_from_time((day as u64 + 1) * DAY_MS);
+
+        let mut messages: HashMap<String, i64> = HashMap::new();
+        let mut authors = HashSet::new();
+        for (channel, author, count) in db
+            .fetch_message_activity(&server.channels, &after, &before)
+            .await?
+        {
+            *messages.entry(channel).or_default() += count;
+            authors.insert(author);
+        }
+
+        db.set_server_message_activity(&server.id, day, &messages, authors.len() as i64)
+            .await
+    }
+
+    /// Record how many members of a server are currently online
+    pub async fn sample_online(db: &Database, server: &Server) -> Result<()> {
+        let mut online = 0;
+        let mut after = None;
+
+        loop {
+            let user_ids = db
+                .fetch_member_ids(&server.id, after, SAMPLE_PAGE_SIZE)
+                .await?;
+
+            online += filter_online(&user_ids).await.len() as i64;
+
+            if (user_ids.len() as i64) < SAMPLE_PAGE_SIZE {
+                break;
+            }
+
+            after = user_ids.last().cloned();
+        }
+
+        db.record_server_peak_online(&server.id, Self::today(), online)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InsightsCounter, ServerInsights};
+
+    #[async_std::test]
+    async fn counters() {
+        database_test!(|db| async move {
+            let day = ServerInsights::today();
+
+            db.increment_server_insights("server", day, &InsightsCounter::Join)
+                .await
+                .unwrap();
+            db.increment_server_insights("server", day, &InsightsCounter::Join)
+                .await
+                .unwrap();
+            db.increment_server_insights("server", day, &InsightsCounter::Kick)
+                .await
+                .unwrap();
+            db.increment_server_insights(
+                "server",
+                day,
+                &InsightsCounter::InviteJoin("code".to_string()),
+            )
+            .await
+            .unwrap();
+            db.record_server_peak_online("server", day, 5)
+                .await
+                .unwrap();
+            db.record_server_peak_online("server", day, 3)
+                .await
+                .unwrap();
+
+            let insights = db.fetch_server_insights("server", day).await.unwrap();
+            assert_eq!(insights.len(), 1);
+            assert_eq!(insights[0].joins, 2);
+            assert_eq!(insights[0].leaves, 0);
+            assert_eq!(insights[0].kicks, 1);
+            assert_eq!(insights[0].invites["code"].joins, 1);
+            assert_eq!(insights[0].peak_online, 5);
+
+            assert!(db
+                .fetch_server_insights("server", day + 1)
+                .await
+                .unwrap()
+                .is_empty());
+        });
+    }
+}
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use revolt_result::Result;
+
+use crate::{InsightsCounter, ServerInsights};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractServerInsights: Sync + Send {
+    /// Increment a counter on a server's insights for a given day
+    async fn increment_server_insights(
+        &self,
+        server_id: &str,
+        day: i64,
+        counter: &InsightsCounter,
+    ) -> Result<()>;
+
+    /// Replace the message activity on a server's insights for a given day
+    async fn set_server_message_activity(
+        &self,
+        server_id: &str,
+        day: i64,
+        messages: &HashMap<String, i64>,
+        active_members: i64,
+    ) -> Result<()>;
+
+    /// Raise the peak online count on a server's insights for today if it is higher
+    async fn record_server_peak_online(&self, server_id: &str, day: i64, online: i64)
+        -> Result<()>;
+
+    /// Fetch a server's insights from a given day onwards, oldest first
+    async fn fetch_server_insights(
+        &self,
+        server_id: &str,
+        since: i64,
+    ) -> Result<Vec<ServerInsights>>;
+
+    /// Count messages sent in the given channels between two message ids
+    ///
+    /// Returns the number of messages sent by each author in each channel.
+    async fn fetch_message_activity(
+        &self,
+        channel_ids: &[String],
+        after: &str,
+        before: &str,
+    ) -> Result<Vec<(String, String, i64)>>;
+}
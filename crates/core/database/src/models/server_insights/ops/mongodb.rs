@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use bson::Document;
+use futures::StreamExt;
+use mongodb::options::{FindOptions, UpdateOptions};
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{InsightsCounter, ServerInsights};
+
+use super::AbstractServerInsights;
+
+static COL: &str = "server_insights";
+
+#[async_trait]
+impl AbstractServerInsights for MongoDb {
+    /// Increment a counter on a server's insights for a given day
+    async fn increment_server_insights(
+        &self,
+        server_id: &str,
+        day: i64,
+        counter: &InsightsCounter,
+    ) -> Result<()> {
+        self.upsert_server_insights(
+            server_id,
+            day,
+            doc! {
+                "$inc": {
+                    counter.as_path(): 1_i64
+                }
+            },
+        )
+        .await
+    }
+
+    /// Replace the message activity on a server's insights for a given day
+    async fn set_server_message_activity(
+        &self,
+        server_id: &str,
+        day: i64,
+        messages: &HashMap<String, i64>,
+        active_members: i64,
+    ) -> Result<()> {
+        self.upsert_server_insights(
+            server_id,
+            day,
+            doc! {
+                "$set": {
+                    "messages": bson::to_bson(messages)
+                        .map_err(|_| create_database_error!("to_bson", "messages"))?,
+                    "active_members": active_members
+                }
+            },
+        )
+        .await
+    }
+
+    /// Raise the peak online count on a server's insights for today if it is higher
+    async fn record_server_peak_online(
+        &self,
+        server_id: &str,
+        day: i64,
+        online: i64,
+    ) -> Result<()> {
+        self.upsert_server_insights(
+            server_id,
+            day,
+            doc! {
+                "$max": {
+                    "peak_online": online
+                }
+            },
+        )
+        .await
+    }
+
+    /// Fetch a server's insights from a given day onwards, oldest first
+    async fn fetch_server_insights(
+        &self,
+        server_id: &str,
+        since: i64,
+    ) -> Result<Vec<ServerInsights>> {
+        query!(
+            self,
+            find_with_options,
+            COL,
+            doc! {
+                "server": server_id,
+                "day": {
+                    "$gte": since
+                }
+            },
+            FindOptions::builder().sort(doc! { "day": 1_i32 }).build()
+        )
+    }
+
+    /// Count messages sent in the given channels between two message ids
+    async fn fetch_message_activity(
+        &self,
+        channel_ids: &[String],
+        after: &str,
+        before: &str,
+    ) -> Result<Vec<(String, String, i64)>> {
+        Ok(self
+            .col::<Document>("messages")
+            .aggregate(
+                vec![
+                    doc! {
+                        "$match": {
+                            "channel": {
+                                "$in": channel_ids
+                            },
+                            "_id": {
+                                "$gte": after,
+                                "$lt": before
+                            }
+                        }
+                    },
+                    doc! {
+                        "$group": {
+                            "_id": {
+                                "channel": "$channel",
+                                "author": "$author"
+                            },
+                            "count": {
+                                "$sum": 1_i32
+                            }
+                        }
+                    },
+                ],
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("aggregate", "messages"))?
+            .filter_map(|s| async { s.ok() })
+            .filter_map(|doc| async move {
+                let id = doc.get_document("_id").ok()?;
+                Some((
+                    id.get_str("channel").ok()?.to_string(),
+                    id.get_str("author").ok()?.to_string(),
+                    doc.get_i32("count").ok()? as i64,
+                ))
+            })
+            .collect()
+            .await)
+    }
+}
+
+impl MongoDb {
+    /// Apply an update to a server's insights for a given day, creating them if necessary
+    async fn upsert_server_insights(
+        &self,
+        server_id: &str,
+        day: i64,
+        mut update: Document,
+    ) -> Result<()> {
+        update.insert(
+            "$setOnInsert",
+            doc! {
+                "server": server_id,
+                "day": day
+            },
+        );
+
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": format!("{server_id}:{day}")
+                },
+                update,
+                UpdateOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+}
+
+impl InsightsCounter {
+    /// Path of the field this counter increments
+    fn as_path(&self) -> String {
+        match self {
+            InsightsCounter::Join => "joins".to_string(),
+            InsightsCounter::Leave => "leaves".to_string(),
+            InsightsCounter::Kick => "kicks".to_string(),
+            InsightsCounter::InviteView(code) => format!("invites.{code}.views"),
+            InsightsCounter::InviteJoin(code) => format!("invites.{code}.joins"),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{InsightsCounter, ServerInsights};
+
+use super::AbstractServerInsights;
+
+#[async_trait]
+impl AbstractServerInsights for ReferenceDb {
+    /// Increment a counter on a server's insights for a given day
+    async fn increment_server_insights(
+        &self,
+        server_id: &str,
+        day: i64,
+        counter: &InsightsCounter,
+    ) -> Result<()> {
+        let mut server_insights = self.server_insights.lock().await;
+        let insights = server_insights
+            .entry(format!("{server_id}:{day}"))
+            .or_insert_with(|| ServerInsights::new(server_id, day));
+
+        match counter {
+            InsightsCounter::Join => insights.joins += 1,
+            InsightsCounter::Leave => insights.leaves += 1,
+            InsightsCounter::Kick => insights.kicks += 1,
+            InsightsCounter::InviteView(code) => {
+                insights.invites.entry(code.clone()).or_default().views += 1
+            }
+            InsightsCounter::InviteJoin(code) => {
+                insights.invites.entry(code.clone()).or_default().joins += 1
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replace the message activity on a server's insights for a given day
+    async fn set_server_message_activity(
+        &self,
+        server_id: &str,
+        day: i64,
+        messages: &HashMap<String, i64>,
+        active_members: i64,
+    ) -> Result<()> {
+        let mut server_insights = self.server_insights.lock().await;
+        let insights = server_insights
+            .entry(format!("{server_id}:{day}"))
+            .or_insert_with(|| ServerInsights::new(server_id, day));
+
+        insights.messages = messages.clone();
+        insights.active_members = active_members;
+        Ok(())
+    }
+
+    /// Raise the peak online count on a server's insights for today if it is higher
+    async fn record_server_peak_online(
+        &self,
+        server_id: &str,
+        day: i64,
+        online: i64,
+    ) -> Result<()> {
+        let mut server_insights = self.server_insights.lock().await;
+        let insights = server_insights
+            .entry(format!("{server_id}:{day}"))
+            .or_insert_with(|| ServerInsights::new(server_id, day));
+
+        insights.peak_online = insights.peak_online.max(online);
+        Ok(())
+    }
+
+    /// Fetch a server's insights from a given day onwards, oldest first
+    async fn fetch_server_insights(
+        &self,
+        server_id: &str,
+        since: i64,
+    ) -> Result<Vec<ServerInsights>> {
+        let server_insights = self.server_insights.lock().await;
+        let mut insights: Vec<ServerInsights> = server_insights
+            .values()
+            .filter(|insights| insights.server == server_id && insights.day >= since)
+            .cloned()
+            .collect();
+
+        insights.sort_by_key(|insights| insights.day);
+        Ok(insights)
+    }
+
+    /// Count messages sent in the given channels between two message ids
+    async fn fetch_message_activity(
+        &self,
+        channel_ids: &[String],
+        after: &str,
+        before: &str,
+    ) -> Result<Vec<(String, String, i64)>> {
+        let messages = self.messages.lock().await;
+        let mut counts: HashMap<(String, String), i64> = HashMap::new();
+        for message in messages.values() {
+            if channel_ids.contains(&message.channel)
+                && message.id.as_str() >= after
+                && message.id.as_str() < before
+            {
+                *counts
+                    .entry((message.channel.clone(), message.author.clone()))
+                    .or_default() += 1;
+            }
+        }
+
+        Ok(counts
+            .into_iter()
+            .map(|((channel, author), count)| (channel, author, count))
+            .collect())
+    }
+}
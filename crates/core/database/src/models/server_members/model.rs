@@ -1,10 +1,13 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use iso8601_timestamp::Timestamp;
+use revolt_config::report_internal_error;
 use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
 use revolt_result::{create_error, Result};
 
 use crate::{
     events::client::EventV1, util::permissions::DatabasePermissionQuery, Channel, Database, File,
-    Server, SystemMessage, User,
+    InsightsCounter, Invite, Message, Server, ServerInsights, SystemMessage, User,
 };
 
 auto_derived_partial!(
@@ -23,6 +26,15 @@ auto_derived_partial!(
         /// Avatar attachment
         #[serde(skip_serializing_if = "Option::is_none")]
         pub avatar: Option<File>,
+        /// Member's server-specific bio
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub bio: Option<String>,
+        /// Server-specific profile banner
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub banner: Option<File>,
+        /// Member's server-specific pronouns
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub pronouns: Option<String>,
 
         /// Member's roles
         #[serde(skip_serializing_if = "Vec::is_empty", default)]
@@ -30,6 +42,22 @@ auto_derived_partial!(
         /// Timestamp this member is timed out until
         #[serde(skip_serializing_if = "Option::is_none")]
         pub timeout: Option<Timestamp>,
+        /// Reason given for this member's timeout
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub timeout_reason: Option<String>,
+        /// Id of the moderator who timed out this member
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub timeout_moderator: Option<String>,
+        /// Whether this member has yet to accept the server's rules
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub pending: bool,
+
+        /// Code of the invite this member joined through
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub invite: Option<String>,
+        /// Whether this member is removed once they go offline
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub temporary: bool,
     },
     "PartialMember"
 );
@@ -50,6 +78,11 @@ auto_derived!(
         Avatar,
         Roles,
         Timeout,
+        TimeoutReason,
+        TimeoutModerator,
+        Bio,
+        Banner,
+        Pronouns,
     }
 
     /// Member removal intention
@@ -67,12 +100,31 @@ impl Default for Member {
             joined_at: Timestamp::now_utc(),
             nickname: None,
             avatar: None,
+            bio: None,
+            banner: None,
+            pronouns: None,
             roles: vec![],
             timeout: None,
+            timeout_reason: None,
+            timeout_moderator: None,
+            pending: false,
+            invite: None,
+            temporary: false,
         }
     }
 }
 
+impl FieldsMember {
+    /// Fields which are cleared together when a timeout is lifted
+    pub fn timeout() -> Vec<FieldsMember> {
+        vec![
+            FieldsMember::Timeout,
+            FieldsMember::TimeoutReason,
+            FieldsMember::TimeoutModerator,
+        ]
+    }
+}
+
 #[allow(clippy::disallowed_methods)]
 impl Member {
     /// Create a new member in a server
@@ -82,7 +134,26 @@ impl Member {
         user: &User,
         channels: Option<Vec<Channel>>,
     ) -> Result<(Member, Vec<Channel>)> {
-        if db.fetch_ban(&server.id, &user.id).await.is_ok() {
+        Member::create_with_invite(db, server, user, channels, None).await
+    }
+
+    /// Create a new member in a server, recording the invite they joined through
+    pub async fn create_with_invite(
+        db: &Database,
+        server: &Server,
+        user: &User,
+        channels: Option<Vec<Channel>>,
+        invite: Option<&Invite>,
+    ) -> Result<(Member, Vec<Channel>)> {
+        if server.scheduled_deletion.is_some() {
+            return Err(create_error!(NotFound));
+        }
+
+        if db
+            .fetch_ban(&server.id, &user.id)
+            .await
+            .is_ok_and(|ban| !ban.is_expired())
+        {
             return Err(create_error!(Banned));
         }
 
@@ -95,10 +166,34 @@ impl Member {
                 server: server.id.to_string(),
                 user: user.id.to_string(),
             },
+            // Bots are added by moderators and are never screened
+            pending: server.rules.is_some() && user.bot.is_none(),
+            invite: invite.map(|invite| invite.code().to_string()),
+            temporary: matches!(
+                invite,
+                Some(Invite::Server {
+                    temporary: true,
+                    ..
+                })
+            ),
             ..Default::default()
         };
 
         db.insert_member(&member).await?;
+        report_internal_error!(db.update_server_member_count(&server.id, 1).await).ok();
+        if user.bot.is_some() {
+            report_internal_error!(db.update_bot_server_count(&user.id, 1).await).ok();
+        }
+
+        ServerInsights::record(db, server, InsightsCounter::Join).await;
+        if let Some(invite) = invite {
+            ServerInsights::record(
+                db,
+                server,
+                InsightsCounter::InviteJoin(invite.code().to_string()),
+            )
+            .await;
+        }
 
         let should_fetch = channels.is_none();
         let mut channels = channels.unwrap_or_default();
@@ -190,9 +285,30 @@ impl Member {
             FieldsMember::Nickname => self.nickname = None,
             FieldsMember::Roles => self.roles.clear(),
             FieldsMember::Timeout => self.timeout = None,
+            FieldsMember::TimeoutReason => self.timeout_reason = None,
+            FieldsMember::TimeoutModerator => self.timeout_moderator = None,
+            FieldsMember::Bio => self.bio = None,
+            FieldsMember::Banner => self.banner = None,
+            FieldsMember::Pronouns => self.pronouns = None,
         }
     }
 
+    /// Remove a user from servers they temporarily joined and were not given a role in
+    pub async fn remove_temporary_memberships(db: &Database, user_id: &str) -> Result<()> {
+        for member in db.fetch_all_memberships(user_id).await? {
+            if member.temporary && member.roles.is_empty() {
+                // Skip memberships of servers which no longer exist
+                if let Ok(server) = db.fetch_server(&member.id.server).await {
+                    member
+                        .remove(db, &server, RemovalIntention::Leave, false)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get this user's current ranking
     pub fn get_ranking(&self, server: &Server) -> i64 {
         let mut value = i64::MAX;
@@ -216,6 +332,94 @@ impl Member {
         }
     }
 
+    /// Clear this member's timeout once it has lapsed
+    pub async fn expire_timeout(&mut self, db: &Database) -> Result<()> {
+        if self.timeout.is_none() || self.in_timeout() {
+            return Ok(());
+        }
+
+        self.update(db, Default::default(), FieldsMember::timeout())
+            .await
+    }
+
+    /// Check whether this member may be pruned
+    ///
+    /// Members may be pruned if they joined before the cutoff
+    /// and hold no roles outside of the given set.
+    pub fn is_prunable(&self, cutoff: Timestamp, roles: &[String]) -> bool {
+        *self.joined_at < *cutoff && self.roles.iter().all(|role| roles.contains(role))
+    }
+
+    /// Find ids of members who have not sent or acknowledged a message within a number of days
+    ///
+    /// The owner, bots and members ranked at or above the given rank are never included.
+    pub async fn fetch_prunable(
+        db: &Database,
+        server: &Server,
+        days: u32,
+        roles: &[String],
+        rank: i64,
+    ) -> Result<Vec<String>> {
+        let cutoff = SystemTime::now()
+            .checked_sub(Duration::from_secs(days as u64 * 86_400))
+            .and_then(|cutoff| cutoff.duration_since(UNIX_EPOCH).ok())
+            .map(|cutoff| cutoff.as_millis() as u64)
+            .ok_or_else(|| create_error!(InvalidOperation))?;
+
+        let joined_before = Timestamp::from_unix_timestamp_ms(cutoff as i64);
+        let candidates: Vec<String> = db
+            .fetch_all_members(&server.id)
+            .await?
+            .into_iter()
+            .filter(|member| {
+                member.id.user != server.owner
+                    && member.get_ranking(server) > rank
+                    && member.is_prunable(joined_before, roles)
+            })
+            .map(|member| member.id.user)
+            .collect();
+
+        if candidates.is_empty() {
+            return Ok(candidates);
+        }
+
+        let active = db
+            .fetch_recently_active_users(&server.channels, &Message::id_from_time(cutoff))
+            .await?;
+
+        let candidates: Vec<String> = candidates
+            .into_iter()
+            .filter(|id| !active.contains(id))
+            .collect();
+
+        Ok(db
+            .fetch_users(&candidates)
+            .await?
+            .into_iter()
+            .filter(|user| user.bot.is_none())
+            .map(|user| user.id)
+            .collect())
+    }
+
+    /// Kick the given members from a server
+    ///
+    /// Returns the number of members which were kicked.
+    pub async fn prune(db: &Database, server: &Server, user_ids: Vec<String>) -> Result<usize> {
+        let mut count = 0;
+        for member in db.fetch_members(&server.id, &user_ids).await? {
+            let user = member.id.user.clone();
+            match member
+                .remove(db, server, RemovalIntention::Kick, true)
+                .await
+            {
+                Ok(()) => count += 1,
+                Err(err) => error!("Failed to prune {user} from {}: {err:?}", server.id),
+            }
+        }
+
+        Ok(count)
+    }
+
     /// Remove member from server
     pub async fn remove(
         self,
@@ -225,6 +429,18 @@ impl Member {
         silent: bool,
     ) -> Result<()> {
         db.delete_member(&self.id).await?;
+        report_internal_error!(db.update_server_member_count(&server.id, -1).await).ok();
+        report_internal_error!(db.update_bot_server_count(&self.id.user, -1).await).ok();
+
+        ServerInsights::record(
+            db,
+            server,
+            match intention {
+                RemovalIntention::Leave => InsightsCounter::Leave,
+                RemovalIntention::Kick | RemovalIntention::Ban => InsightsCounter::Kick,
+            },
+        )
+        .await;
 
         EventV1::ServerMemberLeave {
             id: self.id.server.to_string(),
@@ -234,6 +450,13 @@ impl Member {
         .p(self.id.server.to_string())
         .await;
 
+        // Drop the user from any call they are in on this server
+        if let Some(state) = revolt_presence::get_voice_state(&self.id.user).await {
+            if server.channels.contains(&state.channel_id) {
+                crate::voice::disconnect_voice_state(db, &self.id.user).await;
+            }
+        }
+
         if !silent {
             if let Some(id) = server
                 .system_messages
@@ -260,3 +483,134 @@ impl Member {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iso8601_timestamp::{Duration, Timestamp};
+
+    use crate::{fixture, Member, Message, PartialMember};
+
+    #[async_std::test]
+    async fn expire_timeouts() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_roles",
+                owner user 0
+                moderator user 1
+                user user 2
+                server server 4);
+
+            let timeout = |seconds: i64| PartialMember {
+                timeout: Timestamp::now_utc().checked_add(Duration::seconds(seconds)),
+                timeout_reason: Some("Spam".to_string()),
+                timeout_moderator: Some(owner.id.clone()),
+                ..Default::default()
+            };
+
+            let mut lapsed = db.fetch_member(&server.id, &user.id).await.unwrap();
+            lapsed.update(&db, timeout(-60), vec![]).await.unwrap();
+
+            let mut ongoing = db.fetch_member(&server.id, &moderator.id).await.unwrap();
+            ongoing.update(&db, timeout(3600), vec![]).await.unwrap();
+
+            let mut expired = db
+                .fetch_expired_timeouts(Timestamp::now_utc())
+                .await
+                .unwrap();
+            assert_eq!(expired.len(), 1);
+
+            // Lifting a timeout clears who issued it and why
+            expired[0].expire_timeout(&db).await.unwrap();
+            let member = db.fetch_member(&server.id, &user.id).await.unwrap();
+            assert!(member.timeout.is_none());
+            assert!(member.timeout_reason.is_none());
+            assert!(member.timeout_moderator.is_none());
+
+            assert!(db
+                .fetch_expired_timeouts(Timestamp::now_utc())
+                .await
+                .unwrap()
+                .is_empty());
+
+            let member = db.fetch_member(&server.id, &moderator.id).await.unwrap();
+            assert!(member.in_timeout());
+            assert_eq!(member.timeout_reason.as_deref(), Some("Spam"));
+        });
+    }
+
+    #[async_std::test]
+    async fn member_id_pages() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_roles",
+                owner user 0
+                moderator user 1
+                user user 2
+                server server 4);
+
+            let mut expected = vec![owner.id, moderator.id, user.id];
+            expected.sort();
+
+            let first = db.fetch_member_ids(&server.id, None, 2).await.unwrap();
+            assert_eq!(first, expected[..2]);
+
+            let second = db
+                .fetch_member_ids(&server.id, first.last().cloned(), 2)
+                .await
+                .unwrap();
+            assert_eq!(second, expected[2..]);
+        });
+    }
+
+    #[async_std::test]
+    async fn prunable() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_roles",
+                moderator user 1
+                user user 2
+                channel channel 3
+                server server 4);
+
+            let moderator_role = server
+                .roles
+                .iter()
+                .find(|(_, role)| role.name == "Moderator")
+                .map(|(id, _)| id.clone())
+                .unwrap();
+
+            // Only members holding no roles outside the given set are included
+            let prunable = Member::fetch_prunable(&db, &server, 30, &[], 0)
+                .await
+                .unwrap();
+            assert_eq!(prunable, vec![user.id.clone()]);
+
+            let mut prunable =
+                Member::fetch_prunable(&db, &server, 30, &[moderator_role.clone()], 0)
+                    .await
+                    .unwrap();
+            prunable.sort();
+            let mut expected = vec![moderator.id.clone(), user.id.clone()];
+            expected.sort();
+            assert_eq!(prunable, expected);
+
+            // Members ranked at or above the given rank are kept
+            let prunable = Member::fetch_prunable(&db, &server, 30, &[moderator_role], 3)
+                .await
+                .unwrap();
+            assert_eq!(prunable, vec![user.id.clone()]);
+
+            // Recent activity keeps a member around
+            db.insert_message(&Message {
+                id: ulid::Ulid::new().to_string(),
+                channel: channel.id().to_string(),
+                author: user.id.clone(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+            let prunable = Member::fetch_prunable(&db, &server, 30, &[], 0)
+                .await
+                .unwrap();
+            assert!(prunable.is_empty());
+        });
+    }
+}
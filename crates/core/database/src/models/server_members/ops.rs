@@ -1,3 +1,6 @@
+use std::collections::{HashMap, HashSet};
+
+use iso8601_timestamp::Timestamp;
 use revolt_result::Result;
 
 use crate::{FieldsMember, Member, MemberCompositeKey, PartialMember};
@@ -16,18 +19,59 @@ pub trait AbstractServerMembers: Sync + Send {
     /// Fetch all members in a server
     async fn fetch_all_members<'a>(&self, server_id: &str) -> Result<Vec<Member>>;
 
+    /// Fetch members who joined through an invite, ordered by user id
+    async fn fetch_invited_members(
+        &self,
+        server_id: &str,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<Member>>;
+
+    /// Fetch a page of member ids in a server, ordered by user id
+    async fn fetch_member_ids(
+        &self,
+        server_id: &str,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<String>>;
+
     /// Fetch all memberships for a user
     async fn fetch_all_memberships<'a>(&self, user_id: &str) -> Result<Vec<Member>>;
 
+    /// Fetch all members whose timeout lapsed by the given time
+    async fn fetch_expired_timeouts(&self, now: Timestamp) -> Result<Vec<Member>>;
+
     /// Fetch multiple members by their ids
     async fn fetch_members<'a>(&self, server_id: &str, ids: &'a [String]) -> Result<Vec<Member>>;
 
     /// Fetch member count of a server
     async fn fetch_member_count(&self, server_id: &str) -> Result<usize>;
 
+    /// Fetch the member counts of several servers
+    async fn fetch_member_counts(&self, server_ids: &[String]) -> Result<HashMap<String, usize>>;
+
+    /// Fetch number of members with a given role
+    async fn fetch_role_member_count(&self, server_id: &str, role_id: &str) -> Result<usize>;
+
+    /// Fetch the number of members with each role across several servers
+    async fn fetch_role_member_counts(
+        &self,
+        server_ids: &[String],
+    ) -> Result<HashMap<String, HashMap<String, usize>>>;
+
     /// Fetch server count of a user
     async fn fetch_server_count(&self, user_id: &str) -> Result<usize>;
 
+    /// Fetch the server counts of several users
+    async fn fetch_server_counts(&self, user_ids: &[String]) -> Result<HashMap<String, usize>>;
+
+    /// Fetch ids of users who sent or acknowledged a message in any of the given channels after a given message id
+    async fn fetch_recently_active_users(
+        &self,
+        channel_ids: &[String],
+        after: &str,
+    ) -> Result<HashSet<String>>;
+
     /// Update information for a server member
     async fn update_member(
         &self,
@@ -36,6 +80,22 @@ pub trait AbstractServerMembers: Sync + Send {
         remove: Vec<FieldsMember>,
     ) -> Result<()>;
 
+    /// Add a role to many members of a server at once
+    async fn add_role_to_members(
+        &self,
+        server_id: &str,
+        role_id: &str,
+        user_ids: &[String],
+    ) -> Result<()>;
+
+    /// Remove a role from many members of a server at once
+    async fn remove_role_from_members(
+        &self,
+        server_id: &str,
+        role_id: &str,
+        user_ids: &[String],
+    ) -> Result<()>;
+
     /// Delete a server member by their id
     async fn delete_member(&self, id: &MemberCompositeKey) -> Result<()>;
 }
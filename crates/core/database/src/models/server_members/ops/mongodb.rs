@@ -1,4 +1,9 @@
+use std::collections::{HashMap, HashSet};
+
+use bson::Document;
 use futures::StreamExt;
+use iso8601_timestamp::Timestamp;
+use mongodb::options::FindOptions;
 use revolt_result::Result;
 
 use crate::{FieldsMember, Member, MemberCompositeKey, PartialMember};
@@ -52,6 +57,79 @@ impl AbstractServerMembers for MongoDb {
             .await)
     }
 
+    /// Fetch members who joined through an invite, ordered by user id
+    async fn fetch_invited_members(
+        &self,
+        server_id: &str,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<Member>> {
+        let mut filter = doc! {
+            "_id.server": server_id,
+            "invite": {
+                "$exists": true
+            }
+        };
+
+        if let Some(after) = after {
+            filter.insert("_id.user", doc! { "$gt": after });
+        }
+
+        self.find_with_options(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(limit)
+                .sort(doc! {
+                    "_id.user": 1_i32
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
+    /// Fetch a page of member ids in a server, ordered by user id
+    async fn fetch_member_ids(
+        &self,
+        server_id: &str,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<String>> {
+        let mut filter = doc! {
+            "_id.server": server_id
+        };
+
+        if let Some(after) = after {
+            filter.insert("_id.user", doc! { "$gt": after });
+        }
+
+        Ok(self
+            .col::<Document>(COL)
+            .find(
+                filter,
+                FindOptions::builder()
+                    .projection(doc! { "_id": 1_i32 })
+                    .limit(limit)
+                    .sort(doc! {
+                        "_id.user": 1_i32
+                    })
+                    .build(),
+            )
+            .await
+            .map_err(|_| create_database_error!("find", COL))?
+            .filter_map(|s| async { s.ok() })
+            .filter_map(|doc| async move {
+                doc.get_document("_id")
+                    .ok()?
+                    .get_str("user")
+                    .ok()
+                    .map(|id| id.to_string())
+            })
+            .collect()
+            .await)
+    }
+
     /// Fetch all memberships for a user
     async fn fetch_all_memberships<'a>(&self, user_id: &str) -> Result<Vec<Member>> {
         Ok(self
@@ -75,6 +153,21 @@ impl AbstractServerMembers for MongoDb {
             .await)
     }
 
+    /// Fetch all members whose timeout lapsed by the given time
+    async fn fetch_expired_timeouts(&self, now: Timestamp) -> Result<Vec<Member>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "timeout": {
+                    "$lte": bson::to_bson(&now)
+                        .map_err(|_| create_database_error!("to_bson", "timeout"))?
+                }
+            }
+        )
+    }
+
     /// Fetch multiple members by their ids
     async fn fetch_members<'a>(&self, server_id: &str, ids: &'a [String]) -> Result<Vec<Member>> {
         Ok(self
@@ -115,6 +208,112 @@ impl AbstractServerMembers for MongoDb {
             .map_err(|_| create_database_error!("count_documents", COL))
     }
 
+    /// Fetch the member counts of several servers
+    async fn fetch_member_counts(&self, server_ids: &[String]) -> Result<HashMap<String, usize>> {
+        Ok(self
+            .col::<Document>(COL)
+            .aggregate(
+                vec![
+                    doc! {
+                        "$match": {
+                            "_id.server": {
+                                "$in": server_ids
+                            }
+                        }
+                    },
+                    doc! {
+                        "$group": {
+                            "_id": "$_id.server",
+                            "count": {
+                                "$sum": 1_i32
+                            }
+                        }
+                    },
+                ],
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("aggregate", COL))?
+            .filter_map(|s| async { s.ok() })
+            .filter_map(|doc| async move {
+                Some((
+                    doc.get_str("_id").ok()?.to_string(),
+                    doc.get_i32("count").ok()? as usize,
+                ))
+            })
+            .collect()
+            .await)
+    }
+
+    /// Fetch number of members with a given role
+    async fn fetch_role_member_count(&self, server_id: &str, role_id: &str) -> Result<usize> {
+        self.col::<Member>(COL)
+            .count_documents(
+                doc! {
+                    "_id.server": server_id,
+                    "roles": role_id
+                },
+                None,
+            )
+            .await
+            .map(|c| c as usize)
+            .map_err(|_| create_database_error!("count_documents", COL))
+    }
+
+    /// Fetch the number of members with each role across several servers
+    async fn fetch_role_member_counts(
+        &self,
+        server_ids: &[String],
+    ) -> Result<HashMap<String, HashMap<String, usize>>> {
+        let mut counts: HashMap<String, HashMap<String, usize>> = HashMap::new();
+        let mut cursor = self
+            .col::<Document>(COL)
+            .aggregate(
+                vec![
+                    doc! {
+                        "$match": {
+                            "_id.server": {
+                                "$in": server_ids
+                            },
+                            "roles.0": {
+                                "$exists": true
+                            }
+                        }
+                    },
+                    doc! {
+                        "$unwind": "$roles"
+                    },
+                    doc! {
+                        "$group": {
+                            "_id": {
+                                "server": "$_id.server",
+                                "role": "$roles"
+                            },
+                            "count": {
+                                "$sum": 1_i32
+                            }
+                        }
+                    },
+                ],
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("aggregate", COL))?;
+
+        while let Some(Ok(doc)) = cursor.next().await {
+            if let (Ok(id), Ok(count)) = (doc.get_document("_id"), doc.get_i32("count")) {
+                if let (Ok(server), Ok(role)) = (id.get_str("server"), id.get_str("role")) {
+                    counts
+                        .entry(server.to_string())
+                        .or_default()
+                        .insert(role.to_string(), count as usize);
+                }
+            }
+        }
+
+        Ok(counts)
+    }
+
     /// Fetch server count of a user
     async fn fetch_server_count(&self, user_id: &str) -> Result<usize> {
         self.col::<Member>(COL)
@@ -129,6 +328,90 @@ impl AbstractServerMembers for MongoDb {
             .map_err(|_| create_database_error!("count_documents", COL))
     }
 
+    /// Fetch the server counts of several users
+    async fn fetch_server_counts(&self, user_ids: &[String]) -> Result<HashMap<String, usize>> {
+        Ok(self
+            .col::<Document>(COL)
+            .aggregate(
+                vec![
+                    doc! {
+                        "$match": {
+                            "_id.user": {
+                                "$in": user_ids
+                            }
+                        }
+                    },
+                    doc! {
+                        "$group": {
+                            "_id": "$_id.user",
+                            "count": {
+                                "$sum": 1_i32
+                            }
+                        }
+                    },
+                ],
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("aggregate", COL))?
+            .filter_map(|s| async { s.ok() })
+            .filter_map(|doc| async move {
+                Some((
+                    doc.get_str("_id").ok()?.to_string(),
+                    doc.get_i32("count").ok()? as usize,
+                ))
+            })
+            .collect()
+            .await)
+    }
+
+    /// Fetch ids of users who sent or acknowledged a message in any of the given channels after a given message id
+    async fn fetch_recently_active_users(
+        &self,
+        channel_ids: &[String],
+        after: &str,
+    ) -> Result<HashSet<String>> {
+        let authors = self
+            .col::<Document>("messages")
+            .distinct(
+                "author",
+                doc! {
+                    "channel": {
+                        "$in": channel_ids
+                    },
+                    "_id": {
+                        "$gte": after
+                    }
+                },
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("distinct", "messages"))?;
+
+        let readers = self
+            .col::<Document>("channel_unreads")
+            .distinct(
+                "_id.user",
+                doc! {
+                    "_id.channel": {
+                        "$in": channel_ids
+                    },
+                    "last_id": {
+                        "$gte": after
+                    }
+                },
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("distinct", "channel_unreads"))?;
+
+        Ok(authors
+            .into_iter()
+            .chain(readers)
+            .filter_map(|id| id.as_str().map(|id| id.to_string()))
+            .collect())
+    }
+
     /// Update information for a server member
     async fn update_member(
         &self,
@@ -151,6 +434,60 @@ impl AbstractServerMembers for MongoDb {
         .map(|_| ())
     }
 
+    /// Add a role to many members of a server at once
+    async fn add_role_to_members(
+        &self,
+        server_id: &str,
+        role_id: &str,
+        user_ids: &[String],
+    ) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_many(
+                doc! {
+                    "_id.server": server_id,
+                    "_id.user": {
+                        "$in": user_ids
+                    }
+                },
+                doc! {
+                    "$addToSet": {
+                        "roles": role_id
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_many", COL))
+    }
+
+    /// Remove a role from many members of a server at once
+    async fn remove_role_from_members(
+        &self,
+        server_id: &str,
+        role_id: &str,
+        user_ids: &[String],
+    ) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_many(
+                doc! {
+                    "_id.server": server_id,
+                    "_id.user": {
+                        "$in": user_ids
+                    }
+                },
+                doc! {
+                    "$pull": {
+                        "roles": role_id
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_many", COL))
+    }
+
     /// Delete a server member by their id
     async fn delete_member(&self, id: &MemberCompositeKey) -> Result<()> {
         query!(
@@ -173,6 +510,11 @@ impl IntoDocumentPath for FieldsMember {
             FieldsMember::Nickname => "nickname",
             FieldsMember::Roles => "roles",
             FieldsMember::Timeout => "timeout",
+            FieldsMember::TimeoutReason => "timeout_reason",
+            FieldsMember::TimeoutModerator => "timeout_moderator",
+            FieldsMember::Bio => "bio",
+            FieldsMember::Banner => "banner",
+            FieldsMember::Pronouns => "pronouns",
         })
     }
 }
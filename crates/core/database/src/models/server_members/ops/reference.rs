@@ -1,3 +1,6 @@
+use std::collections::{HashMap, HashSet};
+
+use iso8601_timestamp::Timestamp;
 use revolt_result::Result;
 
 use crate::ReferenceDb;
@@ -40,6 +43,48 @@ impl AbstractServerMembers for ReferenceDb {
             .collect())
     }
 
+    /// Fetch members who joined through an invite, ordered by user id
+    async fn fetch_invited_members(
+        &self,
+        server_id: &str,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<Member>> {
+        let server_members = self.server_members.lock().await;
+        let mut members: Vec<Member> = server_members
+            .values()
+            .filter(|member| {
+                member.id.server == server_id
+                    && member.invite.is_some()
+                    && after.as_ref().map_or(true, |v| &member.id.user > v)
+            })
+            .cloned()
+            .collect();
+
+        members.sort_by(|a, b| a.id.user.cmp(&b.id.user));
+        members.truncate(limit as usize);
+        Ok(members)
+    }
+
+    /// Fetch a page of member ids in a server, ordered by user id
+    async fn fetch_member_ids(
+        &self,
+        server_id: &str,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<String>> {
+        let server_members = self.server_members.lock().await;
+        let mut ids: Vec<String> = server_members
+            .keys()
+            .filter(|key| key.server == server_id && after.as_ref().map_or(true, |v| &key.user > v))
+            .map(|key| key.user.clone())
+            .collect();
+
+        ids.sort();
+        ids.truncate(limit as usize);
+        Ok(ids)
+    }
+
     /// Fetch all memberships for a user
     async fn fetch_all_memberships<'a>(&self, user_id: &str) -> Result<Vec<Member>> {
         let server_members = self.server_members.lock().await;
@@ -50,6 +95,16 @@ impl AbstractServerMembers for ReferenceDb {
             .collect())
     }
 
+    /// Fetch all members whose timeout lapsed by the given time
+    async fn fetch_expired_timeouts(&self, now: Timestamp) -> Result<Vec<Member>> {
+        let server_members = self.server_members.lock().await;
+        Ok(server_members
+            .values()
+            .filter(|member| member.timeout.is_some_and(|timeout| *timeout <= *now))
+            .cloned()
+            .collect())
+    }
+
     /// Fetch multiple members by their ids
     async fn fetch_members<'a>(&self, server_id: &str, ids: &'a [String]) -> Result<Vec<Member>> {
         let server_members = self.server_members.lock().await;
@@ -75,6 +130,52 @@ impl AbstractServerMembers for ReferenceDb {
             .count())
     }
 
+    /// Fetch the member counts of several servers
+    async fn fetch_member_counts(&self, server_ids: &[String]) -> Result<HashMap<String, usize>> {
+        let server_members = self.server_members.lock().await;
+        let mut counts = HashMap::new();
+        for member in server_members.values() {
+            if server_ids.contains(&member.id.server) {
+                *counts.entry(member.id.server.clone()).or_default() += 1;
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Fetch number of members with a given role
+    async fn fetch_role_member_count(&self, server_id: &str, role_id: &str) -> Result<usize> {
+        let server_members = self.server_members.lock().await;
+        Ok(server_members
+            .values()
+            .filter(|member| {
+                member.id.server == server_id && member.roles.iter().any(|role| role == role_id)
+            })
+            .count())
+    }
+
+    /// Fetch the number of members with each role across several servers
+    async fn fetch_role_member_counts(
+        &self,
+        server_ids: &[String],
+    ) -> Result<HashMap<String, HashMap<String, usize>>> {
+        let server_members = self.server_members.lock().await;
+        let mut counts: HashMap<String, HashMap<String, usize>> = HashMap::new();
+        for member in server_members.values() {
+            if server_ids.contains(&member.id.server) {
+                for role in &member.roles {
+                    *counts
+                        .entry(member.id.server.clone())
+                        .or_default()
+                        .entry(role.clone())
+                        .or_default() += 1;
+                }
+            }
+        }
+
+        Ok(counts)
+    }
+
     /// Fetch server count of a user
     async fn fetch_server_count(&self, user_id: &str) -> Result<usize> {
         let server_members = self.server_members.lock().await;
@@ -84,6 +185,54 @@ impl AbstractServerMembers for ReferenceDb {
             .count())
     }
 
+    /// Fetch the server counts of several users
+    async fn fetch_server_counts(&self, user_ids: &[String]) -> Result<HashMap<String, usize>> {
+        let server_members = self.server_members.lock().await;
+        let mut counts = HashMap::new();
+        for member in server_members.values() {
+            if user_ids.contains(&member.id.user) {
+                *counts.entry(member.id.user.clone()).or_default() += 1;
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Fetch ids of users who sent or acknowledged a message in any of the given channels after a given message id
+    async fn fetch_recently_active_users(
+        &self,
+        channel_ids: &[String],
+        after: &str,
+    ) -> Result<HashSet<String>> {
+        let mut users: HashSet<String> = self
+            .messages
+            .lock()
+            .await
+            .values()
+            .filter(|message| {
+                channel_ids.contains(&message.channel) && message.id.as_str() >= after
+            })
+            .map(|message| message.author.clone())
+            .collect();
+
+        users.extend(
+            self.channel_unreads
+                .lock()
+                .await
+                .values()
+                .filter(|unread| {
+                    channel_ids.contains(&unread.id.channel)
+                        && unread
+                            .last_id
+                            .as_deref()
+                            .is_some_and(|last_id| last_id >= after)
+                })
+                .map(|unread| unread.id.user.clone()),
+        );
+
+        Ok(users)
+    }
+
     /// Update information for a server member
     async fn update_member(
         &self,
@@ -105,6 +254,43 @@ impl AbstractServerMembers for ReferenceDb {
         }
     }
 
+    /// Add a role to many members of a server at once
+    async fn add_role_to_members(
+        &self,
+        server_id: &str,
+        role_id: &str,
+        user_ids: &[String],
+    ) -> Result<()> {
+        let mut server_members = self.server_members.lock().await;
+        for member in server_members.values_mut() {
+            if member.id.server == server_id
+                && user_ids.contains(&member.id.user)
+                && !member.roles.iter().any(|role| role == role_id)
+            {
+                member.roles.push(role_id.to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove a role from many members of a server at once
+    async fn remove_role_from_members(
+        &self,
+        server_id: &str,
+        role_id: &str,
+        user_ids: &[String],
+    ) -> Result<()> {
+        let mut server_members = self.server_members.lock().await;
+        for member in server_members.values_mut() {
+            if member.id.server == server_id && user_ids.contains(&member.id.user) {
+                member.roles.retain(|role| role != role_id);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Delete a server member by their id
     async fn delete_member(&self, id: &MemberCompositeKey) -> Result<()> {
         let mut server_members = self.server_members.lock().await;
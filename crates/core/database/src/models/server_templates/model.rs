@@ -0,0 +1,425 @@
+use std::collections::HashMap;
+
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0;
+use revolt_permissions::OverrideField;
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::{Category, Channel, Database, Invite, Role, Server, SystemMessageChannels, User};
+
+auto_derived_partial!(
+    /// Server Template
+    pub struct ServerTemplate {
+        /// Template code
+        #[serde(rename = "_id")]
+        pub code: String,
+        /// Id of the server this template was captured from
+        pub server_id: String,
+        /// Id of the user who created this template
+        pub creator_id: String,
+
+        /// Name of the template
+        pub name: String,
+        /// Description of the template
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Captured server structure
+        pub structure: ServerStructure,
+        /// Time at which the structure was last captured
+        pub updated_at: Timestamp,
+    },
+    "PartialServerTemplate"
+);
+
+auto_derived!(
+    /// Snapshot of a server's structure
+    pub struct ServerStructure {
+        /// Default set of server and channel permissions
+        pub default_permissions: i64,
+        /// Roles, keyed by their id within the template
+        #[serde(
+            default = "HashMap::<String, Role>::new",
+            skip_serializing_if = "HashMap::<String, Role>::is_empty"
+        )]
+        pub roles: HashMap<String, Role>,
+        /// Channels in the order they appear in the server
+        #[serde(default)]
+        pub channels: Vec<TemplateChannel>,
+        /// Categories for this server
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub categories: Vec<Category>,
+        /// Configuration for sending system event messages
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub system_messages: Option<SystemMessageChannels>,
+    }
+
+    /// Channel captured in a template
+    pub struct TemplateChannel {
+        /// Id of the channel within the template
+        pub id: String,
+        /// Type of channel
+        pub channel_type: TemplateChannelType,
+
+        /// Display name of the channel
+        pub name: String,
+        /// Channel description
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Default permissions assigned to users in this channel
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to this channel
+        #[serde(
+            default = "HashMap::<String, OverrideField>::new",
+            skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+        )]
+        pub role_permissions: HashMap<String, OverrideField>,
+        /// Whether this channel inherits permissions from its category
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub sync_permissions: bool,
+
+        /// Whether this channel is marked as not safe for work
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub nsfw: bool,
+    }
+
+    /// Type of channel captured in a template
+    pub enum TemplateChannelType {
+        Text,
+        Voice,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl ServerTemplate {
+    /// Capture a server's structure into a new template
+    pub async fn create(
+        db: &Database,
+        server: &Server,
+        creator: &User,
+        name: String,
+        description: Option<String>,
+    ) -> Result<ServerTemplate> {
+        let template = ServerTemplate {
+            code: Invite::generate_code(),
+            server_id: server.id.clone(),
+            creator_id: creator.id.clone(),
+            name,
+            description,
+            structure: ServerStructure::capture(db, server).await?,
+            updated_at: Timestamp::now_utc(),
+        };
+
+        db.insert_server_template(&template).await?;
+        Ok(template)
+    }
+
+    /// Update template data
+    pub async fn update(&mut self, db: &Database, partial: PartialServerTemplate) -> Result<()> {
+        self.apply_options(partial.clone());
+        db.update_server_template(&self.code, &partial).await
+    }
+
+    /// Re-capture the structure of the server this template was created from
+    pub async fn sync(&mut self, db: &Database, server: &Server) -> Result<()> {
+        self.update(
+            db,
+            PartialServerTemplate {
+                structure: Some(ServerStructure::capture(db, server).await?),
+                updated_at: Some(Timestamp::now_utc()),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Create a new server from this template
+    pub async fn create_server(
+        &self,
+        db: &Database,
+        data: v0::DataCreateServer,
+        owner: &User,
+    ) -> Result<(Server, Vec<Channel>)> {
+        let structure = &self.structure;
+        let server_id = Ulid::new().to_string();
+
+        // Every object receives a fresh id, so keep track of the mapping
+        let role_ids: HashMap<String, String> = structure
+            .roles
+            .keys()
+            .map(|id| (id.clone(), Ulid::new().to_string()))
+            .collect();
+
+        let channel_ids: HashMap<String, String> = structure
+            .channels
+            .iter()
+            .map(|channel| (channel.id.clone(), Ulid::new().to_string()))
+            .collect();
+
+        let remap_roles = |role_permissions: &HashMap<String, OverrideField>| {
+            role_permissions
+                .iter()
+                .filter_map(|(id, value)| role_ids.get(id).map(|id| (id.clone(), *value)))
+                .collect::<HashMap<String, OverrideField>>()
+        };
+
+        let remap_channel =
+            |id: &Option<String>| id.as_ref().and_then(|id| channel_ids.get(id).cloned());
+
+        let channels: Vec<Channel> = structure
+            .channels
+            .iter()
+            .map(|channel| {
+                let id = channel_ids[&channel.id].clone();
+                let server = server_id.clone();
+                let name = channel.name.clone();
+                let description = channel.description.clone();
+                let default_permissions = channel.default_permissions;
+                let role_permissions = remap_roles(&channel.role_permissions);
+
+                match channel.channel_type {
+                    TemplateChannelType::Text => Channel::TextChannel {
+                        id,
+                        server,
+                        name,
+                        description,
+                        icon: None,
+                        last_message_id: None,
+                        default_permissions,
+                        role_permissions,
+                        sync_permissions: channel.sync_permissions,
+                        nsfw: channel.nsfw,
+                        archived: false,
+                    },
+                    TemplateChannelType::Voice => Channel::VoiceChannel {
+                        id,
+                        server,
+                        name,
+                        description,
+                        icon: None,
+                        default_permissions,
+                        role_permissions,
+                        sync_permissions: channel.sync_permissions,
+                        nsfw: channel.nsfw,
+                    },
+                }
+            })
+            .collect();
+
+        let categories: Vec<Category> = structure
+            .categories
+            .iter()
+            .map(|category| Category {
+                id: Ulid::new().to_string(),
+                title: category.title.clone(),
+                channels: category
+                    .channels
+                    .iter()
+                    .filter_map(|id| channel_ids.get(id).cloned())
+                    .collect(),
+                default_permissions: category.default_permissions,
+                role_permissions: remap_roles(&category.role_permissions),
+            })
+            .collect();
+
+        let system_messages =
+            structure
+                .system_messages
+                .as_ref()
+                .map(|system_messages| SystemMessageChannels {
+                    user_joined: remap_channel(&system_messages.user_joined),
+                    user_left: remap_channel(&system_messages.user_left),
+                    user_kicked: remap_channel(&system_messages.user_kicked),
+                    user_banned: remap_channel(&system_messages.user_banned),
+                });
+
+        let server = Server {
+            id: server_id,
+            owner: owner.id.to_string(),
+            name: data.name,
+            description: data.description,
+            channels: channels.iter().map(|c| c.id().to_string()).collect(),
+            categories: if categories.is_empty() {
+                None
+            } else {
+                Some(categories)
+            },
+            system_messages,
+            roles: structure
+                .roles
+                .iter()
+                .map(|(id, role)| (role_ids[id].clone(), role.clone()))
+                .collect(),
+            default_permissions: structure.default_permissions,
+            nsfw: data.nsfw.unwrap_or(false),
+
+            analytics: false,
+            banner: None,
+            discoverable: false,
+            featured: false,
+            flags: None,
+            icon: None,
+            rules: None,
+            verification: None,
+            locked: false,
+            scheduled_deletion: None,
+            tags: vec![],
+            category: None,
+            language: None,
+            member_count: 0,
+        };
+
+        for channel in &channels {
+            db.insert_channel(channel).await?;
+        }
+
+        db.insert_server(&server).await?;
+        Ok((server, channels))
+    }
+}
+
+impl ServerStructure {
+    /// Capture the current structure of a server
+    pub async fn capture(db: &Database, server: &Server) -> Result<ServerStructure> {
+        let mut channels = db.fetch_channels(&server.channels).await?;
+
+        // Preserve the order in which channels appear in the server
+        channels.sort_by_key(|channel| {
+            server
+                .channels
+                .iter()
+                .position(|id| id == channel.id())
+                .unwrap_or_default()
+        });
+
+        Ok(ServerStructure {
+            default_permissions: server.default_permissions,
+            // Role icons belong to the source server and are not carried over
+            roles: server
+                .roles
+                .iter()
+                .map(|(id, role)| {
+                    (
+                        id.clone(),
+                        Role {
+                            icon: None,
+                            ..role.clone()
+                        },
+                    )
+                })
+                .collect(),
+            channels: channels
+                .into_iter()
+                .filter_map(|channel| match channel {
+                    Channel::TextChannel {
+                        id,
+                        name,
+                        description,
+                        default_permissions,
+                        role_permissions,
+                        sync_permissions,
+                        nsfw,
+                        ..
+                    } => Some(TemplateChannel {
+                        id,
+                        channel_type: TemplateChannelType::Text,
+                        name,
+                        description,
+                        default_permissions,
+                        role_permissions,
+                        sync_permissions,
+                        nsfw,
+                    }),
+                    Channel::VoiceChannel {
+                        id,
+                        name,
+                        description,
+                        default_permissions,
+                        role_permissions,
+                        sync_permissions,
+                        nsfw,
+                        ..
+                    } => Some(TemplateChannel {
+                        id,
+                        channel_type: TemplateChannelType::Voice,
+                        name,
+                        description,
+                        default_permissions,
+                        role_permissions,
+                        sync_permissions,
+                        nsfw,
+                    }),
+                    _ => None,
+                })
+                .collect(),
+            categories: server.categories.clone().unwrap_or_default(),
+            system_messages: server.system_messages.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use revolt_models::v0;
+
+    use crate::{fixture, ServerTemplate};
+
+    #[async_std::test]
+    async fn create_server_from_template() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_roles",
+                owner user 0
+                channel channel 3
+                server server 4);
+
+            let template = ServerTemplate::create(&db, &server, &owner, "Course".to_string(), None)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                db.fetch_server_template(&template.code).await.unwrap(),
+                template
+            );
+
+            let (created, channels) = template
+                .create_server(
+                    &db,
+                    v0::DataCreateServer {
+                        name: "Copy".to_string(),
+                        ..Default::default()
+                    },
+                    &owner,
+                )
+                .await
+                .unwrap();
+
+            assert_ne!(created.id, server.id);
+            assert_eq!(created.default_permissions, server.default_permissions);
+            assert_eq!(created.roles.len(), server.roles.len());
+            assert!(created
+                .roles
+                .keys()
+                .all(|id| !server.roles.contains_key(id)));
+
+            // Overrides should point at the new roles
+            assert_eq!(channels.len(), 1);
+            assert_ne!(channels[0].id(), channel.id());
+            if let crate::Channel::TextChannel {
+                role_permissions, ..
+            } = &channels[0]
+            {
+                assert_eq!(role_permissions.len(), 1);
+                assert!(role_permissions
+                    .keys()
+                    .all(|id| created.roles.contains_key(id)));
+            } else {
+                unreachable!()
+            }
+
+            assert_eq!(db.fetch_server(&created.id).await.unwrap(), created);
+        });
+    }
+}
@@ -1,11 +1,23 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
 
+use authifier::models::EmailVerification;
+use iso8601_timestamp::Timestamp;
+use revolt_config::config;
 use revolt_models::v0::{self, DataCreateServerChannel};
-use revolt_permissions::{OverrideField, DEFAULT_PERMISSION_SERVER};
-use revolt_result::Result;
+use revolt_permissions::{
+    calculate_channel_permissions, ChannelPermission, OverrideField, DEFAULT_PERMISSION_SERVER,
+};
+use revolt_result::{create_error, Result};
 use ulid::Ulid;
 
-use crate::{events::client::EventV1, Channel, Database, File, User};
+use crate::{
+    events::client::EventV1, util::permissions::DatabasePermissionQuery, Channel, Database, Emoji,
+    File, Member, User,
+};
 
 auto_derived_partial!(
     /// Server
@@ -55,12 +67,42 @@ auto_derived_partial!(
         /// Whether this server is flagged as not safe for work
         #[serde(skip_serializing_if = "crate::if_false", default)]
         pub nsfw: bool,
+        /// Rules new members must accept before participating
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rules: Option<String>,
+        /// Requirements members must meet before participating
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub verification: Option<VerificationRequirements>,
+        /// Whether new members are prevented from joining
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub locked: bool,
         /// Whether to enable analytics
         #[serde(skip_serializing_if = "crate::if_false", default)]
         pub analytics: bool,
         /// Whether this server should be publicly discoverable
         #[serde(skip_serializing_if = "crate::if_false", default)]
         pub discoverable: bool,
+        /// Whether this server is featured on discovery
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub featured: bool,
+
+        /// Tags describing this server on discovery
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub tags: Vec<String>,
+        /// Category this server is listed under on discovery
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub category: Option<String>,
+        /// Primary language used in this server
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub language: Option<String>,
+
+        /// Number of members in this server
+        #[serde(default)]
+        pub member_count: u32,
+
+        /// Time at which this server will be permanently deleted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub scheduled_deletion: Option<Timestamp>,
     },
     "PartialServer"
 );
@@ -83,11 +125,21 @@ auto_derived_partial!(
         /// Ranking of this role
         #[serde(default)]
         pub rank: i64,
+
+        /// Icon attachment for this role
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub icon: Option<File>,
+        /// Unicode emoji shown in place of an icon
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub emoji: Option<String>,
+        /// Whether members can mention this role
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub mentionable: bool,
     },
     "PartialRole"
 );
 
-auto_derived!(
+auto_derived_partial!(
     /// Channel category
     pub struct Category {
         /// Unique ID for this category
@@ -96,8 +148,21 @@ auto_derived!(
         pub title: String,
         /// Channels in this category
         pub channels: Vec<String>,
-    }
 
+        /// Default permissions assigned to users in this category
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to this category
+        #[serde(
+            default = "HashMap::<String, OverrideField>::new",
+            skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+        )]
+        pub role_permissions: HashMap<String, OverrideField>,
+    },
+    "PartialCategory"
+);
+
+auto_derived!(
     /// System message channel assignments
     pub struct SystemMessageChannels {
         /// ID of channel to send user join messages in
@@ -114,6 +179,23 @@ auto_derived!(
         pub user_banned: Option<String>,
     }
 
+    /// Requirements members must meet before participating in a server
+    #[derive(Default)]
+    pub struct VerificationRequirements {
+        /// Whether members must have a verified email address
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub email: bool,
+        /// Whether members must have multi-factor authentication enabled
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub mfa: bool,
+        /// Minimum age of a member's account in minutes
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub account_age: Option<u32>,
+        /// Minimum number of minutes since a member joined the server
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub membership_age: Option<u32>,
+    }
+
     /// Optional fields on server object
     pub enum FieldsServer {
         Description,
@@ -121,14 +203,49 @@ auto_derived!(
         SystemMessages,
         Icon,
         Banner,
+        Rules,
+        Category,
+        Language,
+        Verification,
+        ScheduledDeletion,
     }
 
     /// Optional fields on server object
     pub enum FieldsRole {
         Colour,
+        Icon,
+        Emoji,
+    }
+
+    /// Optional fields on category object
+    pub enum FieldsCategory {
+        DefaultPermissions,
     }
 );
 
+/// Filter used when searching discoverable servers
+#[derive(Default)]
+pub struct DiscoveryQuery {
+    /// Text to search for in names, descriptions and tags
+    pub query: Option<String>,
+    /// Only include servers with all of these tags
+    pub tags: Vec<String>,
+    /// Only include servers listed under this category
+    pub category: Option<String>,
+    /// Only include servers using this language
+    pub language: Option<String>,
+    /// Only include featured servers
+    pub featured: bool,
+    /// Whether to include servers marked as NSFW
+    pub include_nsfw: bool,
+    /// Order in which to return servers
+    pub sort: v0::DiscoverySort,
+    /// Number of servers to skip
+    pub offset: Option<i64>,
+    /// Maximum number of servers to fetch
+    pub limit: Option<i64>,
+}
+
 #[allow(clippy::disallowed_methods)]
 impl Server {
     /// Create a server
@@ -151,10 +268,19 @@ impl Server {
             banner: None,
             categories: None,
             discoverable: false,
+            featured: false,
             flags: None,
             icon: None,
             roles: HashMap::new(),
+            rules: None,
+            verification: None,
+            locked: false,
             system_messages: None,
+            tags: vec![],
+            category: None,
+            language: None,
+            member_count: 0,
+            scheduled_deletion: None,
         };
 
         let channels: Vec<Channel> = if create_default_channels {
@@ -180,6 +306,16 @@ impl Server {
         Ok((server, channels))
     }
 
+    /// Find a page of discoverable servers matching a query
+    ///
+    /// Returns servers with their member counts and the total number of matches.
+    pub async fn discover(
+        db: &Database,
+        query: &DiscoveryQuery,
+    ) -> Result<(Vec<(Server, usize)>, usize)> {
+        db.fetch_discoverable_servers(query).await
+    }
+
     /// Update server data
     pub async fn update<'a>(
         &mut self,
@@ -217,6 +353,166 @@ impl Server {
         db.delete_server(&self.id).await
     }
 
+    /// Schedule this server to be deleted once the grace period has passed
+    ///
+    /// Until then, the server is hidden from everyone but its owner.
+    pub async fn schedule_deletion(&mut self, db: &Database) -> Result<()> {
+        if self.scheduled_deletion.is_some() {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        let config = config().await;
+        let scheduled_deletion = Timestamp::now_utc()
+            .checked_add(iso8601_timestamp::Duration::days(
+                config.features.advanced.server_deletion_grace_days as i64,
+            ))
+            .unwrap_or_else(Timestamp::now_utc);
+
+        db.update_server(
+            &self.id,
+            &PartialServer {
+                scheduled_deletion: Some(scheduled_deletion),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+        self.scheduled_deletion = Some(scheduled_deletion);
+
+        EventV1::ServerDelete {
+            id: self.id.clone(),
+        }
+        .p(self.id.clone())
+        .await;
+
+        // The owner keeps the server so that they may restore it
+        let owner = db.fetch_user(&self.owner).await?;
+        let member = db.fetch_member(&self.id, &self.owner).await?;
+        let channels = db.fetch_channels(&self.channels).await?;
+        let emojis = db.fetch_emoji_by_parent_id(&self.id).await?;
+        self.send_to_member(db, &owner, &member, &channels, &emojis)
+            .await;
+
+        Ok(())
+    }
+
+    /// Restore a server which was scheduled for deletion
+    pub async fn restore(&mut self, db: &Database) -> Result<()> {
+        if self.scheduled_deletion.is_none() {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        db.update_server(
+            &self.id,
+            &PartialServer::default(),
+            vec![FieldsServer::ScheduledDeletion],
+        )
+        .await?;
+
+        self.scheduled_deletion = None;
+
+        // Members were told the server was deleted, so send it to them again
+        let members = db.fetch_all_members(&self.id).await?;
+        let user_ids: Vec<String> = members
+            .iter()
+            .map(|member| member.id.user.clone())
+            .collect();
+
+        let users: HashMap<String, User> = db
+            .fetch_users(&user_ids)
+            .await?
+            .into_iter()
+            .map(|user| (user.id.clone(), user))
+            .collect();
+
+        let channels = db.fetch_channels(&self.channels).await?;
+        let emojis = db.fetch_emoji_by_parent_id(&self.id).await?;
+
+        // A member who can't be sent the server shouldn't stop the others from receiving it
+        for member in &members {
+            match users.get(&member.id.user) {
+                Some(user) => {
+                    self.send_to_member(db, user, member, &channels, &emojis)
+                        .await
+                }
+                None => error!(
+                    "Failed to send restored server {} to {}: unknown user",
+                    self.id, member.id.user
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send this server to a member along with the channels they can see
+    async fn send_to_member(
+        &self,
+        db: &Database,
+        user: &User,
+        member: &Member,
+        channels: &[Channel],
+        emojis: &[Emoji],
+    ) {
+        let query = DatabasePermissionQuery::new(db, user)
+            .server(self)
+            .member(member);
+
+        let mut visible = vec![];
+        for channel in channels {
+            let mut channel_query = query.clone().channel(channel);
+
+            if calculate_channel_permissions(&mut channel_query)
+                .await
+                .has_channel_permission(ChannelPermission::ViewChannel)
+            {
+                visible.push(channel.clone().into());
+            }
+        }
+
+        EventV1::ServerCreate {
+            id: self.id.clone(),
+            server: self.clone().into(),
+            channels: visible,
+            emojis: emojis.iter().cloned().map(|emoji| emoji.into()).collect(),
+        }
+        .private(user.id.clone())
+        .await;
+    }
+
+    /// Check whether this server's grace period has passed
+    pub fn is_deletion_due(&self) -> bool {
+        self.scheduled_deletion
+            .is_some_and(|scheduled_deletion| *scheduled_deletion <= *Timestamp::now_utc())
+    }
+
+    /// Permanently delete this server and mark every file belonging to it as deleted
+    pub async fn destroy(self, db: &Database) -> Result<()> {
+        let mut files: Vec<String> = self
+            .icon
+            .iter()
+            .chain(self.banner.iter())
+            .chain(self.roles.values().filter_map(|role| role.icon.as_ref()))
+            .map(|file| file.id.clone())
+            .collect();
+
+        for member in db.fetch_all_members(&self.id).await? {
+            files.extend(member.avatar.map(|file| file.id));
+            files.extend(member.banner.map(|file| file.id));
+        }
+
+        for event in db.fetch_server_events(&self.id).await? {
+            files.extend(event.cover.map(|file| file.id));
+        }
+
+        if !files.is_empty() {
+            db.mark_attachments_as_deleted(&files).await?;
+        }
+
+        self.delete(db).await
+    }
+
     /// Remove a field from Server
     pub fn remove_field(&mut self, field: &FieldsServer) {
         match field {
@@ -225,6 +521,11 @@ impl Server {
             FieldsServer::SystemMessages => self.system_messages = None,
             FieldsServer::Icon => self.icon = None,
             FieldsServer::Banner => self.banner = None,
+            FieldsServer::Rules => self.rules = None,
+            FieldsServer::Verification => self.verification = None,
+            FieldsServer::ScheduledDeletion => self.scheduled_deletion = None,
+            FieldsServer::Category => self.category = None,
+            FieldsServer::Language => self.language = None,
         }
     }
 
@@ -253,6 +554,217 @@ impl Server {
             Err(create_error!(NotFound))
         }
     }
+
+    /// Set the ranks of several roles at once
+    ///
+    /// Emits a single bulk event containing every role update.
+    pub async fn set_role_ranks(
+        &mut self,
+        db: &Database,
+        ranks: HashMap<String, i64>,
+    ) -> Result<()> {
+        if ranks
+            .keys()
+            .any(|role_id| !self.roles.contains_key(role_id))
+        {
+            return Err(create_error!(NotFound));
+        }
+
+        db.update_role_ranks(&self.id, &ranks).await?;
+
+        let mut events = vec![];
+        for (role_id, rank) in ranks {
+            if let Some(role) = self.roles.get_mut(&role_id) {
+                role.rank = rank;
+            }
+
+            events.push(EventV1::ServerRoleUpdate {
+                id: self.id.clone(),
+                role_id,
+                data: PartialRole {
+                    rank: Some(rank),
+                    ..Default::default()
+                }
+                .into(),
+                clear: vec![],
+            });
+        }
+
+        EventV1::Bulk { v: events }.p(self.id.clone()).await;
+
+        Ok(())
+    }
+
+    /// Find a category by its id
+    pub fn category(&self, category_id: &str) -> Option<&Category> {
+        self.categories.as_ref().and_then(|categories| {
+            categories
+                .iter()
+                .find(|category| category.id == category_id)
+        })
+    }
+
+    /// Find the category a channel belongs to
+    pub fn category_of(&self, channel_id: &str) -> Option<&Category> {
+        self.categories.as_ref().and_then(|categories| {
+            categories
+                .iter()
+                .find(|category| category.channels.iter().any(|id| id == channel_id))
+        })
+    }
+
+    /// Persist the current set of categories
+    async fn save_categories(&self, db: &Database) -> Result<()> {
+        db.update_server(
+            &self.id,
+            &PartialServer {
+                categories: Some(self.categories.clone().unwrap_or_default()),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
+    }
+
+    /// Create a category on this server
+    pub async fn create_category(&mut self, db: &Database, category: Category) -> Result<()> {
+        // Channels may only belong to one category at a time
+        let categories = self.categories.get_or_insert_with(Vec::new);
+        for existing in categories.iter_mut() {
+            existing
+                .channels
+                .retain(|id| !category.channels.contains(id));
+        }
+
+        categories.push(category.clone());
+        self.save_categories(db).await?;
+
+        EventV1::ServerCategoryUpdate {
+            id: self.id.clone(),
+            category_id: category.id.clone(),
+            data: category.into_optional().into(),
+            clear: vec![],
+        }
+        .p(self.id.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Update a category on this server
+    pub async fn update_category(
+        &mut self,
+        db: &Database,
+        category_id: &str,
+        partial: PartialCategory,
+        remove: Vec<FieldsCategory>,
+    ) -> Result<Category> {
+        let categories = self.categories.get_or_insert_with(Vec::new);
+
+        // Channels may only belong to one category at a time
+        if let Some(channels) = &partial.channels {
+            for existing in categories.iter_mut() {
+                if existing.id != category_id {
+                    existing.channels.retain(|id| !channels.contains(id));
+                }
+            }
+        }
+
+        let category = categories
+            .iter_mut()
+            .find(|category| category.id == category_id)
+            .ok_or_else(|| create_error!(NotFound))?;
+
+        for field in &remove {
+            category.remove_field(field);
+        }
+
+        category.apply_options(partial.clone());
+        let category = category.clone();
+        self.save_categories(db).await?;
+
+        EventV1::ServerCategoryUpdate {
+            id: self.id.clone(),
+            category_id: category_id.to_string(),
+            data: partial.into(),
+            clear: remove.into_iter().map(|v| v.into()).collect(),
+        }
+        .p(self.id.clone())
+        .await;
+
+        Ok(category)
+    }
+
+    /// Delete a category from this server
+    ///
+    /// Channels within the category are left uncategorised.
+    pub async fn delete_category(&mut self, db: &Database, category_id: &str) -> Result<()> {
+        let categories = self.categories.get_or_insert_with(Vec::new);
+        let length = categories.len();
+        categories.retain(|category| category.id != category_id);
+
+        if categories.len() == length {
+            return Err(create_error!(NotFound));
+        }
+
+        self.save_categories(db).await?;
+
+        EventV1::ServerCategoryDelete {
+            id: self.id.clone(),
+            category_id: category_id.to_string(),
+        }
+        .p(self.id.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Set role permission on a category
+    pub async fn set_category_role_permission(
+        &mut self,
+        db: &Database,
+        category_id: &str,
+        role_id: &str,
+        permissions: OverrideField,
+    ) -> Result<Category> {
+        let mut role_permissions = self
+            .category(category_id)
+            .map(|category| category.role_permissions.clone())
+            .ok_or_else(|| create_error!(NotFound))?;
+
+        role_permissions.insert(role_id.to_string(), permissions);
+
+        self.update_category(
+            db,
+            category_id,
+            PartialCategory {
+                role_permissions: Some(role_permissions),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
+    }
+}
+
+impl Category {
+    /// Into optional struct
+    pub fn into_optional(self) -> PartialCategory {
+        PartialCategory {
+            id: Some(self.id),
+            title: Some(self.title),
+            channels: Some(self.channels),
+            default_permissions: self.default_permissions,
+            role_permissions: Some(self.role_permissions),
+        }
+    }
+
+    /// Remove field from Category
+    pub fn remove_field(&mut self, field: &FieldsCategory) {
+        match field {
+            FieldsCategory::DefaultPermissions => self.default_permissions = None,
+        }
+    }
 }
 
 impl Role {
@@ -264,6 +776,9 @@ impl Role {
             colour: self.colour,
             hoist: Some(self.hoist),
             rank: Some(self.rank),
+            icon: self.icon,
+            emoji: self.emoji,
+            mentionable: Some(self.mentionable),
         }
     }
 
@@ -318,6 +833,8 @@ impl Role {
     pub fn remove_field(&mut self, field: &FieldsRole) {
         match field {
             FieldsRole::Colour => self.colour = None,
+            FieldsRole::Icon => self.icon = None,
+            FieldsRole::Emoji => self.emoji = None,
         }
     }
 
@@ -330,10 +847,74 @@ impl Role {
         .p(server_id.to_string())
         .await;
 
+        if let Some(icon) = &self.icon {
+            db.mark_attachment_as_deleted(&icon.id).await?;
+        }
+
         db.delete_role(server_id, role_id).await
     }
 }
 
+impl VerificationRequirements {
+    /// Check the requirements which can be determined without the user's account
+    pub fn is_met_by_member(&self, user: &User, member: &Member) -> bool {
+        if let Some(minutes) = self.account_age {
+            let created_at = Ulid::from_str(&user.id)
+                .map(|id| id.datetime())
+                .unwrap_or_else(|_| SystemTime::now());
+
+            if created_at
+                .elapsed()
+                .map(|elapsed| elapsed < Duration::from_secs(minutes as u64 * 60))
+                .unwrap_or(true)
+            {
+                return false;
+            }
+        }
+
+        if let Some(minutes) = self.membership_age {
+            if member
+                .joined_at
+                .checked_add(iso8601_timestamp::Duration::minutes(minutes as i64))
+                .map_or(true, |verified_at| *verified_at > *Timestamp::now_utc())
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Check whether a member meets these requirements
+    ///
+    /// Bots and members who hold any role are exempt.
+    pub async fn is_met(&self, db: &Database, user: &User, member: &Member) -> bool {
+        if user.bot.is_some() || !member.roles.is_empty() {
+            return true;
+        }
+
+        if !self.is_met_by_member(user, member) {
+            return false;
+        }
+
+        if self.email || self.mfa {
+            let Ok(account) = db.to_authifier_database().find_account(&user.id).await else {
+                return false;
+            };
+
+            if self.email && matches!(account.verification, EmailVerification::Pending { .. }) {
+                return false;
+            }
+
+            if self.mfa && !account.mfa.is_active() {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
 impl SystemMessageChannels {
     pub fn into_channel_ids(self) -> HashSet<String> {
         let mut ids = HashSet::new();
@@ -360,9 +941,14 @@ impl SystemMessageChannels {
 
 #[cfg(test)]
 mod tests {
+    use iso8601_timestamp::{Duration, Timestamp};
+    use revolt_models::v0;
     use revolt_permissions::{calculate_server_permissions, ChannelPermission};
 
-    use crate::{fixture, util::permissions::DatabasePermissionQuery};
+    use crate::{
+        fixture, util::permissions::DatabasePermissionQuery, DiscoveryQuery, Member, PartialServer,
+        RemovalIntention, Server, User, VerificationRequirements,
+    };
 
     #[async_std::test]
     async fn permissions() {
@@ -389,4 +975,185 @@ mod tests {
                 .has_channel_permission(ChannelPermission::BanMembers));
         });
     }
+
+    #[async_std::test]
+    async fn discovery() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_roles",
+                user user 2
+                server server 4);
+
+            let search = DiscoveryQuery {
+                query: Some("rust".to_string()),
+                ..Default::default()
+            };
+
+            let active = DiscoveryQuery {
+                query: Some("rust".to_string()),
+                sort: v0::DiscoverySort::Active,
+                ..Default::default()
+            };
+
+            let (results, total) = Server::discover(&db, &search).await.unwrap();
+            assert!(results.is_empty());
+            assert_eq!(total, 0);
+
+            db.update_server(
+                &server.id,
+                &PartialServer {
+                    discoverable: Some(true),
+                    tags: Some(vec!["rust".to_string()]),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .unwrap();
+
+            let (results, total) = Server::discover(&db, &search).await.unwrap();
+            assert_eq!(results.len(), 1);
+            assert_eq!(total, 1);
+            assert_eq!(results[0].0.id, server.id);
+            assert_eq!(results[0].1, 3);
+
+            // Member counts are kept up to date as members leave
+            db.fetch_member(&server.id, &user.id)
+                .await
+                .unwrap()
+                .remove(&db, &server, RemovalIntention::Leave, true)
+                .await
+                .unwrap();
+
+            let (results, _) = Server::discover(&db, &search).await.unwrap();
+            assert_eq!(results[0].1, 2);
+
+            db.update_server(
+                &server.id,
+                &PartialServer {
+                    nsfw: Some(true),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .unwrap();
+
+            let (results, _) = Server::discover(&db, &active).await.unwrap();
+            assert!(results.is_empty());
+
+            let active = DiscoveryQuery {
+                include_nsfw: true,
+                ..active
+            };
+
+            let (results, _) = Server::discover(&db, &active).await.unwrap();
+            assert_eq!(results.len(), 1);
+
+            // Pages are taken after counting every match
+            let (results, total) = Server::discover(
+                &db,
+                &DiscoveryQuery {
+                    offset: Some(1),
+                    ..active
+                },
+            )
+            .await
+            .unwrap();
+            assert!(results.is_empty());
+            assert_eq!(total, 1);
+        });
+    }
+
+    #[test]
+    fn verification_ages() {
+        let new_user = User {
+            id: ulid::Ulid::new().to_string(),
+            ..Default::default()
+        };
+
+        let old_user = User {
+            id: ulid::Ulid::from_parts(0, 0).to_string(),
+            ..Default::default()
+        };
+
+        let member = Member::default();
+        let account_age = VerificationRequirements {
+            account_age: Some(60),
+            ..Default::default()
+        };
+
+        assert!(!account_age.is_met_by_member(&new_user, &member));
+        assert!(account_age.is_met_by_member(&old_user, &member));
+
+        let membership_age = VerificationRequirements {
+            membership_age: Some(60),
+            ..Default::default()
+        };
+
+        assert!(!membership_age.is_met_by_member(&old_user, &member));
+        assert!(VerificationRequirements::default().is_met_by_member(&new_user, &member));
+    }
+
+    #[async_std::test]
+    async fn scheduled_deletion() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_roles",
+                owner user 0
+                user user 2
+                server server 4);
+
+            let can_view = |user: User, server: Server| {
+                let db = db.clone();
+                async move {
+                    let mut query = DatabasePermissionQuery::new(&db, &user).server(&server);
+                    calculate_server_permissions(&mut query)
+                        .await
+                        .has_channel_permission(ChannelPermission::ViewChannel)
+                }
+            };
+
+            let mut server = server;
+            assert!(!server.is_deletion_due());
+
+            server.schedule_deletion(&db).await.unwrap();
+            assert!(server.scheduled_deletion.is_some());
+            assert!(server.schedule_deletion(&db).await.is_err());
+
+            // Servers are kept around until their grace period has passed
+            assert!(!server.is_deletion_due());
+            assert!(Server {
+                scheduled_deletion: Timestamp::now_utc().checked_sub(Duration::seconds(1)),
+                ..server.clone()
+            }
+            .is_deletion_due());
+
+            let scheduled: Vec<String> = db
+                .fetch_servers_scheduled_for_deletion()
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|server| server.id)
+                .collect();
+            assert_eq!(scheduled, vec![server.id.clone()]);
+
+            // Only the owner may still see the server
+            let stored = db.fetch_server(&server.id).await.unwrap();
+            assert!(stored.scheduled_deletion.is_some());
+            assert!(can_view(owner.clone(), stored.clone()).await);
+            assert!(!can_view(user.clone(), stored).await);
+
+            server.restore(&db).await.unwrap();
+            assert!(server.scheduled_deletion.is_none());
+            assert!(server.restore(&db).await.is_err());
+
+            let stored = db.fetch_server(&server.id).await.unwrap();
+            assert!(stored.scheduled_deletion.is_none());
+            assert!(can_view(user, stored).await);
+            assert!(db
+                .fetch_servers_scheduled_for_deletion()
+                .await
+                .unwrap()
+                .is_empty());
+        });
+    }
 }
@@ -1,6 +1,8 @@
+use std::collections::HashMap;
+
 use revolt_result::Result;
 
-use crate::{FieldsRole, FieldsServer, PartialRole, PartialServer, Role, Server};
+use crate::{DiscoveryQuery, FieldsRole, FieldsServer, PartialRole, PartialServer, Role, Server};
 
 mod mongodb;
 mod reference;
@@ -16,6 +18,20 @@ pub trait AbstractServers: Sync + Send {
     /// Fetch a servers by their ids
     async fn fetch_servers<'a>(&self, ids: &'a [String]) -> Result<Vec<Server>>;
 
+    /// Fetch a page of discoverable servers matching a query along with their member counts
+    ///
+    /// Also returns the total number of matching servers.
+    async fn fetch_discoverable_servers(
+        &self,
+        query: &DiscoveryQuery,
+    ) -> Result<(Vec<(Server, usize)>, usize)>;
+
+    /// Fetch servers which have analytics enabled
+    async fn fetch_servers_with_analytics(&self) -> Result<Vec<Server>>;
+
+    /// Fetch servers which are scheduled for deletion
+    async fn fetch_servers_scheduled_for_deletion(&self) -> Result<Vec<Server>>;
+
     /// Update a server with new information
     async fn update_server(
         &self,
@@ -24,6 +40,9 @@ pub trait AbstractServers: Sync + Send {
         remove: Vec<FieldsServer>,
     ) -> Result<()>;
 
+    /// Adjust the stored member count of a server
+    async fn update_server_member_count(&self, id: &str, delta: i32) -> Result<()>;
+
     /// Delete a server by its id
     async fn delete_server(&self, id: &str) -> Result<()>;
 
@@ -39,6 +58,9 @@ pub trait AbstractServers: Sync + Send {
         remove: Vec<FieldsRole>,
     ) -> Result<()>;
 
+    /// Update the ranks of several roles on a server at once
+    async fn update_role_ranks(&self, server_id: &str, ranks: &HashMap<String, i64>) -> Result<()>;
+
     /// Delete a role from a server
     ///
     /// Also updates channels and members.
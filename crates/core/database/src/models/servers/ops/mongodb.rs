@@ -1,8 +1,11 @@
+use std::collections::HashMap;
+
 use bson::{to_document, Bson, Document};
 use futures::StreamExt;
+use revolt_models::v0;
 use revolt_result::Result;
 
-use crate::{FieldsRole, FieldsServer, PartialRole, PartialServer, Role, Server};
+use crate::{DiscoveryQuery, FieldsRole, FieldsServer, PartialRole, PartialServer, Role, Server};
 use crate::{IntoDocumentPath, MongoDb};
 
 use super::AbstractServers;
@@ -46,6 +49,161 @@ impl AbstractServers for MongoDb {
             .await)
     }
 
+    /// Fetch a page of discoverable servers matching a query along with their member counts
+    async fn fetch_discoverable_servers(
+        &self,
+        query: &DiscoveryQuery,
+    ) -> Result<(Vec<(Server, usize)>, usize)> {
+        let mut filter = doc! {
+            "discoverable": true,
+            "scheduled_deletion": {
+                "$exists": false
+            }
+        };
+
+        if !query.include_nsfw {
+            filter.insert("nsfw", doc! { "$ne": true });
+        }
+
+        if query.featured {
+            filter.insert("featured", true);
+        }
+
+        if let Some(category) = &query.category {
+            filter.insert("category", category.as_str());
+        }
+
+        if let Some(language) = &query.language {
+            filter.insert("language", language.as_str());
+        }
+
+        if !query.tags.is_empty() {
+            filter.insert("tags", doc! { "$all": query.tags.clone() });
+        }
+
+        if let Some(text) = &query.query {
+            filter.insert(
+                "$text",
+                doc! {
+                    "$search": text
+                },
+            );
+        }
+
+        let mut pipeline = vec![];
+
+        let sort = match query.sort {
+            v0::DiscoverySort::Popular => doc! {
+                "member_count": -1_i32,
+                "_id": 1_i32
+            },
+            v0::DiscoverySort::Active => {
+                // Message ids are ULIDs so the greatest one is also the most recent
+                pipeline.push(doc! {
+                    "$lookup": {
+                        "from": "channels",
+                        "localField": "channels",
+                        "foreignField": "_id",
+                        "as": "last_activity"
+                    }
+                });
+
+                pipeline.push(doc! {
+                    "$set": {
+                        "last_activity": {
+                            "$max": "$last_activity.last_message_id"
+                        }
+                    }
+                });
+
+                doc! {
+                    "last_activity": -1_i32,
+                    "member_count": -1_i32,
+                    "_id": 1_i32
+                }
+            }
+        };
+
+        pipeline.push(doc! { "$sort": sort });
+        pipeline.push(doc! { "$skip": query.offset.unwrap_or_default() });
+        pipeline.push(doc! { "$limit": query.limit.unwrap_or(50) });
+
+        let result = self
+            .col::<Document>(COL)
+            .aggregate(
+                vec![
+                    doc! {
+                        "$match": filter
+                    },
+                    doc! {
+                        "$facet": {
+                            "total": [
+                                {
+                                    "$count": "count"
+                                }
+                            ],
+                            "servers": pipeline
+                        }
+                    },
+                ],
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("aggregate", COL))?
+            .next()
+            .await
+            .and_then(|result| result.ok())
+            .ok_or_else(|| create_database_error!("aggregate", COL))?;
+
+        let total = result
+            .get_array("total")
+            .ok()
+            .and_then(|total| total.first())
+            .and_then(Bson::as_document)
+            .and_then(|total| total.get_i32("count").ok())
+            .unwrap_or_default() as usize;
+
+        let servers = result
+            .get_array("servers")
+            .map_err(|_| create_database_error!("aggregate", COL))?
+            .iter()
+            .filter_map(Bson::as_document)
+            .filter_map(|document| bson::from_document::<Server>(document.clone()).ok())
+            .map(|server| {
+                let count = server.member_count as usize;
+                (server, count)
+            })
+            .collect();
+
+        Ok((servers, total))
+    }
+
+    /// Fetch servers which have analytics enabled
+    async fn fetch_servers_with_analytics(&self) -> Result<Vec<Server>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "analytics": true
+            }
+        )
+    }
+
+    /// Fetch servers which are scheduled for deletion
+    async fn fetch_servers_scheduled_for_deletion(&self) -> Result<Vec<Server>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "scheduled_deletion": {
+                    "$exists": true
+                }
+            }
+        )
+    }
+
     /// Update a server with new information
     async fn update_server(
         &self,
@@ -65,6 +223,25 @@ impl AbstractServers for MongoDb {
         .map(|_| ())
     }
 
+    /// Adjust the stored member count of a server
+    async fn update_server_member_count(&self, id: &str, delta: i32) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$inc": {
+                        "member_count": delta
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
     /// Delete a server by its id
     async fn delete_server(&self, id: &str) -> Result<()> {
         self.delete_associated_server_objects(id).await?;
@@ -111,6 +288,28 @@ impl AbstractServers for MongoDb {
         .map(|_| ())
     }
 
+    /// Update the ranks of several roles on a server at once
+    async fn update_role_ranks(&self, server_id: &str, ranks: &HashMap<String, i64>) -> Result<()> {
+        let mut set = Document::new();
+        for (role_id, rank) in ranks {
+            set.insert(format!("roles.{role_id}.rank"), rank);
+        }
+
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": server_id
+                },
+                doc! {
+                    "$set": set
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", "server"))
+    }
+
     /// Delete a role from a server
     ///
     /// Also updates channels and members.
@@ -145,6 +344,24 @@ impl AbstractServers for MongoDb {
             .await
             .map_err(|_| create_database_error!("update_one", "channels"))?;
 
+        self.col::<Document>("servers")
+            .update_one(
+                doc! {
+                    "_id": server_id,
+                    "categories": {
+                        "$exists": true
+                    }
+                },
+                doc! {
+                    "$unset": {
+                        "categories.$[].role_permissions.".to_owned() + role_id: 1_i32
+                    }
+                },
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("update_one", "servers"))?;
+
         self.col::<Document>("servers")
             .update_one(
                 doc! {
@@ -170,6 +387,11 @@ impl IntoDocumentPath for FieldsServer {
             FieldsServer::Categories => "categories",
             FieldsServer::Description => "description",
             FieldsServer::Icon => "icon",
+            FieldsServer::Rules => "rules",
+            FieldsServer::Verification => "verification",
+            FieldsServer::ScheduledDeletion => "scheduled_deletion",
+            FieldsServer::Category => "category",
+            FieldsServer::Language => "language",
             FieldsServer::SystemMessages => "system_messages",
         })
     }
@@ -179,6 +401,8 @@ impl IntoDocumentPath for FieldsRole {
     fn as_path(&self) -> Option<&'static str> {
         Some(match self {
             FieldsRole::Colour => "colour",
+            FieldsRole::Icon => "icon",
+            FieldsRole::Emoji => "emoji",
         })
     }
 }
@@ -258,6 +482,37 @@ impl MongoDb {
                 .map_err(|_| create_database_error!("delete_many", with))?;
         }
 
+        // Delete templates captured from this server.
+        self.col::<Document>("server_templates")
+            .delete_many(
+                doc! {
+                    "server_id": &server_id
+                },
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("delete_many", "server_templates"))?;
+
+        // Delete the server's audit log, automod rules, jobs, events and insights.
+        for with in &[
+            "audit_logs",
+            "automod_rules",
+            "moderation_records",
+            "role_assignment_jobs",
+            "server_events",
+            "server_insights",
+        ] {
+            self.col::<Document>(with)
+                .delete_many(
+                    doc! {
+                        "server": &server_id
+                    },
+                    None,
+                )
+                .await
+                .map_err(|_| create_database_error!("delete_many", with))?;
+        }
+
         // Update many attachments with parent id.
         self.delete_many_attachments(doc! {
             "used_for.id": &server_id
@@ -1,7 +1,10 @@
+use std::collections::HashMap;
+
+use revolt_models::v0;
 use revolt_result::Result;
 
-use crate::ReferenceDb;
-use crate::{FieldsRole, FieldsServer, PartialRole, PartialServer, Role, Server};
+use crate::{Channel, ReferenceDb};
+use crate::{DiscoveryQuery, FieldsRole, FieldsServer, PartialRole, PartialServer, Role, Server};
 
 use super::AbstractServers;
 
@@ -40,6 +43,93 @@ impl AbstractServers for ReferenceDb {
             .collect()
     }
 
+    /// Fetch a page of discoverable servers matching a query along with their member counts
+    async fn fetch_discoverable_servers(
+        &self,
+        query: &DiscoveryQuery,
+    ) -> Result<(Vec<(Server, usize)>, usize)> {
+        let text = query.query.as_ref().map(|text| text.to_lowercase());
+        let servers: Vec<Server> =
+            self.servers
+                .lock()
+                .await
+                .values()
+                .filter(|server| server.discoverable && server.scheduled_deletion.is_none())
+                .filter(|server| query.include_nsfw || !server.nsfw)
+                .filter(|server| !query.featured || server.featured)
+                .filter(|server| query.category.is_none() || server.category == query.category)
+                .filter(|server| query.language.is_none() || server.language == query.language)
+                .filter(|server| query.tags.iter().all(|tag| server.tags.contains(tag)))
+                .filter(|server| {
+                    text.as_ref().map_or(true, |text| {
+                        server.name.to_lowercase().contains(text)
+                            || server.description.as_ref().is_some_and(|description| {
+                                description.to_lowercase().contains(text)
+                            })
+                            || server.tags.contains(text)
+                    })
+                })
+                .cloned()
+                .collect();
+
+        let channels = self.channels.lock().await;
+        let mut results: Vec<(Server, usize, Option<String>)> = servers
+            .into_iter()
+            .map(|server| {
+                let count = server.member_count as usize;
+
+                let last_activity = server
+                    .channels
+                    .iter()
+                    .filter_map(|id| match channels.get(id) {
+                        Some(Channel::TextChannel {
+                            last_message_id, ..
+                        }) => last_message_id.clone(),
+                        _ => None,
+                    })
+                    .max();
+
+                (server, count, last_activity)
+            })
+            .collect();
+
+        match query.sort {
+            v0::DiscoverySort::Popular => results.sort_by(|a, b| b.1.cmp(&a.1)),
+            v0::DiscoverySort::Active => results.sort_by(|a, b| b.2.cmp(&a.2).then(b.1.cmp(&a.1))),
+        }
+
+        let total = results.len();
+        Ok((
+            results
+                .into_iter()
+                .skip(query.offset.unwrap_or_default() as usize)
+                .take(query.limit.unwrap_or(50) as usize)
+                .map(|(server, count, _)| (server, count))
+                .collect(),
+            total,
+        ))
+    }
+
+    /// Fetch servers which have analytics enabled
+    async fn fetch_servers_with_analytics(&self) -> Result<Vec<Server>> {
+        let servers = self.servers.lock().await;
+        Ok(servers
+            .values()
+            .filter(|server| server.analytics)
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch servers which are scheduled for deletion
+    async fn fetch_servers_scheduled_for_deletion(&self) -> Result<Vec<Server>> {
+        let servers = self.servers.lock().await;
+        Ok(servers
+            .values()
+            .filter(|server| server.scheduled_deletion.is_some())
+            .cloned()
+            .collect())
+    }
+
     /// Update a server with new information
     async fn update_server(
         &self,
@@ -61,6 +151,17 @@ impl AbstractServers for ReferenceDb {
         }
     }
 
+    /// Adjust the stored member count of a server
+    async fn update_server_member_count(&self, id: &str, delta: i32) -> Result<()> {
+        let mut servers = self.servers.lock().await;
+        if let Some(server) = servers.get_mut(id) {
+            server.member_count = server.member_count.saturating_add_signed(delta);
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
     /// Delete a server by its id
     async fn delete_server(&self, id: &str) -> Result<()> {
         let mut servers = self.servers.lock().await;
@@ -108,6 +209,29 @@ impl AbstractServers for ReferenceDb {
         }
     }
 
+    /// Update the ranks of several roles on a server at once
+    async fn update_role_ranks(&self, server_id: &str, ranks: &HashMap<String, i64>) -> Result<()> {
+        let mut servers = self.servers.lock().await;
+        if let Some(server) = servers.get_mut(server_id) {
+            if ranks
+                .keys()
+                .all(|role_id| server.roles.contains_key(role_id))
+            {
+                for (role_id, rank) in ranks {
+                    if let Some(role) = server.roles.get_mut(role_id) {
+                        role.rank = *rank;
+                    }
+                }
+
+                Ok(())
+            } else {
+                Err(create_error!(NotFound))
+            }
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
     /// Delete a role from a server
     ///
     /// Also updates channels and members.
@@ -115,6 +239,12 @@ impl AbstractServers for ReferenceDb {
         let mut servers = self.servers.lock().await;
         if let Some(server) = servers.get_mut(server_id) {
             if server.roles.remove(role_id).is_some() {
+                if let Some(categories) = &mut server.categories {
+                    for category in categories {
+                        category.role_permissions.remove(role_id);
+                    }
+                }
+
                 Ok(())
             } else {
                 Err(create_error!(NotFound))
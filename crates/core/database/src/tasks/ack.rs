@@ -20,8 +20,13 @@ use super::DelayedTask;
 pub enum AckEvent {
     /// Add mentions for a channel
     ProcessMessage {
-        /// push notification, message, recipients, push silenced
-        messages: Vec<(Option<PushNotification>, Message, Vec<String>, bool)>,
+        /// push notification and its recipients, message, mention recipients, push silenced
+        messages: Vec<(
+            Option<(PushNotification, Vec<String>)>,
+            Message,
+            Vec<String>,
+            bool,
+        )>,
     },
 
     /// Acknowledge message in a channel for a user
@@ -140,26 +145,27 @@ pub async fn handle_ack_event(
                 debug!("Added {} mentions for user {}", message_ids.len(), &user);
             }
 
-            for (push, _, recipients, silenced) in messages {
-                if *silenced || recipients.is_empty() || push.is_none() {
+            for (push, _, _, silenced) in messages {
+                let Some((push, recipients)) = push else {
+                    debug!("Rejecting push: no push exists");
+                    continue;
+                };
+
+                if *silenced || recipients.is_empty() {
                     debug!(
-                        "Rejecting push: silenced: {}, recipient count: {}, push exists: {:?}",
+                        "Rejecting push: silenced: {}, recipient count: {}",
                         *silenced,
-                        recipients.length(),
-                        push
+                        recipients.length()
                     );
                     continue;
                 }
 
                 debug!(
                     "Sending push event to AMQP; message {} for {} users",
-                    push.as_ref().unwrap().message.id,
+                    push.message.id,
                     recipients.len()
                 );
-                if let Err(err) = amqp
-                    .message_sent(recipients.clone(), push.clone().unwrap())
-                    .await
-                {
+                if let Err(err) = amqp.message_sent(recipients.clone(), push.clone()).await {
                     revolt_config::capture_error(&err);
                 }
             }
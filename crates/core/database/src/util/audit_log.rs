@@ -0,0 +1,83 @@
+#[cfg(feature = "rocket-impl")]
+use revolt_result::{create_error, Error};
+
+/// Reason supplied for an action through the `X-Audit-Log-Reason` header
+#[derive(Default)]
+pub struct AuditLogReason(Option<String>);
+
+impl AuditLogReason {
+    pub fn into_inner(self) -> Option<String> {
+        self.0
+    }
+}
+
+#[cfg(feature = "rocket-impl")]
+use revolt_rocket_okapi::{
+    gen::OpenApiGenerator,
+    request::{OpenApiFromRequest, RequestHeaderInput},
+    revolt_okapi::openapi3::{Parameter, ParameterValue},
+};
+
+#[cfg(feature = "rocket-impl")]
+use schemars::schema::{InstanceType, SchemaObject, SingleOrVec};
+
+#[cfg(feature = "rocket-impl")]
+impl<'r> OpenApiFromRequest<'r> for AuditLogReason {
+    fn from_request_input(
+        _gen: &mut OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> revolt_rocket_okapi::Result<RequestHeaderInput> {
+        Ok(RequestHeaderInput::Parameter(Parameter {
+            name: "X-Audit-Log-Reason".to_string(),
+            description: Some("Reason to record in the server audit log".to_string()),
+            allow_empty_value: false,
+            required: false,
+            deprecated: false,
+            extensions: schemars::Map::new(),
+            location: "header".to_string(),
+            value: ParameterValue::Schema {
+                allow_reserved: false,
+                example: None,
+                examples: None,
+                explode: None,
+                style: None,
+                schema: SchemaObject {
+                    instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::String))),
+                    ..Default::default()
+                },
+            },
+        }))
+    }
+}
+
+#[cfg(feature = "rocket-impl")]
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome},
+};
+
+#[cfg(feature = "rocket-impl")]
+#[async_trait]
+impl<'r> FromRequest<'r> for AuditLogReason {
+    type Error = Error;
+
+    async fn from_request(request: &'r rocket::Request<'_>) -> Outcome<Self, Self::Error> {
+        let reason = request
+            .headers()
+            .get_one("X-Audit-Log-Reason")
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+
+        if reason.as_ref().is_some_and(|reason| reason.len() > 512) {
+            return Outcome::Error((
+                Status::BadRequest,
+                create_error!(FailedValidation {
+                    error: "audit log reason too long".to_string(),
+                }),
+            ));
+        }
+
+        Outcome::Success(AuditLogReason(reason))
+    }
+}
@@ -0,0 +1,583 @@
+use std::{
+    collections::HashSet,
+    num::NonZeroUsize,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
+
+use async_std::sync::Mutex;
+use iso8601_timestamp::Timestamp;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use revolt_models::v0::{MessageSort, RE_MENTION};
+use revolt_result::{create_error, Result};
+use ulid::Ulid;
+
+use crate::{
+    AutomodAction, AutomodRule, AutomodTrigger, Database, FieldsMember, Message, MessageFilter,
+    MessageQuery, MessageTimePeriod, ModerationAction, ModerationRecord, PartialMember,
+    SystemMessage,
+};
+
+static RE_LINK: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\bhttps?://([^\s/?#<>]+)([^\s<>]*)").unwrap());
+
+/// Hosts which are always considered to be invite links
+const INVITE_HOSTS: [&str; 1] = ["rvlt.gg"];
+
+/// Maximum number of recent messages considered for flood and repeat detection
+const HISTORY_LIMIT: i64 = 100;
+
+/// How long compiled rules are reused for before being fetched again
+///
+/// Edits made through another node only take effect here once this passes.
+const RULE_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Enabled rules of recently active servers and when they were fetched, keyed by server id
+static RULE_CACHE: Lazy<Mutex<lru::LruCache<String, (Instant, Arc<Vec<CompiledRule>>)>>> =
+    Lazy::new(|| Mutex::new(lru::LruCache::new(NonZeroUsize::new(1000).unwrap())));
+
+/// Enabled rule with its patterns compiled ahead of time
+struct CompiledRule {
+    rule: AutomodRule,
+    patterns: Vec<Regex>,
+}
+
+/// Message being checked against a server's automod rules
+pub struct AutomodContext<'a> {
+    /// Server the message is being sent in
+    pub server: &'a str,
+    /// Channel the message is being sent in
+    pub channel: &'a str,
+    /// Author of the message
+    pub author: &'a str,
+    /// Roles the author has in the server
+    pub roles: &'a [String],
+    /// Message content
+    pub content: &'a str,
+}
+
+/// Outcome of enforcing automod rules on a message
+#[derive(Debug, PartialEq, Eq)]
+pub enum AutomodVerdict {
+    /// Message may be sent
+    Allow,
+    /// Message should be silently discarded
+    Discard,
+}
+
+/// Find all enabled rules which the given message triggers
+pub async fn evaluate(db: &Database, context: &AutomodContext<'_>) -> Result<Vec<AutomodRule>> {
+    let cached = enabled_rules(db, context.server).await?;
+    let rules: Vec<&CompiledRule> = cached
+        .iter()
+        .filter(|compiled| {
+            !compiled
+                .rule
+                .exempt_channels
+                .iter()
+                .any(|id| id == context.channel)
+                && !compiled
+                    .rule
+                    .exempt_roles
+                    .iter()
+                    .any(|id| context.roles.contains(id))
+        })
+        .collect();
+
+    if rules.is_empty() {
+        return Ok(vec![]);
+    }
+
+    // Only fetch history if a rule depends on it
+    let window = rules
+        .iter()
+        .filter_map(|compiled| match compiled.rule.trigger {
+            AutomodTrigger::Flood { window, .. } | AutomodTrigger::Repeated { window, .. } => {
+                Some(window)
+            }
+            _ => None,
+        })
+        .max();
+
+    let history = if let Some(window) = window {
+        recent_messages(db, context, window).await?
+    } else {
+        vec![]
+    };
+
+    Ok(rules
+        .into_iter()
+        .filter(|compiled| is_triggered(compiled, context.content, &history))
+        .map(|compiled| compiled.rule.clone())
+        .collect())
+}
+
+/// Forget this node's cached rules of a server so they are fetched again on the next message
+pub async fn invalidate_rules(server: &str) {
+    RULE_CACHE.lock().await.pop(server);
+}
+
+/// Fetch and compile the enabled rules of a server, reusing them if already cached
+async fn enabled_rules(db: &Database, server: &str) -> Result<Arc<Vec<CompiledRule>>> {
+    if let Some((fetched_at, rules)) = RULE_CACHE.lock().await.get(server) {
+        if fetched_at.elapsed() < RULE_CACHE_TTL {
+            return Ok(rules.clone());
+        }
+    }
+
+    let rules: Arc<Vec<CompiledRule>> = Arc::new(
+        db.fetch_automod_rules(server)
+            .await?
+            .into_iter()
+            .filter(|rule| rule.enabled)
+            .map(|rule| {
+                let patterns = match &rule.trigger {
+                    AutomodTrigger::Keyword { patterns, .. } => patterns
+                        .iter()
+                        .filter_map(|pattern| AutomodTrigger::compile_pattern(pattern).ok())
+                        .collect(),
+                    _ => vec![],
+                };
+
+                CompiledRule { rule, patterns }
+            })
+            .collect(),
+    );
+
+    RULE_CACHE
+        .lock()
+        .await
+        .put(server.to_string(), (Instant::now(), rules.clone()));
+
+    Ok(rules)
+}
+
+/// Apply the actions of triggered rules
+///
+/// Returns an error if any rule blocks the message.
+pub async fn enforce(
+    db: &Database,
+    context: &AutomodContext<'_>,
+    rules: &[AutomodRule],
+) -> Result<AutomodVerdict> {
+    let mut blocked_by = None;
+    let mut verdict = AutomodVerdict::Allow;
+
+    for rule in rules {
+        for action in &rule.actions {
+            match action {
+                AutomodAction::Block => {
+                    blocked_by.get_or_insert(rule.name.clone());
+                }
+                AutomodAction::Delete => verdict = AutomodVerdict::Discard,
+                AutomodAction::Timeout { duration } => {
+                    if let Ok(mut member) = db.fetch_member(context.server, context.author).await {
+                        // Don't extend an ongoing timeout or record it again
+                        if member.in_timeout() {
+                            continue;
+                        }
+
+                        let timeout = Timestamp::now_utc()
+                            .checked_add(iso8601_timestamp::Duration::seconds(*duration as i64));
+
+                        let reason = format!("Automod rule: {}", rule.name);
+
+                        // Don't attribute this timeout to whoever moderated a lapsed one
+                        member
+                            .update(
+                                db,
+                                PartialMember {
+                                    timeout,
+                                    timeout_reason: Some(reason.clone()),
+                                    ..Default::default()
+                                },
+                                if member.timeout_moderator.is_some() {
+                                    vec![FieldsMember::TimeoutModerator]
+                                } else {
+                                    vec![]
+                                },
+                            )
+                            .await?;
+
+                        ModerationRecord::create(
+                            db,
+                            context.server,
+                            context.author,
+                            None,
+                            ModerationAction::Timeout,
+                            Some(reason),
+                            timeout,
+                        )
+                        .await?;
+                    }
+                }
+                AutomodAction::Alert { channel } => {
+                    SystemMessage::Text {
+                        content: format!(
+                            "Automod rule **{}** triggered by <@{}> in <#{}>.",
+                            rule.name, context.author, context.channel
+                        ),
+                    }
+                    .into_message(channel.to_string())
+                    .send_without_notifications(db, None, None, false, false, false)
+                    .await
+                    .ok();
+                }
+            }
+        }
+    }
+
+    if let Some(rule) = blocked_by {
+        return Err(create_error!(BlockedByAutomod { rule }));
+    }
+
+    Ok(verdict)
+}
+
+/// Fetch the author's messages in this channel sent within the last `window` seconds
+async fn recent_messages(
+    db: &Database,
+    context: &AutomodContext<'_>,
+    window: u64,
+) -> Result<Vec<Message>> {
+    let cutoff = SystemTime::now()
+        .checked_sub(Duration::from_secs(window))
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+    let messages = db
+        .fetch_messages(MessageQuery {
+            limit: Some(HISTORY_LIMIT),
+            filter: MessageFilter {
+                channel: Some(context.channel.to_string()),
+                author: Some(context.author.to_string()),
+                ..Default::default()
+            },
+            time_period: MessageTimePeriod::Absolute {
+                before: None,
+                after: Some(Ulid::from_datetime(cutoff).to_string()),
+                sort: Some(MessageSort::Latest),
+            },
+        })
+        .await?;
+
+    Ok(messages
+        .into_iter()
+        .filter(|message| sent_within(message, window))
+        .collect())
+}
+
+/// Check whether a message was sent within the last `window` seconds
+fn sent_within(message: &Message, window: u64) -> bool {
+    Ulid::from_string(&message.id)
+        .ok()
+        .and_then(|id| id.datetime().elapsed().ok())
+        .map_or(true, |elapsed| elapsed.as_secs() < window)
+}
+
+/// Check whether a rule's trigger matches the given content
+fn is_triggered(compiled: &CompiledRule, content: &str, history: &[Message]) -> bool {
+    match &compiled.rule.trigger {
+        AutomodTrigger::Keyword { keywords, .. } => {
+            let lowercase = content.to_lowercase();
+            keywords
+                .iter()
+                .any(|keyword| !keyword.is_empty() && lowercase.contains(&keyword.to_lowercase()))
+                || compiled
+                    .patterns
+                    .iter()
+                    .any(|regex| regex.is_match(content))
+        }
+        AutomodTrigger::MentionSpam { max_mentions } => {
+            RE_MENTION
+                .captures_iter(content)
+                .filter_map(|capture| capture.get(1).map(|id| id.as_str()))
+                .collect::<HashSet<&str>>()
+                .len()
+                > *max_mentions
+        }
+        AutomodTrigger::Links {
+            block_invites,
+            block_links,
+            allowed_domains,
+        } => RE_LINK.captures_iter(content).any(|capture| {
+            let host = capture[1].to_lowercase();
+            let host = host.split(':').next().unwrap_or_default();
+            if allowed_domains
+                .iter()
+                .any(|domain| is_same_or_subdomain(host, &domain.to_lowercase()))
+            {
+                return false;
+            }
+
+            let is_invite =
+                INVITE_HOSTS.contains(&host) || capture[2].to_lowercase().starts_with("/invite/");
+
+            if is_invite {
+                *block_invites
+            } else {
+                *block_links
+            }
+        }),
+        AutomodTrigger::Flood {
+            max_messages,
+            window,
+        } => {
+            history
+                .iter()
+                .filter(|message| sent_within(message, *window))
+                .count()
+                + 1
+                > *max_messages
+        }
+        AutomodTrigger::Repeated {
+            max_duplicates,
+            window,
+        } => {
+            let normalised = normalise(content);
+            !normalised.is_empty()
+                && history
+                    .iter()
+                    .filter(|message| sent_within(message, *window))
+                    .filter(|message| {
+                        message.content.as_deref().map(normalise).as_deref()
+                            == Some(normalised.as_str())
+                    })
+                    .count()
+                    >= *max_duplicates
+        }
+        AutomodTrigger::Caps {
+            min_length,
+            max_percentage,
+        } => {
+            // Mentions are made up of capitals and should not count towards the total
+            let (letters, capitals) = RE_MENTION
+                .replace_all(content, "")
+                .chars()
+                .filter(|c| c.is_alphabetic())
+                .fold((0, 0), |(letters, capitals), c| {
+                    (letters + 1, capitals + c.is_uppercase() as usize)
+                });
+
+            letters > 0
+                && letters >= *min_length
+                && capitals * 100 > letters * *max_percentage as usize
+        }
+        AutomodTrigger::Zalgo { max_combining } => {
+            let mut run = 0;
+            content.chars().any(|c| {
+                if is_combining(c) {
+                    run += 1;
+                } else {
+                    run = 0;
+                }
+
+                run > *max_combining
+            })
+        }
+    }
+}
+
+/// Normalise message content for duplicate comparison
+fn normalise(content: &str) -> String {
+    content.trim().to_lowercase()
+}
+
+/// Check whether a host is a domain or one of its subdomains
+fn is_same_or_subdomain(host: &str, domain: &str) -> bool {
+    host == domain
+        || host
+            .strip_suffix(domain)
+            .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+/// Check whether a character is a combining diacritical mark
+fn is_combining(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use revolt_result::ErrorType;
+    use ulid::Ulid;
+
+    use super::{enforce, evaluate, AutomodContext, AutomodVerdict};
+    use crate::{fixture, Message, PartialAutomodRule};
+
+    fn context<'a>(server: &'a str, content: &'a str, roles: &'a [String]) -> AutomodContext<'a> {
+        AutomodContext {
+            server,
+            channel: "channel",
+            author: "author",
+            roles,
+            content,
+        }
+    }
+
+    #[async_std::test]
+    async fn triggers() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_automod_rules", server server 3);
+
+            let cases = [
+                ("hello world", 0),
+                ("this is FORBIDDEN", 1),
+                ("get your fr33 nitro", 1),
+                (
+                    "<@01EX2NCWQ0CHS3QJF0FEQS1GR4> <@01EX2NCWQ0CHS3QJF0FEQS1GR4>",
+                    0,
+                ),
+                (
+                    "<@01EX2NCWQ0CHS3QJF0FEQS1GR4> <@01FD58YK5W7QRV5H3D64KTQYX3>",
+                    1,
+                ),
+                ("join https://rvlt.gg/Testers", 1),
+                ("see https://app.revolt.chat/invite/Testers", 0),
+                ("see https://example.com/page", 0),
+                ("STOP SHOUTING", 1),
+                ("OK", 0),
+                ("z\u{0301}\u{0302}\u{0303}algo", 1),
+                ("café", 0),
+            ];
+
+            for (content, expected) in cases {
+                assert_eq!(
+                    evaluate(&db, &context(&server.id, content, &[]))
+                        .await
+                        .unwrap()
+                        .len(),
+                    expected,
+                    "{content}"
+                );
+            }
+        });
+    }
+
+    #[async_std::test]
+    async fn exemptions() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_automod_rules",
+                server server 2
+                keyword automod_rule 6);
+
+            let triggered = evaluate(&db, &context(&server.id, "spam", &[]))
+                .await
+                .unwrap();
+            assert_eq!(triggered, vec![keyword.clone()]);
+
+            let roles = ["moderator".to_string()];
+            assert!(evaluate(&db, &context(&server.id, "spam", &roles))
+                .await
+                .unwrap()
+                .is_empty());
+
+            // Editing a rule takes effect on the next message
+            let mut keyword = keyword;
+            keyword
+                .update(
+                    &db,
+                    PartialAutomodRule {
+                        enabled: Some(false),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert!(evaluate(&db, &context(&server.id, "spam", &[]))
+                .await
+                .unwrap()
+                .is_empty());
+        });
+    }
+
+    #[async_std::test]
+    async fn history() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_automod_rules", server server 4);
+
+            for content in ["first", "Hello"] {
+                db.insert_message(&Message {
+                    id: Ulid::new().to_string(),
+                    channel: "channel".to_string(),
+                    author: "author".to_string(),
+                    content: Some(content.to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            }
+
+            assert!(evaluate(&db, &context(&server.id, "hello", &[]))
+                .await
+                .unwrap()
+                .is_empty());
+
+            db.insert_message(&Message {
+                id: Ulid::new().to_string(),
+                channel: "channel".to_string(),
+                author: "author".to_string(),
+                content: Some("hello ".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+            // Third message within window and second duplicate
+            assert_eq!(
+                evaluate(&db, &context(&server.id, "hello", &[]))
+                    .await
+                    .unwrap()
+                    .len(),
+                2
+            );
+
+            // Messages by other authors are not counted
+            let other = AutomodContext {
+                author: "other",
+                ..context(&server.id, "hello", &[])
+            };
+            assert!(evaluate(&db, &other).await.unwrap().is_empty());
+        });
+    }
+
+    #[async_std::test]
+    async fn actions() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_automod_rules",
+                server server 5
+                discard automod_rule 16
+                block automod_rule 17);
+
+            assert_eq!(
+                enforce(&db, &context(&server.id, "spam", &[]), &[])
+                    .await
+                    .unwrap(),
+                AutomodVerdict::Allow
+            );
+
+            assert_eq!(
+                enforce(&db, &context(&server.id, "spam", &[]), &[discard.clone()])
+                    .await
+                    .unwrap(),
+                AutomodVerdict::Discard
+            );
+
+            // Blocking takes priority over silently discarding
+            let error = enforce(&db, &context(&server.id, "spam", &[]), &[discard, block])
+                .await
+                .unwrap_err();
+            assert!(matches!(
+                error.error_type,
+                ErrorType::BlockedByAutomod { rule } if rule == "Block Spam"
+            ));
+        });
+    }
+}
@@ -1,3 +1,4 @@
+use iso8601_timestamp::Timestamp;
 use revolt_models::v0::*;
 use revolt_permissions::{calculate_user_permissions, UserPermission};
 
@@ -19,6 +20,278 @@ impl crate::Bot {
                 .unwrap_or_default(),
         }
     }
+
+    pub fn into_directory_bot(self, user: crate::User, server_count: usize) -> DirectoryBot {
+        DirectoryBot {
+            tags: self.tags.clone(),
+            short_description: self.short_description.clone(),
+            long_description: self.long_description.clone(),
+            terms_of_service_url: self.terms_of_service_url.clone(),
+            privacy_policy_url: self.privacy_policy_url.clone(),
+            flags: self.flags.unwrap_or_default() as u32,
+            server_count: server_count as i64,
+            bot: self.into_public_bot(user),
+        }
+    }
+}
+
+impl From<crate::AuditLogEntry> for AuditLogEntry {
+    fn from(value: crate::AuditLogEntry) -> Self {
+        AuditLogEntry {
+            id: value.id,
+            server: value.server,
+            actor: value.actor,
+            action: value.action.into(),
+            target: value.target,
+            changes: value.changes.into_iter().map(Into::into).collect(),
+            reason: value.reason,
+        }
+    }
+}
+
+impl From<crate::AuditLogChange> for AuditLogChange {
+    fn from(value: crate::AuditLogChange) -> Self {
+        AuditLogChange {
+            key: value.key,
+            before: value.before,
+            after: value.after,
+        }
+    }
+}
+
+impl From<crate::AuditLogAction> for AuditLogAction {
+    fn from(value: crate::AuditLogAction) -> Self {
+        match value {
+            crate::AuditLogAction::ServerUpdate => AuditLogAction::ServerUpdate,
+            crate::AuditLogAction::ChannelCreate => AuditLogAction::ChannelCreate,
+            crate::AuditLogAction::ChannelUpdate => AuditLogAction::ChannelUpdate,
+            crate::AuditLogAction::ChannelDelete => AuditLogAction::ChannelDelete,
+            crate::AuditLogAction::CategoryCreate => AuditLogAction::CategoryCreate,
+            crate::AuditLogAction::CategoryUpdate => AuditLogAction::CategoryUpdate,
+            crate::AuditLogAction::CategoryDelete => AuditLogAction::CategoryDelete,
+            crate::AuditLogAction::RoleCreate => AuditLogAction::RoleCreate,
+            crate::AuditLogAction::RoleUpdate => AuditLogAction::RoleUpdate,
+            crate::AuditLogAction::RoleDelete => AuditLogAction::RoleDelete,
+            crate::AuditLogAction::RoleAssignmentCreate => AuditLogAction::RoleAssignmentCreate,
+            crate::AuditLogAction::MemberUpdate => AuditLogAction::MemberUpdate,
+            crate::AuditLogAction::MemberKick => AuditLogAction::MemberKick,
+            crate::AuditLogAction::MemberBan => AuditLogAction::MemberBan,
+            crate::AuditLogAction::MemberUnban => AuditLogAction::MemberUnban,
+            crate::AuditLogAction::MemberPrune => AuditLogAction::MemberPrune,
+            crate::AuditLogAction::InviteDelete => AuditLogAction::InviteDelete,
+            crate::AuditLogAction::WebhookCreate => AuditLogAction::WebhookCreate,
+            crate::AuditLogAction::WebhookUpdate => AuditLogAction::WebhookUpdate,
+            crate::AuditLogAction::WebhookDelete => AuditLogAction::WebhookDelete,
+            crate::AuditLogAction::EmojiCreate => AuditLogAction::EmojiCreate,
+            crate::AuditLogAction::EmojiDelete => AuditLogAction::EmojiDelete,
+            crate::AuditLogAction::AutomodRuleCreate => AuditLogAction::AutomodRuleCreate,
+            crate::AuditLogAction::AutomodRuleUpdate => AuditLogAction::AutomodRuleUpdate,
+            crate::AuditLogAction::AutomodRuleDelete => AuditLogAction::AutomodRuleDelete,
+            crate::AuditLogAction::VanityInviteUpdate => AuditLogAction::VanityInviteUpdate,
+            crate::AuditLogAction::VanityInviteDelete => AuditLogAction::VanityInviteDelete,
+            crate::AuditLogAction::ServerEventCreate => AuditLogAction::ServerEventCreate,
+            crate::AuditLogAction::ServerEventUpdate => AuditLogAction::ServerEventUpdate,
+            crate::AuditLogAction::ServerEventDelete => AuditLogAction::ServerEventDelete,
+        }
+    }
+}
+
+impl From<AuditLogAction> for crate::AuditLogAction {
+    fn from(value: AuditLogAction) -> Self {
+        match value {
+            AuditLogAction::ServerUpdate => crate::AuditLogAction::ServerUpdate,
+            AuditLogAction::ChannelCreate => crate::AuditLogAction::ChannelCreate,
+            AuditLogAction::ChannelUpdate => crate::AuditLogAction::ChannelUpdate,
+            AuditLogAction::ChannelDelete => crate::AuditLogAction::ChannelDelete,
+            AuditLogAction::CategoryCreate => crate::AuditLogAction::CategoryCreate,
+            AuditLogAction::CategoryUpdate => crate::AuditLogAction::CategoryUpdate,
+            AuditLogAction::CategoryDelete => crate::AuditLogAction::CategoryDelete,
+            AuditLogAction::RoleCreate => crate::AuditLogAction::RoleCreate,
+            AuditLogAction::RoleUpdate => crate::AuditLogAction::RoleUpdate,
+            AuditLogAction::RoleDelete => crate::AuditLogAction::RoleDelete,
+            AuditLogAction::RoleAssignmentCreate => crate::AuditLogAction::RoleAssignmentCreate,
+            AuditLogAction::MemberUpdate => crate::AuditLogAction::MemberUpdate,
+            AuditLogAction::MemberKick => crate::AuditLogAction::MemberKick,
+            AuditLogAction::MemberBan => crate::AuditLogAction::MemberBan,
+            AuditLogAction::MemberUnban => crate::AuditLogAction::MemberUnban,
+            AuditLogAction::MemberPrune => crate::AuditLogAction::MemberPrune,
+            AuditLogAction::InviteDelete => crate::AuditLogAction::InviteDelete,
+            AuditLogAction::WebhookCreate => crate::AuditLogAction::WebhookCreate,
+            AuditLogAction::WebhookUpdate => crate::AuditLogAction::WebhookUpdate,
+            AuditLogAction::WebhookDelete => crate::AuditLogAction::WebhookDelete,
+            AuditLogAction::EmojiCreate => crate::AuditLogAction::EmojiCreate,
+            AuditLogAction::EmojiDelete => crate::AuditLogAction::EmojiDelete,
+            AuditLogAction::AutomodRuleCreate => crate::AuditLogAction::AutomodRuleCreate,
+            AuditLogAction::AutomodRuleUpdate => crate::AuditLogAction::AutomodRuleUpdate,
+            AuditLogAction::AutomodRuleDelete => crate::AuditLogAction::AutomodRuleDelete,
+            AuditLogAction::VanityInviteUpdate => crate::AuditLogAction::VanityInviteUpdate,
+            AuditLogAction::VanityInviteDelete => crate::AuditLogAction::VanityInviteDelete,
+            AuditLogAction::ServerEventCreate => crate::AuditLogAction::ServerEventCreate,
+            AuditLogAction::ServerEventUpdate => crate::AuditLogAction::ServerEventUpdate,
+            AuditLogAction::ServerEventDelete => crate::AuditLogAction::ServerEventDelete,
+        }
+    }
+}
+
+impl From<crate::AutomodRule> for AutomodRule {
+    fn from(value: crate::AutomodRule) -> Self {
+        AutomodRule {
+            id: value.id,
+            server: value.server,
+            name: value.name,
+            enabled: value.enabled,
+            trigger: value.trigger.into(),
+            actions: value
+                .actions
+                .into_iter()
+                .map(|action| action.into())
+                .collect(),
+            exempt_roles: value.exempt_roles,
+            exempt_channels: value.exempt_channels,
+        }
+    }
+}
+
+impl From<crate::AutomodTrigger> for AutomodTrigger {
+    fn from(value: crate::AutomodTrigger) -> Self {
+        match value {
+            crate::AutomodTrigger::Keyword { keywords, patterns } => {
+                AutomodTrigger::Keyword { keywords, patterns }
+            }
+            crate::AutomodTrigger::MentionSpam { max_mentions } => {
+                AutomodTrigger::MentionSpam { max_mentions }
+            }
+            crate::AutomodTrigger::Links {
+                block_invites,
+                block_links,
+                allowed_domains,
+            } => AutomodTrigger::Links {
+                block_invites,
+                block_links,
+                allowed_domains,
+            },
+            crate::AutomodTrigger::Flood {
+                max_messages,
+                window,
+            } => AutomodTrigger::Flood {
+                max_messages,
+                window,
+            },
+            crate::AutomodTrigger::Repeated {
+                max_duplicates,
+                window,
+            } => AutomodTrigger::Repeated {
+                max_duplicates,
+                window,
+            },
+            crate::AutomodTrigger::Caps {
+                min_length,
+                max_percentage,
+            } => AutomodTrigger::Caps {
+                min_length,
+                max_percentage,
+            },
+            crate::AutomodTrigger::Zalgo { max_combining } => {
+                AutomodTrigger::Zalgo { max_combining }
+            }
+        }
+    }
+}
+
+impl From<AutomodTrigger> for crate::AutomodTrigger {
+    fn from(value: AutomodTrigger) -> Self {
+        match value {
+            AutomodTrigger::Keyword { keywords, patterns } => {
+                crate::AutomodTrigger::Keyword { keywords, patterns }
+            }
+            AutomodTrigger::MentionSpam { max_mentions } => {
+                crate::AutomodTrigger::MentionSpam { max_mentions }
+            }
+            AutomodTrigger::Links {
+                block_invites,
+                block_links,
+                allowed_domains,
+            } => crate::AutomodTrigger::Links {
+                block_invites,
+                block_links,
+                allowed_domains,
+            },
+            AutomodTrigger::Flood {
+                max_messages,
+                window,
+            } => crate::AutomodTrigger::Flood {
+                max_messages,
+                window,
+            },
+            AutomodTrigger::Repeated {
+                max_duplicates,
+                window,
+            } => crate::AutomodTrigger::Repeated {
+                max_duplicates,
+                window,
+            },
+            AutomodTrigger::Caps {
+                min_length,
+                max_percentage,
+            } => crate::AutomodTrigger::Caps {
+                min_length,
+                max_percentage,
+            },
+            AutomodTrigger::Zalgo { max_combining } => {
+                crate::AutomodTrigger::Zalgo { max_combining }
+            }
+        }
+    }
+}
+
+impl From<crate::AutomodAction> for AutomodAction {
+    fn from(value: crate::AutomodAction) -> Self {
+        match value {
+            crate::AutomodAction::Block => AutomodAction::Block,
+            crate::AutomodAction::Delete => AutomodAction::Delete,
+            crate::AutomodAction::Timeout { duration } => AutomodAction::Timeout { duration },
+            crate::AutomodAction::Alert { channel } => AutomodAction::Alert { channel },
+        }
+    }
+}
+
+impl From<AutomodAction> for crate::AutomodAction {
+    fn from(value: AutomodAction) -> Self {
+        match value {
+            AutomodAction::Block => crate::AutomodAction::Block,
+            AutomodAction::Delete => crate::AutomodAction::Delete,
+            AutomodAction::Timeout { duration } => crate::AutomodAction::Timeout { duration },
+            AutomodAction::Alert { channel } => crate::AutomodAction::Alert { channel },
+        }
+    }
+}
+
+impl From<crate::ServerInsights> for ServerInsights {
+    fn from(value: crate::ServerInsights) -> Self {
+        ServerInsights {
+            date: iso8601_timestamp::Timestamp::from_unix_timestamp_ms(value.day * 86_400_000),
+            joins: value.joins,
+            leaves: value.leaves,
+            kicks: value.kicks,
+            messages: value.messages,
+            active_members: value.active_members,
+            peak_online: value.peak_online,
+            invites: value
+                .invites
+                .into_iter()
+                .map(|(code, conversion)| (code, conversion.into()))
+                .collect(),
+        }
+    }
+}
+
+impl From<crate::InviteConversion> for InviteConversion {
+    fn from(value: crate::InviteConversion) -> Self {
+        InviteConversion {
+            views: value.views,
+            joins: value.joins,
+        }
+    }
 }
 
 impl From<crate::Bot> for Bot {
@@ -34,6 +307,21 @@ impl From<crate::Bot> for Bot {
             terms_of_service_url: value.terms_of_service_url,
             privacy_policy_url: value.privacy_policy_url,
             flags: value.flags.unwrap_or_default() as u32,
+            tags: value.tags,
+            short_description: value.short_description,
+            long_description: value.long_description,
+            delisted: value.delisted,
+            verification: value.verification.map(Into::into),
+        }
+    }
+}
+
+impl From<crate::BotVerificationStatus> for BotVerificationStatus {
+    fn from(value: crate::BotVerificationStatus) -> Self {
+        match value {
+            crate::BotVerificationStatus::Pending => BotVerificationStatus::Pending,
+            crate::BotVerificationStatus::Approved => BotVerificationStatus::Approved,
+            crate::BotVerificationStatus::Rejected => BotVerificationStatus::Rejected,
         }
     }
 }
@@ -63,21 +351,37 @@ impl From<crate::Invite> for Invite {
                 code,
                 creator,
                 channel,
+                max_uses,
+                expires_at,
+                uses,
             } => Invite::Group {
                 code,
                 creator,
                 channel,
+                max_uses,
+                expires_at,
+                uses,
             },
             crate::Invite::Server {
                 code,
                 server,
                 creator,
                 channel,
+                max_uses,
+                expires_at,
+                temporary,
+                uses,
+                vanity,
             } => Invite::Server {
                 code,
                 server,
                 creator,
                 channel,
+                max_uses,
+                expires_at,
+                temporary,
+                uses,
+                vanity,
             },
         }
     }
@@ -187,7 +491,9 @@ impl From<crate::Channel> for Channel {
                 last_message_id,
                 default_permissions,
                 role_permissions,
+                sync_permissions,
                 nsfw,
+                archived,
             } => Channel::TextChannel {
                 id,
                 server,
@@ -197,7 +503,9 @@ impl From<crate::Channel> for Channel {
                 last_message_id,
                 default_permissions,
                 role_permissions,
+                sync_permissions,
                 nsfw,
+                archived,
             },
             crate::Channel::VoiceChannel {
                 id,
@@ -207,6 +515,7 @@ impl From<crate::Channel> for Channel {
                 icon,
                 default_permissions,
                 role_permissions,
+                sync_permissions,
                 nsfw,
             } => Channel::VoiceChannel {
                 id,
@@ -216,6 +525,7 @@ impl From<crate::Channel> for Channel {
                 icon: icon.map(|file| file.into()),
                 default_permissions,
                 role_permissions,
+                sync_permissions,
                 nsfw,
             },
         }
@@ -267,7 +577,9 @@ impl From<Channel> for crate::Channel {
                 last_message_id,
                 default_permissions,
                 role_permissions,
+                sync_permissions,
                 nsfw,
+                archived,
             } => crate::Channel::TextChannel {
                 id,
                 server,
@@ -277,7 +589,9 @@ impl From<Channel> for crate::Channel {
                 last_message_id,
                 default_permissions,
                 role_permissions,
+                sync_permissions,
                 nsfw,
+                archived,
             },
             Channel::VoiceChannel {
                 id,
@@ -287,6 +601,7 @@ impl From<Channel> for crate::Channel {
                 icon,
                 default_permissions,
                 role_permissions,
+                sync_permissions,
                 nsfw,
             } => crate::Channel::VoiceChannel {
                 id,
@@ -296,6 +611,7 @@ impl From<Channel> for crate::Channel {
                 icon: icon.map(|file| file.into()),
                 default_permissions,
                 role_permissions,
+                sync_permissions,
                 nsfw,
             },
         }
@@ -314,6 +630,8 @@ impl From<crate::PartialChannel> for PartialChannel {
             permissions: value.permissions,
             role_permissions: value.role_permissions,
             default_permissions: value.default_permissions,
+            sync_permissions: value.sync_permissions,
+            archived: value.archived,
             last_message_id: value.last_message_id,
         }
     }
@@ -331,6 +649,8 @@ impl From<PartialChannel> for crate::PartialChannel {
             permissions: value.permissions,
             role_permissions: value.role_permissions,
             default_permissions: value.default_permissions,
+            sync_permissions: value.sync_permissions,
+            archived: value.archived,
             last_message_id: value.last_message_id,
         }
     }
@@ -541,6 +861,9 @@ impl From<crate::SystemMessage> for SystemMessage {
             crate::SystemMessage::UserRemove { id, by } => Self::UserRemove { id, by },
             crate::SystemMessage::MessagePinned { id, by } => Self::MessagePinned { id, by },
             crate::SystemMessage::MessageUnpinned { id, by } => Self::MessageUnpinned { id, by },
+            crate::SystemMessage::ServerOwnershipChanged { from, to } => {
+                Self::ServerOwnershipChanged { from, to }
+            }
         }
     }
 }
@@ -608,11 +931,236 @@ impl From<crate::Report> for Report {
     }
 }
 
+impl From<crate::ModerationRecord> for ModerationRecord {
+    fn from(value: crate::ModerationRecord) -> Self {
+        ModerationRecord {
+            id: value.id,
+            server: value.server,
+            user: value.user,
+            moderator: value.moderator,
+            action: value.action.into(),
+            reason: value.reason,
+            expires_at: value.expires_at,
+        }
+    }
+}
+
+impl From<crate::ModerationAction> for ModerationAction {
+    fn from(value: crate::ModerationAction) -> Self {
+        match value {
+            crate::ModerationAction::Timeout => ModerationAction::Timeout,
+            crate::ModerationAction::Kick => ModerationAction::Kick,
+            crate::ModerationAction::Ban => ModerationAction::Ban,
+        }
+    }
+}
+
+impl From<crate::PruneJob> for PruneJob {
+    fn from(value: crate::PruneJob) -> Self {
+        PruneJob {
+            id: value.id,
+            server: value.server,
+            creator: value.creator,
+            total: value.members.len(),
+            processed: value.processed,
+            kicked: value.kicked,
+            status: value.status.into(),
+        }
+    }
+}
+
+impl From<crate::PruneStatus> for PruneStatus {
+    fn from(value: crate::PruneStatus) -> Self {
+        match value {
+            crate::PruneStatus::Pending => PruneStatus::Pending,
+            crate::PruneStatus::Running => PruneStatus::Running,
+            crate::PruneStatus::Completed => PruneStatus::Completed,
+            crate::PruneStatus::Failed => PruneStatus::Failed,
+        }
+    }
+}
+
+impl From<crate::RoleAssignmentJob> for RoleAssignmentJob {
+    fn from(value: crate::RoleAssignmentJob) -> Self {
+        RoleAssignmentJob {
+            id: value.id,
+            server: value.server,
+            role: value.role,
+            creator: value.creator,
+            action: value.action.into(),
+            total: value.total,
+            processed: value.processed,
+            status: value.status.into(),
+        }
+    }
+}
+
+impl From<crate::RoleAssignmentAction> for RoleAssignmentAction {
+    fn from(value: crate::RoleAssignmentAction) -> Self {
+        match value {
+            crate::RoleAssignmentAction::Add => RoleAssignmentAction::Add,
+            crate::RoleAssignmentAction::Remove => RoleAssignmentAction::Remove,
+        }
+    }
+}
+
+impl From<RoleAssignmentAction> for crate::RoleAssignmentAction {
+    fn from(value: RoleAssignmentAction) -> Self {
+        match value {
+            RoleAssignmentAction::Add => crate::RoleAssignmentAction::Add,
+            RoleAssignmentAction::Remove => crate::RoleAssignmentAction::Remove,
+        }
+    }
+}
+
+impl From<crate::RoleAssignmentStatus> for RoleAssignmentStatus {
+    fn from(value: crate::RoleAssignmentStatus) -> Self {
+        match value {
+            crate::RoleAssignmentStatus::Running => RoleAssignmentStatus::Running,
+            crate::RoleAssignmentStatus::Completed => RoleAssignmentStatus::Completed,
+            crate::RoleAssignmentStatus::Failed => RoleAssignmentStatus::Failed,
+        }
+    }
+}
+
+impl From<crate::NotificationSettings> for NotificationSettings {
+    fn from(value: crate::NotificationSettings) -> Self {
+        NotificationSettings {
+            servers: value
+                .servers
+                .into_iter()
+                .map(|(id, preference)| (id, preference.into()))
+                .collect(),
+            channels: value
+                .channels
+                .into_iter()
+                .map(|(id, preference)| (id, preference.into()))
+                .collect(),
+        }
+    }
+}
+
+impl From<crate::NotificationPreference> for NotificationPreference {
+    fn from(value: crate::NotificationPreference) -> Self {
+        NotificationPreference {
+            level: value.level.map(|level| level.into()),
+            muted_until: value.muted_until,
+        }
+    }
+}
+
+impl From<NotificationPreference> for crate::NotificationPreference {
+    fn from(value: NotificationPreference) -> Self {
+        crate::NotificationPreference {
+            level: value.level.map(|level| level.into()),
+            muted_until: value.muted_until,
+        }
+    }
+}
+
+impl From<crate::NotificationLevel> for NotificationLevel {
+    fn from(value: crate::NotificationLevel) -> Self {
+        match value {
+            crate::NotificationLevel::All => NotificationLevel::All,
+            crate::NotificationLevel::Mentions => NotificationLevel::Mentions,
+            crate::NotificationLevel::None => NotificationLevel::None,
+        }
+    }
+}
+
+impl From<NotificationLevel> for crate::NotificationLevel {
+    fn from(value: NotificationLevel) -> Self {
+        match value {
+            NotificationLevel::All => crate::NotificationLevel::All,
+            NotificationLevel::Mentions => crate::NotificationLevel::Mentions,
+            NotificationLevel::None => crate::NotificationLevel::None,
+        }
+    }
+}
+
+impl From<crate::ServerEvent> for ServerEvent {
+    fn from(value: crate::ServerEvent) -> Self {
+        ServerEvent {
+            id: value.id,
+            server: value.server,
+            creator: value.creator,
+            title: value.title,
+            description: value.description,
+            location: value.location.into(),
+            cover: value.cover.map(|file| file.into()),
+            starts_at: value.starts_at,
+            ends_at: value.ends_at,
+            interested: value.interested,
+        }
+    }
+}
+
+impl From<crate::PartialServerEvent> for PartialServerEvent {
+    fn from(value: crate::PartialServerEvent) -> Self {
+        PartialServerEvent {
+            id: value.id,
+            server: value.server,
+            creator: value.creator,
+            title: value.title,
+            description: value.description,
+            location: value.location.map(|location| location.into()),
+            cover: value.cover.map(|file| file.into()),
+            starts_at: value.starts_at,
+            ends_at: value.ends_at,
+            interested: value.interested,
+        }
+    }
+}
+
+impl From<crate::ServerEventLocation> for ServerEventLocation {
+    fn from(value: crate::ServerEventLocation) -> Self {
+        match value {
+            crate::ServerEventLocation::Channel { id } => ServerEventLocation::Channel { id },
+            crate::ServerEventLocation::Text { name } => ServerEventLocation::Text { name },
+        }
+    }
+}
+
+impl From<ServerEventLocation> for crate::ServerEventLocation {
+    fn from(value: ServerEventLocation) -> Self {
+        match value {
+            ServerEventLocation::Channel { id } => crate::ServerEventLocation::Channel { id },
+            ServerEventLocation::Text { name } => crate::ServerEventLocation::Text { name },
+        }
+    }
+}
+
+impl From<crate::FieldsServerEvent> for FieldsServerEvent {
+    fn from(value: crate::FieldsServerEvent) -> Self {
+        match value {
+            crate::FieldsServerEvent::Description => FieldsServerEvent::Description,
+            crate::FieldsServerEvent::Cover => FieldsServerEvent::Cover,
+        }
+    }
+}
+
+impl From<FieldsServerEvent> for crate::FieldsServerEvent {
+    fn from(value: FieldsServerEvent) -> Self {
+        match value {
+            FieldsServerEvent::Description => crate::FieldsServerEvent::Description,
+            FieldsServerEvent::Cover => crate::FieldsServerEvent::Cover,
+        }
+    }
+}
+
 impl From<crate::ServerBan> for ServerBan {
     fn from(value: crate::ServerBan) -> Self {
+        let remaining = value
+            .expires_at
+            .map(|expires_at| (*expires_at - *Timestamp::now_utc()).whole_seconds().max(0) as u64);
+
         ServerBan {
             id: value.id.into(),
             reason: value.reason,
+            moderator: value.moderator,
+            created_at: value.created_at,
+            expires_at: value.expires_at,
+            remaining,
         }
     }
 }
@@ -626,6 +1174,11 @@ impl From<crate::Member> for Member {
             avatar: value.avatar.map(|f| f.into()),
             roles: value.roles,
             timeout: value.timeout,
+            timeout_reason: value.timeout_reason,
+            timeout_moderator: value.timeout_moderator,
+            pending: value.pending,
+            invite: value.invite,
+            temporary: value.temporary,
         }
     }
 }
@@ -637,8 +1190,16 @@ impl From<Member> for crate::Member {
             joined_at: value.joined_at,
             nickname: value.nickname,
             avatar: value.avatar.map(|f| f.into()),
+            bio: None,
+            banner: None,
+            pronouns: None,
             roles: value.roles,
             timeout: value.timeout,
+            timeout_reason: value.timeout_reason,
+            timeout_moderator: value.timeout_moderator,
+            pending: value.pending,
+            invite: value.invite,
+            temporary: value.temporary,
         }
     }
 }
@@ -652,6 +1213,11 @@ impl From<crate::PartialMember> for PartialMember {
             avatar: value.avatar.map(|f| f.into()),
             roles: value.roles,
             timeout: value.timeout,
+            timeout_reason: value.timeout_reason,
+            timeout_moderator: value.timeout_moderator,
+            pending: value.pending,
+            invite: value.invite,
+            temporary: value.temporary,
         }
     }
 }
@@ -663,8 +1229,26 @@ impl From<PartialMember> for crate::PartialMember {
             joined_at: value.joined_at,
             nickname: value.nickname,
             avatar: value.avatar.map(|f| f.into()),
+            bio: None,
+            banner: None,
+            pronouns: None,
             roles: value.roles,
             timeout: value.timeout,
+            timeout_reason: value.timeout_reason,
+            timeout_moderator: value.timeout_moderator,
+            pending: value.pending,
+            invite: value.invite,
+            temporary: value.temporary,
+        }
+    }
+}
+
+impl From<crate::Member> for MemberProfile {
+    fn from(value: crate::Member) -> Self {
+        MemberProfile {
+            bio: value.bio,
+            banner: value.banner.map(|f| f.into()),
+            pronouns: value.pronouns,
         }
     }
 }
@@ -694,6 +1278,11 @@ impl From<crate::FieldsMember> for FieldsMember {
             crate::FieldsMember::Nickname => FieldsMember::Nickname,
             crate::FieldsMember::Roles => FieldsMember::Roles,
             crate::FieldsMember::Timeout => FieldsMember::Timeout,
+            crate::FieldsMember::TimeoutReason => FieldsMember::TimeoutReason,
+            crate::FieldsMember::TimeoutModerator => FieldsMember::TimeoutModerator,
+            crate::FieldsMember::Bio => FieldsMember::Bio,
+            crate::FieldsMember::Banner => FieldsMember::Banner,
+            crate::FieldsMember::Pronouns => FieldsMember::Pronouns,
         }
     }
 }
@@ -705,6 +1294,11 @@ impl From<FieldsMember> for crate::FieldsMember {
             FieldsMember::Nickname => crate::FieldsMember::Nickname,
             FieldsMember::Roles => crate::FieldsMember::Roles,
             FieldsMember::Timeout => crate::FieldsMember::Timeout,
+            FieldsMember::TimeoutReason => crate::FieldsMember::TimeoutReason,
+            FieldsMember::TimeoutModerator => crate::FieldsMember::TimeoutModerator,
+            FieldsMember::Bio => crate::FieldsMember::Bio,
+            FieldsMember::Banner => crate::FieldsMember::Banner,
+            FieldsMember::Pronouns => crate::FieldsMember::Pronouns,
         }
     }
 }
@@ -741,8 +1335,16 @@ impl From<crate::Server> for Server {
             banner: value.banner.map(|f| f.into()),
             flags: value.flags.unwrap_or_default() as u32,
             nsfw: value.nsfw,
+            rules: value.rules,
+            verification: value.verification.map(|v| v.into()),
+            locked: value.locked,
+            scheduled_deletion: value.scheduled_deletion,
             analytics: value.analytics,
             discoverable: value.discoverable,
+            featured: value.featured,
+            tags: value.tags,
+            category: value.category,
+            language: value.language,
         }
     }
 }
@@ -769,8 +1371,17 @@ impl From<Server> for crate::Server {
             banner: value.banner.map(|f| f.into()),
             flags: Some(value.flags as i32),
             nsfw: value.nsfw,
+            rules: value.rules,
+            verification: value.verification.map(|v| v.into()),
+            locked: value.locked,
+            scheduled_deletion: value.scheduled_deletion,
             analytics: value.analytics,
             discoverable: value.discoverable,
+            featured: value.featured,
+            tags: value.tags,
+            category: value.category,
+            language: value.language,
+            member_count: 0,
         }
     }
 }
@@ -795,8 +1406,16 @@ impl From<crate::PartialServer> for PartialServer {
             banner: value.banner.map(|f| f.into()),
             flags: value.flags.map(|v| v as u32),
             nsfw: value.nsfw,
+            rules: value.rules,
+            verification: value.verification.map(|v| v.into()),
+            locked: value.locked,
+            scheduled_deletion: value.scheduled_deletion,
             analytics: value.analytics,
             discoverable: value.discoverable,
+            featured: value.featured,
+            tags: value.tags,
+            category: value.category,
+            language: value.language,
         }
     }
 }
@@ -821,8 +1440,17 @@ impl From<PartialServer> for crate::PartialServer {
             banner: value.banner.map(|f| f.into()),
             flags: value.flags.map(|v| v as i32),
             nsfw: value.nsfw,
+            rules: value.rules,
+            verification: value.verification.map(|v| v.into()),
+            locked: value.locked,
+            scheduled_deletion: value.scheduled_deletion,
             analytics: value.analytics,
             discoverable: value.discoverable,
+            featured: value.featured,
+            tags: value.tags,
+            category: value.category,
+            language: value.language,
+            member_count: None,
         }
     }
 }
@@ -835,6 +1463,11 @@ impl From<crate::FieldsServer> for FieldsServer {
             crate::FieldsServer::Description => FieldsServer::Description,
             crate::FieldsServer::Icon => FieldsServer::Icon,
             crate::FieldsServer::SystemMessages => FieldsServer::SystemMessages,
+            crate::FieldsServer::Rules => FieldsServer::Rules,
+            crate::FieldsServer::Verification => FieldsServer::Verification,
+            crate::FieldsServer::ScheduledDeletion => FieldsServer::ScheduledDeletion,
+            crate::FieldsServer::Category => FieldsServer::Category,
+            crate::FieldsServer::Language => FieldsServer::Language,
         }
     }
 }
@@ -847,6 +1480,11 @@ impl From<FieldsServer> for crate::FieldsServer {
             FieldsServer::Description => crate::FieldsServer::Description,
             FieldsServer::Icon => crate::FieldsServer::Icon,
             FieldsServer::SystemMessages => crate::FieldsServer::SystemMessages,
+            FieldsServer::Rules => crate::FieldsServer::Rules,
+            FieldsServer::Verification => crate::FieldsServer::Verification,
+            FieldsServer::ScheduledDeletion => crate::FieldsServer::ScheduledDeletion,
+            FieldsServer::Category => crate::FieldsServer::Category,
+            FieldsServer::Language => crate::FieldsServer::Language,
         }
     }
 }
@@ -857,6 +1495,8 @@ impl From<crate::Category> for Category {
             id: value.id,
             title: value.title,
             channels: value.channels,
+            default_permissions: value.default_permissions,
+            role_permissions: value.role_permissions,
         }
     }
 }
@@ -867,6 +1507,70 @@ impl From<Category> for crate::Category {
             id: value.id,
             title: value.title,
             channels: value.channels,
+            default_permissions: value.default_permissions,
+            role_permissions: value.role_permissions,
+        }
+    }
+}
+
+impl From<crate::PartialCategory> for PartialCategory {
+    fn from(value: crate::PartialCategory) -> Self {
+        PartialCategory {
+            id: value.id,
+            title: value.title,
+            channels: value.channels,
+            default_permissions: value.default_permissions,
+            role_permissions: value.role_permissions,
+        }
+    }
+}
+
+impl From<PartialCategory> for crate::PartialCategory {
+    fn from(value: PartialCategory) -> Self {
+        crate::PartialCategory {
+            id: value.id,
+            title: value.title,
+            channels: value.channels,
+            default_permissions: value.default_permissions,
+            role_permissions: value.role_permissions,
+        }
+    }
+}
+
+impl From<crate::FieldsCategory> for FieldsCategory {
+    fn from(value: crate::FieldsCategory) -> Self {
+        match value {
+            crate::FieldsCategory::DefaultPermissions => FieldsCategory::DefaultPermissions,
+        }
+    }
+}
+
+impl From<FieldsCategory> for crate::FieldsCategory {
+    fn from(value: FieldsCategory) -> Self {
+        match value {
+            FieldsCategory::DefaultPermissions => crate::FieldsCategory::DefaultPermissions,
+        }
+    }
+}
+
+impl From<crate::VerificationRequirements> for VerificationRequirements {
+    fn from(value: crate::VerificationRequirements) -> Self {
+        VerificationRequirements {
+            email: value.email,
+            mfa: value.mfa,
+            account_age: value.account_age,
+            membership_age: value.membership_age,
+        }
+    }
+}
+
+impl From<VerificationRequirements> for crate::VerificationRequirements {
+    fn from(value: VerificationRequirements) -> Self {
+        crate::VerificationRequirements {
+            email: value.email,
+            mfa: value.mfa,
+            account_age: value.account_age,
+            membership_age: value.membership_age,
         }
     }
 }
@@ -901,6 +1605,10 @@ impl From<crate::Role> for Role {
             colour: value.colour,
             hoist: value.hoist,
             rank: value.rank,
+            icon: value.icon.map(|file| file.into()),
+            emoji: value.emoji,
+            mentionable: value.mentionable,
+            member_count: None,
         }
     }
 }
@@ -913,6 +1621,9 @@ impl From<Role> for crate::Role {
             colour: value.colour,
             hoist: value.hoist,
             rank: value.rank,
+            icon: value.icon.map(|file| file.into()),
+            emoji: value.emoji,
+            mentionable: value.mentionable,
         }
     }
 }
@@ -925,6 +1636,10 @@ impl From<crate::PartialRole> for PartialRole {
             colour: value.colour,
             hoist: value.hoist,
             rank: value.rank,
+            icon: value.icon.map(|file| file.into()),
+            emoji: value.emoji,
+            mentionable: value.mentionable,
+            member_count: None,
         }
     }
 }
@@ -937,6 +1652,57 @@ impl From<PartialRole> for crate::PartialRole {
             colour: value.colour,
             hoist: value.hoist,
             rank: value.rank,
+            icon: value.icon.map(|file| file.into()),
+            emoji: value.emoji,
+            mentionable: value.mentionable,
+        }
+    }
+}
+
+impl From<crate::ServerTemplate> for ServerTemplate {
+    fn from(value: crate::ServerTemplate) -> Self {
+        ServerTemplate {
+            code: value.code,
+            server_id: value.server_id,
+            creator_id: value.creator_id,
+            name: value.name,
+            description: value.description,
+            structure: value.structure.into(),
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl From<crate::ServerStructure> for ServerStructure {
+    fn from(value: crate::ServerStructure) -> Self {
+        ServerStructure {
+            default_permissions: value.default_permissions,
+            roles: value
+                .roles
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect(),
+            channels: value.channels.into_iter().map(|v| v.into()).collect(),
+            categories: value.categories.into_iter().map(|v| v.into()).collect(),
+            system_messages: value.system_messages.map(|v| v.into()),
+        }
+    }
+}
+
+impl From<crate::TemplateChannel> for TemplateChannel {
+    fn from(value: crate::TemplateChannel) -> Self {
+        TemplateChannel {
+            id: value.id,
+            channel_type: match value.channel_type {
+                crate::TemplateChannelType::Text => TemplateChannelType::Text,
+                crate::TemplateChannelType::Voice => TemplateChannelType::Voice,
+            },
+            name: value.name,
+            description: value.description,
+            default_permissions: value.default_permissions,
+            role_permissions: value.role_permissions,
+            sync_permissions: value.sync_permissions,
+            nsfw: value.nsfw,
         }
     }
 }
@@ -945,6 +1711,8 @@ impl From<crate::FieldsRole> for FieldsRole {
     fn from(value: crate::FieldsRole) -> Self {
         match value {
             crate::FieldsRole::Colour => FieldsRole::Colour,
+            crate::FieldsRole::Icon => FieldsRole::Icon,
+            crate::FieldsRole::Emoji => FieldsRole::Emoji,
         }
     }
 }
@@ -953,6 +1721,8 @@ impl From<FieldsRole> for crate::FieldsRole {
     fn from(value: FieldsRole) -> Self {
         match value {
             FieldsRole::Colour => crate::FieldsRole::Colour,
+            FieldsRole::Icon => crate::FieldsRole::Icon,
+            FieldsRole::Emoji => crate::FieldsRole::Emoji,
         }
     }
 }
@@ -1308,6 +2078,7 @@ impl From<crate::UserProfile> for UserProfile {
         UserProfile {
             content: value.content,
             background: value.background.map(|file| file.into()),
+            member: None,
         }
     }
 }
@@ -174,7 +174,7 @@ async fn calculate_members_permissions<'a>(
 ) -> HashMap<String, PermissionValue> {
     let mut resp = HashMap::new();
 
-    let (_, channel_role_permissions, channel_default_permissions) = match query
+    let (id, channel_role_permissions, channel_default_permissions, sync_permissions) = match query
         .channel
         .as_ref()
         .expect("A channel must be assigned to calculate channel permissions")
@@ -184,17 +184,26 @@ async fn calculate_members_permissions<'a>(
             id,
             role_permissions,
             default_permissions,
+            sync_permissions,
             ..
         }
         | Channel::VoiceChannel {
             id,
             role_permissions,
             default_permissions,
+            sync_permissions,
             ..
-        } => (id, role_permissions, default_permissions),
+        } => (id, role_permissions, default_permissions, sync_permissions),
         _ => panic!("Calculation of member permissions must be done on a server channel"),
     };
 
+    // Find the category this channel inherits permissions from
+    let category = if sync_permissions {
+        query.server.category_of(&id).cloned()
+    } else {
+        None
+    };
+
     if query.users.is_none() {
         let ids: Vec<String> = query
             .members
@@ -272,40 +281,77 @@ async fn calculate_members_permissions<'a>(
             continue;
         }
 
+        // Members in timeout, yet to accept the rules or failing verification are restricted
+        let restricted = member.in_timeout()
+            || member.pending
+            || match &query.server.verification {
+                Some(verification) => !verification.is_met(query.database, user, member).await,
+                None => false,
+            };
+
         // Get the user's server permissions
-        let mut permission = calculate_server_permissions(&query.server, user, member);
+        let mut permission = calculate_server_permissions(&query.server, user, member, restricted);
+
+        // Apply the category layer if the channel is synced to one
+        if let Some(category) = &category {
+            if let Some(defaults) = category.default_permissions {
+                permission.apply(defaults.into());
+            }
+
+            for role_override in
+                get_role_overrides(&query.server, member, &category.role_permissions)
+            {
+                permission.apply(role_override)
+            }
+        }
 
         if let Some(defaults) = channel_default_permissions {
             permission.apply(defaults.into());
         }
 
         // Get the applicable role overrides
-        let mut roles = channel_role_permissions
-            .iter()
-            .filter(|(id, _)| member.roles.contains(id))
-            .filter_map(|(id, permission)| {
-                query.server.roles.get(id).map(|role| {
-                    let v: Override = (*permission).into();
-                    (role.rank, v)
-                })
-            })
-            .collect::<Vec<(i64, Override)>>();
-
-        roles.sort_by(|a, b| b.0.cmp(&a.0));
-        let overrides = roles.into_iter().map(|(_, v)| v);
-
-        for role_override in overrides {
+        for role_override in get_role_overrides(&query.server, member, &channel_role_permissions) {
             permission.apply(role_override)
         }
 
+        if restricted {
+            permission.restrict(*ALLOW_IN_TIMEOUT);
+        }
+
         resp.insert(user.id.clone(), permission);
     }
 
     resp
 }
 
+/// Get the ordered role overrides (from lowest to highest) for a member from a set of role permissions
+fn get_role_overrides(
+    server: &Server,
+    member: &Member,
+    role_permissions: &HashMap<String, OverrideField>,
+) -> Vec<Override> {
+    let mut roles = role_permissions
+        .iter()
+        .filter(|(id, _)| member.roles.contains(id))
+        .filter_map(|(id, permission)| {
+            server.roles.get(id).map(|role| {
+                let v: Override = (*permission).into();
+                (role.rank, v)
+            })
+        })
+        .collect::<Vec<(i64, Override)>>();
+
+    roles.sort_by(|a, b| b.0.cmp(&a.0));
+    roles.into_iter().map(|(_, v)| v).collect()
+}
+
 /// Calculates a member's server permissions
-fn calculate_server_permissions(server: &Server, user: &User, member: &Member) -> PermissionValue {
+fn calculate_server_permissions(
+    server: &Server,
+    user: &User,
+    member: &Member,
+    restricted: bool,
+) -> PermissionValue {
     if user.privileged || server.owner == user.id {
         return ChannelPermission::GrantAllSafe.into();
     }
@@ -329,7 +375,7 @@ fn calculate_server_permissions(server: &Server, user: &User, member: &Member) -
         permissions.apply(role);
     }
 
-    if member.in_timeout() {
+    if restricted {
         permissions.restrict(*ALLOW_IN_TIMEOUT);
     }
 
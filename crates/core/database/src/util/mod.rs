@@ -1,3 +1,5 @@
+pub mod audit_log;
+pub mod automod;
 pub mod bridge;
 pub mod bulk_permissions;
 pub mod idempotency;
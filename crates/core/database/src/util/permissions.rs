@@ -1,16 +1,15 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashMap};
 
 use revolt_permissions::{
-    calculate_user_permissions, ChannelType, Override, PermissionQuery, PermissionValue,
-    RelationshipStatus, DEFAULT_PERMISSION_DIRECT_MESSAGE,
+    calculate_user_permissions, ChannelType, Override, OverrideField, PermissionQuery,
+    PermissionValue, RelationshipStatus, DEFAULT_PERMISSION_DIRECT_MESSAGE,
 };
 
-use crate::{Channel, Database, Member, Server, User};
+use crate::{Category, Channel, Database, Member, Server, User};
 
 /// Permissions calculator
 #[derive(Clone)]
 pub struct DatabasePermissionQuery<'a> {
-    #[allow(dead_code)]
     database: &'a Database,
 
     perspective: &'a User,
@@ -23,6 +22,7 @@ pub struct DatabasePermissionQuery<'a> {
     cached_user_permission: Option<PermissionValue>,
     cached_mutual_connection: Option<bool>,
     cached_permission: Option<u64>,
+    cached_unverified: Option<bool>,
 }
 
 #[async_trait]
@@ -124,7 +124,10 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
     /// Is our perspective user a member of the server?
     async fn are_we_a_member(&mut self) -> bool {
         if let Some(server) = &self.server {
-            if self.member.is_some() {
+            // Servers pending deletion are hidden from everyone but their owner
+            if server.scheduled_deletion.is_some() && server.owner != self.perspective.id {
+                false
+            } else if self.member.is_some() {
                 true
             } else if let Ok(member) = self
                 .database
@@ -185,6 +188,37 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
         }
     }
 
+    /// Is our perspective user yet to accept this server's rules?
+    async fn are_we_pending(&mut self) -> bool {
+        if let Some(member) = &self.member {
+            member.pending
+        } else {
+            false
+        }
+    }
+
+    /// Does our perspective user fail to meet this server's verification requirements?
+    async fn are_we_unverified(&mut self) -> bool {
+        if let Some(unverified) = self.cached_unverified {
+            return unverified;
+        }
+
+        let unverified = if let (Some(server), Some(member)) = (&self.server, &self.member) {
+            if let Some(verification) = &server.verification {
+                !verification
+                    .is_met(self.database, self.perspective, member)
+                    .await
+            } else {
+                false
+            }
+        } else {
+            false
+        };
+
+        self.cached_unverified = Some(unverified);
+        unverified
+    }
+
     // * For calculating channel permission
 
     /// Get the type of the channel
@@ -208,6 +242,21 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
         }
     }
 
+    /// Get the default permissions of the category this channel inherits from
+    async fn get_default_category_permissions(&mut self) -> Override {
+        self.get_synced_category()
+            .and_then(|category| category.default_permissions)
+            .map(|permissions| permissions.into())
+            .unwrap_or_default()
+    }
+
+    /// Get the ordered role overrides (from lowest to highest) for this member in the category this channel inherits from
+    async fn get_our_category_role_overrides(&mut self) -> Vec<Override> {
+        self.get_synced_category()
+            .map(|category| self.get_our_role_overrides(&category.role_permissions))
+            .unwrap_or_default()
+    }
+
     /// Get the default channel permissions
     /// Group channel defaults should be mapped to an allow-only override
     async fn get_default_channel_permissions(&mut self) -> Override {
@@ -256,31 +305,7 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
                 })
                 | Cow::Owned(Channel::VoiceChannel {
                     role_permissions, ..
-                }) => {
-                    if let Some(server) = &self.server {
-                        let member_roles = self
-                            .member
-                            .as_ref()
-                            .map(|member| member.roles.clone())
-                            .unwrap_or_default();
-
-                        let mut roles = role_permissions
-                            .iter()
-                            .filter(|(id, _)| member_roles.contains(id))
-                            .filter_map(|(id, permission)| {
-                                server.roles.get(id).map(|role| {
-                                    let v: Override = (*permission).into();
-                                    (role.rank, v)
-                                })
-                            })
-                            .collect::<Vec<(i64, Override)>>();
-
-                        roles.sort_by(|a, b| b.0.cmp(&a.0));
-                        roles.into_iter().map(|(_, v)| v).collect()
-                    } else {
-                        vec![]
-                    }
-                }
+                }) => self.get_our_role_overrides(role_permissions),
                 _ => vec![],
             }
         } else {
@@ -378,6 +403,56 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
 }
 
 impl<'a> DatabasePermissionQuery<'a> {
+    /// Get the category the current channel inherits permissions from, if it is synced to one
+    fn get_synced_category(&self) -> Option<&Category> {
+        match self.channel.as_deref() {
+            Some(Channel::TextChannel {
+                id,
+                sync_permissions: true,
+                ..
+            })
+            | Some(Channel::VoiceChannel {
+                id,
+                sync_permissions: true,
+                ..
+            }) => self
+                .server
+                .as_deref()
+                .and_then(|server| server.category_of(id)),
+            _ => None,
+        }
+    }
+
+    /// Get the ordered role overrides (from lowest to highest) for this member from a set of role permissions
+    fn get_our_role_overrides(
+        &self,
+        role_permissions: &HashMap<String, OverrideField>,
+    ) -> Vec<Override> {
+        if let Some(server) = &self.server {
+            let member_roles = self
+                .member
+                .as_ref()
+                .map(|member| member.roles.clone())
+                .unwrap_or_default();
+
+            let mut roles = role_permissions
+                .iter()
+                .filter(|(id, _)| member_roles.contains(id))
+                .filter_map(|(id, permission)| {
+                    server.roles.get(id).map(|role| {
+                        let v: Override = (*permission).into();
+                        (role.rank, v)
+                    })
+                })
+                .collect::<Vec<(i64, Override)>>();
+
+            roles.sort_by(|a, b| b.0.cmp(&a.0));
+            roles.into_iter().map(|(_, v)| v).collect()
+        } else {
+            vec![]
+        }
+    }
+
     /// Create a new permission calculator
     pub fn new(database: &'a Database, perspective: &'a User) -> DatabasePermissionQuery<'a> {
         DatabasePermissionQuery {
@@ -391,6 +466,7 @@ impl<'a> DatabasePermissionQuery<'a> {
             cached_mutual_connection: None,
             cached_user_permission: None,
             cached_permission: None,
+            cached_unverified: None,
         }
     }
 
@@ -63,9 +63,19 @@ impl Reference {
                     .into_iter()
                     .next()
                     .ok_or(create_error!(NotFound))?,
+                max_uses: None,
+                expires_at: None,
+                temporary: false,
+                uses: 0,
+                vanity: false,
             })
         } else {
-            db.fetch_invite(&self.id).await
+            let invite = db.fetch_invite(&self.id).await?;
+            if invite.is_expired() {
+                return Err(create_error!(NotFound));
+            }
+
+            Ok(invite)
         }
     }
 
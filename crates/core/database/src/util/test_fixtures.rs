@@ -4,7 +4,10 @@ use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
 use serde_json::from_str;
 
-use crate::{Channel, Database, Member, Server, User};
+use crate::{
+    AuditLogEntry, AutomodRule, Channel, Database, Invite, Member, Server, ServerBan, ServerEvent,
+    User,
+};
 
 static RE_ID: Lazy<Regex> = Lazy::new(|| Regex::new("__ID:(\\d+)__").unwrap());
 
@@ -15,6 +18,11 @@ enum LoadedFixture {
     Channel(Channel),
     Server(Server),
     ServerMember(Member),
+    ServerBan(ServerBan),
+    ServerEvent(ServerEvent),
+    Invite(Invite),
+    AutomodRule(AutomodRule),
+    AuditLogEntry(AuditLogEntry),
 }
 
 pub async fn load_fixture(db: &Database, input: &str) -> HashMap<String, String> {
@@ -40,6 +48,11 @@ pub async fn load_fixture(db: &Database, input: &str) -> HashMap<String, String>
             LoadedFixture::Channel(channel) => db.insert_channel(&channel).await.unwrap(),
             LoadedFixture::Server(server) => db.insert_server(&server).await.unwrap(),
             LoadedFixture::ServerMember(member) => db.insert_member(&member).await.unwrap(),
+            LoadedFixture::ServerBan(ban) => db.insert_ban(&ban).await.unwrap(),
+            LoadedFixture::ServerEvent(event) => db.insert_server_event(&event).await.unwrap(),
+            LoadedFixture::Invite(invite) => db.insert_invite(&invite).await.unwrap(),
+            LoadedFixture::AutomodRule(rule) => db.insert_automod_rule(&rule).await.unwrap(),
+            LoadedFixture::AuditLogEntry(entry) => db.insert_audit_log_entry(&entry).await.unwrap(),
         }
     }
 
@@ -53,6 +66,9 @@ pub trait FetchFixture {
     async fn channel(&self, db: &Database, d: usize) -> Channel;
     async fn server(&self, db: &Database, d: usize) -> Server;
     async fn member(&self, db: &Database, d_server: usize, d_user: usize) -> Member;
+    async fn server_event(&self, db: &Database, d: usize) -> ServerEvent;
+    async fn invite(&self, db: &Database, d: usize) -> Invite;
+    async fn automod_rule(&self, db: &Database, d: usize) -> AutomodRule;
 }
 
 #[async_trait]
@@ -83,6 +99,24 @@ impl FetchFixture for HashMap<String, String> {
         .await
         .unwrap()
     }
+
+    async fn server_event(&self, db: &Database, d: usize) -> ServerEvent {
+        db.fetch_server_event(self.get(&d.to_string()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn invite(&self, db: &Database, d: usize) -> Invite {
+        db.fetch_invite(self.get(&d.to_string()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn automod_rule(&self, db: &Database, d: usize) -> AutomodRule {
+        db.fetch_automod_rule(self.get(&d.to_string()).unwrap())
+            .await
+            .unwrap()
+    }
 }
 
 #[macro_export]
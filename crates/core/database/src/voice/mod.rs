@@ -0,0 +1,275 @@
+use revolt_config::config;
+use revolt_models::v0;
+use revolt_permissions::{ChannelPermission, PermissionValue};
+use revolt_presence::{delete_voice_state, set_voice_state, VoiceState};
+use revolt_result::Result;
+
+use crate::{events::client::EventV1, Channel, Database};
+
+mod token;
+
+pub use token::*;
+
+/// Grants given to a user when joining a voice room
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceGrants {
+    /// Room this grant applies to
+    pub room: String,
+    /// Whether the user may join the room
+    pub room_join: bool,
+    /// Whether the user may moderate other participants
+    #[serde(skip_serializing_if = "crate::if_false", default)]
+    pub room_admin: bool,
+    /// Whether the user may publish tracks
+    pub can_publish: bool,
+    /// Whether the user may subscribe to other participants' tracks
+    pub can_subscribe: bool,
+    /// Sources the user may publish from
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub can_publish_sources: Vec<String>,
+}
+
+/// Token issued by a voice backend
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VoiceToken {
+    /// Signed token used to authenticate with the voice server
+    pub token: String,
+    /// URL of the voice server to connect to
+    pub url: String,
+    /// Region the voice server is located in
+    pub region: String,
+}
+
+/// Abstract voice server implementation
+#[async_trait]
+pub trait VoiceBackend: Sync + Send {
+    /// Issue a token for a user to join a room with the given grants
+    async fn create_token(
+        &self,
+        user_id: &str,
+        grants: VoiceGrants,
+        region: Option<&str>,
+    ) -> Result<VoiceToken>;
+
+    /// Replace the grants of a user already connected to a room
+    async fn update_grants(
+        &self,
+        user_id: &str,
+        grants: VoiceGrants,
+        region: Option<&str>,
+    ) -> Result<()>;
+
+    /// Disconnect a user from a room
+    async fn remove_participant(
+        &self,
+        user_id: &str,
+        room: &str,
+        region: Option<&str>,
+    ) -> Result<()>;
+}
+
+impl VoiceGrants {
+    /// Derive the grants for a room from a user's channel permissions
+    pub fn from_permissions(room: &str, permissions: &PermissionValue) -> VoiceGrants {
+        let mut can_publish_sources = vec![];
+
+        if permissions.has_channel_permission(ChannelPermission::Speak) {
+            can_publish_sources.push("microphone".to_string());
+        }
+
+        if permissions.has_channel_permission(ChannelPermission::Video) {
+            can_publish_sources.push("camera".to_string());
+            can_publish_sources.push("screen_share".to_string());
+            can_publish_sources.push("screen_share_audio".to_string());
+        }
+
+        let room_join = permissions.has_channel_permission(ChannelPermission::Connect);
+
+        VoiceGrants {
+            room: room.to_string(),
+            room_join,
+            room_admin: room_join
+                && (permissions.has_channel_permission(ChannelPermission::MuteMembers)
+                    || permissions.has_channel_permission(ChannelPermission::DeafenMembers)
+                    || permissions.has_channel_permission(ChannelPermission::MoveMembers)),
+            can_publish: room_join && !can_publish_sources.is_empty(),
+            can_subscribe: room_join,
+            can_publish_sources: if room_join {
+                can_publish_sources
+            } else {
+                vec![]
+            },
+        }
+    }
+
+    /// Restrict the grants according to a user's current voice state
+    pub fn apply_voice_state(&mut self, state: &VoiceState) {
+        if state.muted {
+            self.can_publish_sources
+                .retain(|source| source != "microphone" && source != "screen_share_audio");
+            self.can_publish = !self.can_publish_sources.is_empty();
+        }
+
+        if state.deafened {
+            self.can_subscribe = false;
+        }
+    }
+}
+
+/// Convert a stored voice state into its API model
+pub fn voice_state_model(state: VoiceState) -> v0::VoiceState {
+    v0::VoiceState {
+        id: state.user_id,
+        channel: state.channel_id,
+        muted: state.muted,
+        deafened: state.deafened,
+        joined_at: iso8601_timestamp::Timestamp::from_unix_timestamp_ms(state.joined_at as i64),
+    }
+}
+
+/// Publish a user's voice state in a channel, no state means the user left the call
+pub async fn publish_voice_state(channel: &Channel, user_id: &str, state: Option<VoiceState>) {
+    let topic = match channel {
+        Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => {
+            server.to_string()
+        }
+        _ => channel.id().to_string(),
+    };
+
+    EventV1::VoiceStateUpdate {
+        id: channel.id().to_string(),
+        user: user_id.to_string(),
+        state: state.map(voice_state_model),
+    }
+    .p(topic)
+    .await;
+}
+
+/// Store a user's voice state and notify both the channel they
+/// joined and any channel they were previously connected to
+pub async fn update_voice_state(db: &Database, channel: &Channel, state: VoiceState) {
+    if let Some(previous) = set_voice_state(&state).await {
+        if previous.channel_id != state.channel_id {
+            if let Ok(previous_channel) = db.fetch_channel(&previous.channel_id).await {
+                publish_voice_state(&previous_channel, &state.user_id, None).await;
+            }
+        }
+    }
+
+    let user_id = state.user_id.clone();
+    publish_voice_state(channel, &user_id, Some(state)).await;
+}
+
+/// Drop a user's session from a call on the voice server
+///
+/// Failures are logged, the user's tracked voice state is left untouched.
+pub async fn remove_voice_participant(state: &VoiceState) {
+    let result = match voice_backend().await {
+        Ok(backend) => {
+            backend
+                .remove_participant(&state.user_id, &state.channel_id, state.region.as_deref())
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        error!(
+            "Failed to remove {} from call {}: {error:?}",
+            state.user_id, state.channel_id
+        );
+    }
+}
+
+/// Disconnect a user from whichever call they are in, returns their previous state
+pub async fn disconnect_voice_state(db: &Database, user_id: &str) -> Option<VoiceState> {
+    let previous = delete_voice_state(user_id).await?;
+    remove_voice_participant(&previous).await;
+
+    if let Ok(channel) = db.fetch_channel(&previous.channel_id).await {
+        publish_voice_state(&channel, user_id, None).await;
+    }
+
+    Some(previous)
+}
+
+/// Create the voice backend configured for this node
+pub async fn voice_backend() -> Result<Box<dyn VoiceBackend>> {
+    let config = config().await;
+    if config.api.voice.nodes.is_empty() {
+        return Err(create_error!(VoiceUnavailable));
+    }
+
+    Ok(Box::new(TokenVoiceBackend::new(config.api.voice)))
+}
+
+#[cfg(test)]
+mod tests {
+    use revolt_permissions::{ChannelPermission, PermissionValue, DEFAULT_PERMISSION};
+    use revolt_presence::VoiceState;
+
+    use super::VoiceGrants;
+
+    #[test]
+    fn grants_from_default_permissions() {
+        let permissions: PermissionValue = (*DEFAULT_PERMISSION).into();
+        let grants = VoiceGrants::from_permissions("room", &permissions);
+
+        assert!(grants.room_join);
+        assert!(grants.can_publish);
+        assert!(grants.can_subscribe);
+        assert!(!grants.room_admin);
+        assert_eq!(grants.can_publish_sources, vec!["microphone".to_string()]);
+    }
+
+    #[test]
+    fn grants_for_moderators() {
+        let permissions: PermissionValue = (ChannelPermission::Connect
+            + ChannelPermission::Speak
+            + ChannelPermission::Video
+            + ChannelPermission::MuteMembers)
+            .into();
+
+        let grants = VoiceGrants::from_permissions("room", &permissions);
+
+        assert!(grants.room_admin);
+        assert!(grants
+            .can_publish_sources
+            .contains(&"screen_share".to_string()));
+    }
+
+    #[test]
+    fn grants_for_muted_users() {
+        let permissions: PermissionValue =
+            (ChannelPermission::Connect + ChannelPermission::Speak + ChannelPermission::Video)
+                .into();
+
+        let mut grants = VoiceGrants::from_permissions("room", &permissions);
+        grants.apply_voice_state(&VoiceState {
+            muted: true,
+            deafened: true,
+            ..VoiceState::new("room", "user")
+        });
+
+        assert!(grants.can_publish);
+        assert!(!grants.can_subscribe);
+        assert_eq!(
+            grants.can_publish_sources,
+            vec!["camera".to_string(), "screen_share".to_string()]
+        );
+    }
+
+    #[test]
+    fn grants_without_connect() {
+        let permissions: PermissionValue =
+            (ChannelPermission::Speak + ChannelPermission::MuteMembers).into();
+
+        let grants = VoiceGrants::from_permissions("room", &permissions);
+
+        assert!(!grants.room_join);
+        assert!(!grants.room_admin);
+        assert!(!grants.can_publish);
+        assert!(grants.can_publish_sources.is_empty());
+    }
+}
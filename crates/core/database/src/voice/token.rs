@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use isahc::http::Request;
+use jwt_simple::prelude::{Claims, Duration, HS256Key, JWTClaims, MACLike};
+use revolt_config::{ApiVoice, ApiVoiceNode};
+use revolt_result::Result;
+use serde::Serialize;
+
+use super::{VoiceBackend, VoiceGrants, VoiceToken};
+
+/// Claims included in the room token
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VoiceClaims {
+    /// Grants for the room being joined
+    pub video: VoiceGrants,
+}
+
+/// Permissions of a participant already connected to a room
+#[derive(Serialize, Debug, Clone)]
+struct ParticipantPermission {
+    can_subscribe: bool,
+    can_publish: bool,
+    can_publish_sources: Vec<String>,
+}
+
+/// Request to change a connected participant's permissions
+#[derive(Serialize, Debug, Clone)]
+struct UpdateParticipantRequest {
+    room: String,
+    identity: String,
+    permission: ParticipantPermission,
+}
+
+/// Request identifying a participant in a room
+#[derive(Serialize, Debug, Clone)]
+struct RoomParticipantIdentity {
+    room: String,
+    identity: String,
+}
+
+/// Voice backend which signs short-lived room tokens
+pub struct TokenVoiceBackend {
+    /// Lifetime of issued tokens in seconds
+    token_ttl: u64,
+    /// Region to use if none is requested
+    default_region: String,
+    /// Available voice nodes by region
+    nodes: HashMap<String, ApiVoiceNode>,
+}
+
+impl TokenVoiceBackend {
+    /// Create a new token backend from configuration
+    pub fn new(config: ApiVoice) -> TokenVoiceBackend {
+        TokenVoiceBackend {
+            token_ttl: config.token_ttl,
+            default_region: config.default_region,
+            nodes: config.nodes,
+        }
+    }
+
+    /// Find the node for a given region, falling back to the default region
+    fn resolve_node(&self, region: Option<&str>) -> Result<(&String, &ApiVoiceNode)> {
+        let region = region.unwrap_or(&self.default_region);
+        self.nodes
+            .get_key_value(region)
+            .ok_or_else(|| create_error!(VoiceUnavailable))
+    }
+
+    /// Call the room service of a voice node with administrative access to a room
+    async fn room_service<T: Serialize>(
+        &self,
+        node: &ApiVoiceNode,
+        room: &str,
+        method: &str,
+        body: &T,
+    ) -> Result<()> {
+        let token = TokenVoiceBackend::sign(
+            node,
+            Claims::with_custom_claims(
+                VoiceClaims {
+                    video: VoiceGrants {
+                        room: room.to_string(),
+                        room_admin: true,
+                        ..Default::default()
+                    },
+                },
+                Duration::from_secs(self.token_ttl),
+            ),
+        )?;
+
+        let body = serde_json::to_vec(body).map_err(|_| create_error!(InternalError))?;
+
+        let request = Request::post(format!(
+            "{}/twirp/livekit.RoomService/{method}",
+            node.url.replacen("ws", "http", 1)
+        ))
+        .header("Authorization", format!("Bearer {token}"))
+        .header("Content-Type", "application/json")
+        .body(body)
+        .map_err(|_| create_error!(InternalError))?;
+
+        let response = isahc::send_async(request)
+            .await
+            .map_err(|_| create_error!(InternalError))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(create_error!(VoiceUnavailable))
+        }
+    }
+
+    /// Sign claims using a node's secret
+    fn sign(node: &ApiVoiceNode, claims: JWTClaims<VoiceClaims>) -> Result<String> {
+        HS256Key::from_bytes(node.secret.as_bytes())
+            .authenticate(claims.with_issuer(&node.key))
+            .map_err(|_| create_error!(InternalError))
+    }
+}
+
+#[async_trait]
+impl VoiceBackend for TokenVoiceBackend {
+    /// Issue a token for a user to join a room with the given grants
+    async fn create_token(
+        &self,
+        user_id: &str,
+        grants: VoiceGrants,
+        region: Option<&str>,
+    ) -> Result<VoiceToken> {
+        if !grants.room_join {
+            return Err(create_error!(MissingPermission {
+                permission: "Connect".to_string()
+            }));
+        }
+
+        let (region, node) = self.resolve_node(region)?;
+
+        let claims = Claims::with_custom_claims(
+            VoiceClaims { video: grants },
+            Duration::from_secs(self.token_ttl),
+        )
+        .with_subject(user_id);
+
+        Ok(VoiceToken {
+            token: TokenVoiceBackend::sign(node, claims)?,
+            url: node.url.clone(),
+            region: region.clone(),
+        })
+    }
+
+    /// Replace the grants of a user already connected to a room
+    async fn update_grants(
+        &self,
+        user_id: &str,
+        grants: VoiceGrants,
+        region: Option<&str>,
+    ) -> Result<()> {
+        let (_, node) = self.resolve_node(region)?;
+
+        self.room_service(
+            node,
+            &grants.room,
+            "UpdateParticipant",
+            &UpdateParticipantRequest {
+                room: grants.room.clone(),
+                identity: user_id.to_string(),
+                permission: ParticipantPermission {
+                    can_subscribe: grants.can_subscribe,
+                    can_publish: grants.can_publish,
+                    can_publish_sources: grants
+                        .can_publish_sources
+                        .iter()
+                        .map(|source| source.to_uppercase())
+                        .collect(),
+                },
+            },
+        )
+        .await
+    }
+
+    /// Disconnect a user from a room
+    async fn remove_participant(
+        &self,
+        user_id: &str,
+        room: &str,
+        region: Option<&str>,
+    ) -> Result<()> {
+        let (_, node) = self.resolve_node(region)?;
+
+        self.room_service(
+            node,
+            room,
+            "RemoveParticipant",
+            &RoomParticipantIdentity {
+                room: room.to_string(),
+                identity: user_id.to_string(),
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use jwt_simple::prelude::{HS256Key, MACLike};
+    use revolt_config::{ApiVoice, ApiVoiceNode};
+    use revolt_permissions::{PermissionValue, DEFAULT_PERMISSION};
+
+    use super::{TokenVoiceBackend, VoiceClaims};
+    use crate::voice::{VoiceBackend, VoiceGrants};
+
+    fn backend() -> TokenVoiceBackend {
+        let mut nodes = HashMap::new();
+        nodes.insert(
+            "eu".to_string(),
+            ApiVoiceNode {
+                url: "wss://eu.voice.example".to_string(),
+                key: "key".to_string(),
+                secret: "secret".to_string(),
+            },
+        );
+
+        TokenVoiceBackend::new(ApiVoice {
+            token_ttl: 60,
+            default_region: "eu".to_string(),
+            nodes,
+        })
+    }
+
+    #[async_std::test]
+    async fn token_carries_grants() {
+        let permissions: PermissionValue = (*DEFAULT_PERMISSION).into();
+        let grants = VoiceGrants::from_permissions("channel", &permissions);
+
+        let token = backend()
+            .create_token("user", grants.clone(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(token.region, "eu");
+        assert_eq!(token.url, "wss://eu.voice.example");
+
+        let claims = HS256Key::from_bytes(b"secret")
+            .verify_token::<VoiceClaims>(&token.token, None)
+            .unwrap();
+
+        assert_eq!(claims.subject.as_deref(), Some("user"));
+        assert_eq!(claims.issuer.as_deref(), Some("key"));
+        assert_eq!(claims.custom.video, grants);
+    }
+
+    #[async_std::test]
+    async fn unknown_region() {
+        let permissions: PermissionValue = (*DEFAULT_PERMISSION).into();
+        let grants = VoiceGrants::from_permissions("channel", &permissions);
+
+        assert!(backend()
+            .create_token("user", grants, Some("us"))
+            .await
+            .is_err());
+    }
+}
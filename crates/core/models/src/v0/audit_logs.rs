@@ -0,0 +1,110 @@
+use super::User;
+
+#[cfg(feature = "rocket")]
+use rocket::{FromForm, FromFormField};
+
+#[cfg(feature = "validator")]
+use validator::Validate;
+
+auto_derived!(
+    /// Audit Log Entry
+    pub struct AuditLogEntry {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the server this entry belongs to
+        pub server: String,
+        /// Id of the user who performed the action
+        pub actor: String,
+        /// Action that was performed
+        pub action: AuditLogAction,
+        /// Id of the object the action was performed on
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub target: Option<String>,
+        /// Fields changed by this action
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub changes: Vec<AuditLogChange>,
+        /// Reason given for this action
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub reason: Option<String>,
+    }
+
+    /// Change made to a single field
+    pub struct AuditLogChange {
+        /// Name of the field
+        pub key: String,
+        /// JSON encoded value before the change
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub before: Option<String>,
+        /// JSON encoded value after the change
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub after: Option<String>,
+    }
+
+    /// Action recorded in the audit log
+    #[cfg_attr(feature = "rocket", derive(FromFormField))]
+    pub enum AuditLogAction {
+        ServerUpdate,
+        ChannelCreate,
+        ChannelUpdate,
+        ChannelDelete,
+        CategoryCreate,
+        CategoryUpdate,
+        CategoryDelete,
+        RoleCreate,
+        RoleUpdate,
+        RoleDelete,
+        RoleAssignmentCreate,
+        MemberUpdate,
+        MemberKick,
+        MemberBan,
+        MemberUnban,
+        MemberPrune,
+        InviteDelete,
+        WebhookCreate,
+        WebhookUpdate,
+        WebhookDelete,
+        EmojiCreate,
+        EmojiDelete,
+        AutomodRuleCreate,
+        AutomodRuleUpdate,
+        AutomodRuleDelete,
+        VanityInviteUpdate,
+        VanityInviteDelete,
+        ServerEventCreate,
+        ServerEventUpdate,
+        ServerEventDelete,
+    }
+
+    /// Options for fetching the audit log
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsFetchAuditLog {
+        /// Maximum number of entries to fetch
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 100)))]
+        pub limit: Option<i64>,
+        /// Entry id before which entries should be fetched
+        #[cfg_attr(feature = "validator", validate(length(min = 26, max = 26)))]
+        pub before: Option<String>,
+        /// Entry id after which entries should be fetched
+        #[cfg_attr(feature = "validator", validate(length(min = 26, max = 26)))]
+        pub after: Option<String>,
+        /// Only include entries performed by this user
+        pub actor: Option<String>,
+        /// Only include entries of this type
+        pub action: Option<AuditLogAction>,
+        /// Only include entries targeting this object
+        pub target: Option<String>,
+    }
+
+    /// Audit log result
+    pub struct AuditLogResult {
+        /// Audit log entries, newest first
+        pub entries: Vec<AuditLogEntry>,
+        /// Users who performed the listed actions
+        pub users: Vec<User>,
+    }
+);
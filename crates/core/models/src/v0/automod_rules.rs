@@ -0,0 +1,170 @@
+#[cfg(feature = "validator")]
+use validator::Validate;
+
+auto_derived!(
+    /// Automod Rule
+    pub struct AutomodRule {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the server this rule belongs to
+        pub server: String,
+        /// Name of the rule
+        pub name: String,
+        /// Whether this rule is currently being enforced
+        pub enabled: bool,
+
+        /// Condition which causes this rule to trigger
+        pub trigger: AutomodTrigger,
+        /// Actions taken when this rule triggers
+        pub actions: Vec<AutomodAction>,
+
+        /// Roles which are not affected by this rule
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub exempt_roles: Vec<String>,
+        /// Channels in which this rule is not enforced
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub exempt_channels: Vec<String>,
+    }
+
+    /// Condition which causes an automod rule to trigger
+    #[serde(tag = "type")]
+    pub enum AutomodTrigger {
+        /// Message contains a blocked word or matches a blocked pattern
+        Keyword {
+            /// Case-insensitive words or phrases to block
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "Vec::is_empty", default)
+            )]
+            keywords: Vec<String>,
+            /// Regular expressions to block
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "Vec::is_empty", default)
+            )]
+            patterns: Vec<String>,
+        },
+        /// Message mentions too many users
+        MentionSpam {
+            /// Maximum number of unique mentions allowed
+            max_mentions: usize,
+        },
+        /// Message contains links or invites
+        Links {
+            /// Whether to block invites to other servers
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            block_invites: bool,
+            /// Whether to block links to other websites
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            block_links: bool,
+            /// Domains which may always be linked to
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "Vec::is_empty", default)
+            )]
+            allowed_domains: Vec<String>,
+        },
+        /// Author is sending messages too quickly
+        Flood {
+            /// Maximum number of messages allowed within the window
+            max_messages: usize,
+            /// Length of the window in seconds
+            window: u64,
+        },
+        /// Author is repeating the same message
+        Repeated {
+            /// Maximum number of identical messages allowed within the window
+            max_duplicates: usize,
+            /// Length of the window in seconds
+            window: u64,
+        },
+        /// Message is mostly written in capital letters
+        Caps {
+            /// Minimum number of letters before this rule applies
+            min_length: usize,
+            /// Maximum percentage of letters which may be capitals
+            max_percentage: u8,
+        },
+        /// Message contains excessive combining characters
+        Zalgo {
+            /// Maximum number of combining characters allowed on a single character
+            max_combining: usize,
+        },
+    }
+
+    /// Action taken when an automod rule triggers
+    #[serde(tag = "type")]
+    pub enum AutomodAction {
+        /// Reject the message and tell the author why
+        Block,
+        /// Silently discard the message
+        Delete,
+        /// Time out the author
+        Timeout {
+            /// Length of the timeout in seconds
+            duration: u64,
+        },
+        /// Notify moderators in a channel
+        Alert {
+            /// Id of the channel to send the alert to
+            channel: String,
+        },
+    }
+
+    /// Information about new automod rule to create
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataCreateAutomodRule {
+        /// Rule name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+        /// Whether the rule should be enforced immediately, defaults to true
+        pub enabled: Option<bool>,
+        /// Condition which causes this rule to trigger
+        pub trigger: AutomodTrigger,
+        /// Actions taken when this rule triggers
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 4)))]
+        pub actions: Vec<AutomodAction>,
+        /// Roles which are not affected by this rule
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "validator", validate(length(max = 100)))]
+        pub exempt_roles: Vec<String>,
+        /// Channels in which this rule is not enforced
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "validator", validate(length(max = 100)))]
+        pub exempt_channels: Vec<String>,
+    }
+
+    /// New automod rule information
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataEditAutomodRule {
+        /// Rule name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: Option<String>,
+        /// Whether the rule is being enforced
+        pub enabled: Option<bool>,
+        /// Condition which causes this rule to trigger
+        pub trigger: Option<AutomodTrigger>,
+        /// Actions taken when this rule triggers
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 4)))]
+        pub actions: Option<Vec<AutomodAction>>,
+        /// Roles which are not affected by this rule
+        #[cfg_attr(feature = "validator", validate(length(max = 100)))]
+        pub exempt_roles: Option<Vec<String>>,
+        /// Channels in which this rule is not enforced
+        #[cfg_attr(feature = "validator", validate(length(max = 100)))]
+        pub exempt_channels: Option<Vec<String>>,
+    }
+);
@@ -1,5 +1,8 @@
 use super::User;
 
+#[cfg(feature = "rocket")]
+use rocket::FromForm;
+
 auto_derived!(
     /// Bot
     #[derive(Default)]
@@ -54,6 +57,44 @@ auto_derived!(
             serde(skip_serializing_if = "crate::if_zero_u32", default)
         )]
         pub flags: u32,
+
+        /// Tags describing this bot on the directory
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub tags: Vec<String>,
+        /// Short summary shown on the directory
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "String::is_empty", default)
+        )]
+        pub short_description: String,
+        /// Full description shown on the directory
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "String::is_empty", default)
+        )]
+        pub long_description: String,
+        /// Whether this bot has been removed from the directory by platform moderators
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub delisted: bool,
+        /// State of this bot's verification request
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub verification: Option<BotVerificationStatus>,
+    }
+
+    /// State of a bot verification request
+    pub enum BotVerificationStatus {
+        /// Waiting for review by platform moderators
+        Pending,
+        /// Request was approved and the bot is verified
+        Approved,
+        /// Request was rejected
+        Rejected,
     }
 
     /// Optional fields on bot object
@@ -131,6 +172,25 @@ auto_derived!(
         /// Interactions URL
         #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2048)))]
         pub interactions_url: Option<String>,
+        /// Whether the bot should be listed on the directory
+        ///
+        /// The bot must also be public in order to show up.
+        pub discoverable: Option<bool>,
+        /// Tags describing this bot on the directory
+        #[cfg_attr(feature = "validator", validate(length(max = 5)))]
+        pub tags: Option<Vec<String>>,
+        /// Short summary shown on the directory
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 120)))]
+        pub short_description: Option<String>,
+        /// Full description shown on the directory
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 4000)))]
+        pub long_description: Option<String>,
+        /// URL for terms of service
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2048)))]
+        pub terms_of_service_url: Option<String>,
+        /// URL for privacy policy
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2048)))]
+        pub privacy_policy_url: Option<String>,
         /// Fields to remove from bot object
         #[cfg_attr(feature = "validator", validate(length(min = 1)))]
         pub remove: Option<Vec<FieldsBot>>,
@@ -169,4 +229,90 @@ auto_derived!(
         pub bot: Bot,
         pub user: User,
     }
+
+    /// Bot listed on the directory
+    pub struct DirectoryBot {
+        /// Public bot information
+        #[serde(flatten)]
+        pub bot: PublicBot,
+        /// Tags describing this bot
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub tags: Vec<String>,
+        /// Short summary of this bot
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "String::is_empty", default)
+        )]
+        pub short_description: String,
+        /// Full description of this bot
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "String::is_empty", default)
+        )]
+        pub long_description: String,
+        /// URL for terms of service
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "String::is_empty", default)
+        )]
+        pub terms_of_service_url: String,
+        /// URL for privacy policy
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "String::is_empty", default)
+        )]
+        pub privacy_policy_url: String,
+        /// Enum of bot flags
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_zero_u32", default)
+        )]
+        pub flags: u32,
+        /// Number of servers this bot is in
+        pub server_count: i64,
+    }
+
+    /// Options for browsing the bot directory
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsBotDirectory {
+        /// Text to search for in bot names, descriptions and tags
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 64)))]
+        pub query: Option<String>,
+        /// Only include bots with all of these tags
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "validator", validate(length(max = 5)))]
+        pub tags: Vec<String>,
+        /// Only include verified bots
+        pub verified: Option<bool>,
+        /// Maximum number of bots to fetch
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 100)))]
+        pub limit: Option<i64>,
+        /// Number of bots to skip
+        #[cfg_attr(feature = "validator", validate(range(min = 0)))]
+        pub offset: Option<i64>,
+    }
+
+    /// Bot directory results
+    pub struct BotDirectoryResponse {
+        /// Bots matching the search
+        pub bots: Vec<DirectoryBot>,
+        /// Total number of bots matching the search
+        pub total: i64,
+    }
+
+    /// Decision on a bot verification request
+    pub struct DataReviewBotVerification {
+        /// Whether to verify the bot
+        pub approve: bool,
+    }
+
+    /// New bot directory listing information
+    pub struct DataEditBotListing {
+        /// Whether the bot is removed from the directory
+        pub delisted: bool,
+    }
 );
@@ -1,4 +1,19 @@
-use super::{Channel, File, Server, User};
+use iso8601_timestamp::Timestamp;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::{Channel, File, Member, Server, User};
+
+#[cfg(feature = "rocket")]
+use rocket::FromForm;
+
+#[cfg(feature = "validator")]
+use validator::Validate;
+
+/// Regex for valid vanity invite codes
+///
+/// Lowercase alphanumeric words separated by single dashes
+pub static RE_VANITY: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap());
 
 auto_derived!(
     /// Invite
@@ -15,6 +30,28 @@ auto_derived!(
             creator: String,
             /// Id of the server channel this invite points to
             channel: String,
+
+            /// Maximum number of times this invite can be used
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            max_uses: Option<u32>,
+            /// Time at which this invite expires
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            expires_at: Option<Timestamp>,
+            /// Whether members joining through this invite are removed once they go offline
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            temporary: bool,
+            /// Number of times this invite has been used
+            #[cfg_attr(feature = "serde", serde(default))]
+            uses: u32,
+            /// Whether this is the server's custom vanity invite
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            vanity: bool,
         },
         /// Invite to a group channel
         Group {
@@ -25,6 +62,72 @@ auto_derived!(
             creator: String,
             /// Id of the group channel this invite points to
             channel: String,
+
+            /// Maximum number of times this invite can be used
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            max_uses: Option<u32>,
+            /// Time at which this invite expires
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            expires_at: Option<Timestamp>,
+            /// Number of times this invite has been used
+            #[cfg_attr(feature = "serde", serde(default))]
+            uses: u32,
+        },
+    }
+
+    /// New invite information
+    #[derive(Default)]
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataCreateInvite {
+        /// Maximum number of times this invite can be used
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 1000)))]
+        pub max_uses: Option<u32>,
+        /// Number of seconds until this invite expires
+        #[cfg_attr(feature = "validator", validate(range(min = 60, max = 2592000)))]
+        pub max_age: Option<u64>,
+        /// Whether members joining through this invite should be removed once they go offline
+        ///
+        /// Only applies to server invites, members are kept if they are given a role.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub temporary: bool,
+    }
+
+    /// Vanity invite information
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataSetVanityInvite {
+        /// Custom invite code
+        #[cfg_attr(
+            feature = "validator",
+            validate(length(min = 3, max = 24), regex = "RE_VANITY")
+        )]
+        pub code: String,
+        /// Id of the server channel the invite should point to
+        pub channel: String,
+    }
+
+    /// Options for fetching server invites
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsFetchInvites {
+        /// Whether to include the members who joined through each invite
+        pub include_members: Option<bool>,
+        /// Maximum number of members to fetch
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 1000)))]
+        pub limit: Option<i64>,
+        /// User id after which members should be fetched
+        #[cfg_attr(feature = "validator", validate(length(min = 26, max = 26)))]
+        pub after: Option<String>,
+    }
+
+    /// Server invites response
+    #[serde(untagged)]
+    pub enum FetchInvitesResponse {
+        JustInvites(Vec<Invite>),
+        InvitesWithMembers {
+            /// Invites to this server
+            invites: Vec<Invite>,
+            /// Members who joined through one of these invites
+            members: Vec<Member>,
         },
     }
 
@@ -100,6 +100,12 @@ auto_derived!(
                 )
             )]
             role_permissions: HashMap<String, OverrideField>,
+            /// Whether this channel inherits permissions from its category
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            sync_permissions: bool,
 
             /// Whether this channel is marked as not safe for work
             #[cfg_attr(
@@ -107,6 +113,12 @@ auto_derived!(
                 serde(skip_serializing_if = "crate::if_false", default)
             )]
             nsfw: bool,
+            /// Whether this channel is archived and no longer accepts new messages
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            archived: bool,
         },
         /// Voice channel belonging to a server
         VoiceChannel {
@@ -137,6 +149,12 @@ auto_derived!(
                 )
             )]
             role_permissions: HashMap<String, OverrideField>,
+            /// Whether this channel inherits permissions from its category
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            sync_permissions: bool,
 
             /// Whether this channel is marked as not safe for work
             #[cfg_attr(
@@ -169,6 +187,10 @@ auto_derived!(
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub default_permissions: Option<OverrideField>,
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub sync_permissions: Option<bool>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub archived: Option<bool>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub last_message_id: Option<String>,
     }
 
@@ -205,6 +227,9 @@ auto_derived!(
         /// Whether this channel is archived
         pub archived: Option<bool>,
 
+        /// Whether this channel should inherit permissions from its category
+        pub sync_permissions: Option<bool>,
+
         /// Fields to remove from channel
         #[cfg_attr(feature = "serde", serde(default))]
         pub remove: Option<Vec<FieldsChannel>>,
@@ -288,10 +313,21 @@ auto_derived!(
         pub leave_silently: Option<bool>,
     }
 
+    /// Options when joining a voice call
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsJoinCall {
+        /// Preferred voice server region
+        pub region: Option<String>,
+    }
+
     /// Voice server token response
-    pub struct LegacyCreateVoiceUserResponse {
+    pub struct CreateVoiceUserResponse {
         /// Token for authenticating with the voice server
-        token: String,
+        pub token: String,
+        /// URL of the voice server to connect to
+        pub url: String,
+        /// Region of the voice server
+        pub region: String,
     }
 );
 
@@ -307,6 +343,16 @@ impl Channel {
         }
     }
 
+    /// Get a reference to the id of the server this channel belongs to
+    pub fn server(&self) -> Option<&str> {
+        match self {
+            Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => {
+                Some(server)
+            }
+            _ => None,
+        }
+    }
+
     /// This returns a Result because the recipient name can't be determined here without a db call,
     /// which can't be done since this is models, which can't reference the database crate.
     ///
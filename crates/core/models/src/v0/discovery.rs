@@ -0,0 +1,115 @@
+use super::File;
+
+#[cfg(feature = "validator")]
+use validator::Validate;
+
+#[cfg(feature = "rocket")]
+use rocket::{FromForm, FromFormField};
+
+auto_derived!(
+    /// Public preview of a discoverable server
+    pub struct DiscoveryServer {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Name of the server
+        pub name: String,
+        /// Description for the server
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+        /// Icon attachment
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub icon: Option<File>,
+        /// Banner attachment
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub banner: Option<File>,
+        /// Bitfield of server flags
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_zero_u32", default)
+        )]
+        pub flags: u32,
+        /// Whether this server is flagged as not safe for work
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub nsfw: bool,
+        /// Whether this server is featured
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub featured: bool,
+        /// Tags describing this server
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub tags: Vec<String>,
+        /// Category this server is listed under
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub category: Option<String>,
+        /// Primary language used in this server
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub language: Option<String>,
+        /// Number of members in this server
+        pub member_count: i64,
+    }
+
+    /// Sort used for discovering servers
+    #[derive(Default)]
+    #[cfg_attr(feature = "rocket", derive(FromFormField))]
+    pub enum DiscoverySort {
+        /// Sort by the servers with the most members first
+        #[default]
+        Popular,
+        /// Sort by the servers with the most recent messages first
+        Active,
+    }
+
+    /// Options for discovering servers
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsDiscoverServers {
+        /// Text to search for in server names, descriptions and tags
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 64)))]
+        pub query: Option<String>,
+        /// Only include servers with all of these tags
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "validator", validate(length(max = 5)))]
+        pub tags: Vec<String>,
+        /// Only include servers in this category
+        pub category: Option<String>,
+        /// Only include servers using this language
+        pub language: Option<String>,
+        /// Only include featured servers
+        pub featured: Option<bool>,
+        /// Whether to include servers flagged as not safe for work
+        pub include_nsfw: Option<bool>,
+        /// Sort order
+        pub sort: Option<DiscoverySort>,
+        /// Maximum number of servers to fetch
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 100)))]
+        pub limit: Option<i64>,
+        /// Number of servers to skip
+        #[cfg_attr(feature = "validator", validate(range(min = 0)))]
+        pub offset: Option<i64>,
+    }
+
+    /// Discovery search results
+    pub struct DiscoveryResponse {
+        /// Servers matching the search
+        pub servers: Vec<DiscoveryServer>,
+        /// Total number of servers matching the search
+        pub total: i64,
+    }
+
+    /// New discovery listing information
+    pub struct DataEditDiscoveryListing {
+        /// Whether this server is listed on discovery
+        pub discoverable: Option<bool>,
+        /// Whether this server is featured on discovery
+        pub featured: Option<bool>,
+    }
+);
@@ -1,31 +1,51 @@
+mod audit_logs;
+mod automod_rules;
 mod bots;
 mod channel_invites;
 mod channel_unreads;
 mod channel_webhooks;
 mod channels;
+mod discovery;
 mod embeds;
 mod emojis;
 mod files;
 mod messages;
+mod moderation_records;
+mod notification_settings;
+mod role_assignment_jobs;
 mod safety_reports;
 mod server_bans;
+mod server_events;
+mod server_insights;
 mod server_members;
+mod server_templates;
 mod servers;
 mod user_settings;
 mod users;
+mod voice;
 
+pub use audit_logs::*;
+pub use automod_rules::*;
 pub use bots::*;
 pub use channel_invites::*;
 pub use channel_unreads::*;
 pub use channel_webhooks::*;
 pub use channels::*;
+pub use discovery::*;
 pub use embeds::*;
 pub use emojis::*;
 pub use files::*;
 pub use messages::*;
+pub use moderation_records::*;
+pub use notification_settings::*;
+pub use role_assignment_jobs::*;
 pub use safety_reports::*;
 pub use server_bans::*;
+pub use server_events::*;
+pub use server_insights::*;
 pub use server_members::*;
+pub use server_templates::*;
 pub use servers::*;
 pub use user_settings::*;
 pub use users::*;
+pub use voice::*;
@@ -0,0 +1,62 @@
+use iso8601_timestamp::Timestamp;
+
+use super::User;
+
+#[cfg(feature = "rocket")]
+use rocket::FromForm;
+
+#[cfg(feature = "validator")]
+use validator::Validate;
+
+auto_derived!(
+    /// Moderation action taken against a server member
+    pub struct ModerationRecord {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the server this action was taken in
+        pub server: String,
+        /// Id of the user this action was taken against
+        pub user: String,
+        /// Id of the moderator who took this action
+        ///
+        /// Not present if the action was taken by automod.
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub moderator: Option<String>,
+        /// Action that was taken
+        pub action: ModerationAction,
+        /// Reason given for this action
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub reason: Option<String>,
+        /// Time at which this timeout or ban lapses
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub expires_at: Option<Timestamp>,
+    }
+
+    /// Moderation action taken against a server member
+    pub enum ModerationAction {
+        Timeout,
+        Kick,
+        Ban,
+    }
+
+    /// Options for fetching a member's moderation history
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsFetchModerationHistory {
+        /// Maximum number of records to fetch
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 100)))]
+        pub limit: Option<i64>,
+        /// Record id before which records should be fetched
+        #[cfg_attr(feature = "validator", validate(length(min = 26, max = 26)))]
+        pub before: Option<String>,
+    }
+
+    /// Moderation history result
+    pub struct ModerationHistoryResult {
+        /// Moderation records, newest first
+        pub records: Vec<ModerationRecord>,
+        /// Moderators who took the listed actions
+        pub users: Vec<User>,
+    }
+);
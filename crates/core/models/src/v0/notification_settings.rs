@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use iso8601_timestamp::Timestamp;
+
+#[cfg(feature = "validator")]
+use validator::Validate;
+
+auto_derived!(
+    /// Which messages a user wants to be notified about
+    pub enum NotificationLevel {
+        /// Every message
+        All,
+        /// Only messages which mention the user
+        Mentions,
+        /// No messages at all
+        None,
+    }
+
+    /// Notification preference for a single server or channel
+    pub struct NotificationPreference {
+        /// Which messages to be notified about
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub level: Option<NotificationLevel>,
+        /// Time until which notifications are muted
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub muted_until: Option<Timestamp>,
+    }
+
+    /// User's notification settings
+    pub struct NotificationSettings {
+        /// Preferences for entire servers
+        pub servers: HashMap<String, NotificationPreference>,
+        /// Preferences for individual channels, taking priority over servers
+        pub channels: HashMap<String, NotificationPreference>,
+    }
+
+    /// Notification settings changes
+    ///
+    /// Setting a preference to null removes it.
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataEditNotificationSettings {
+        /// Preferences for entire servers
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(
+            feature = "validator",
+            validate(length(max = 200), custom = "validate_ids")
+        )]
+        pub servers: HashMap<String, Option<NotificationPreference>>,
+        /// Preferences for individual channels
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(
+            feature = "validator",
+            validate(length(max = 500), custom = "validate_ids")
+        )]
+        pub channels: HashMap<String, Option<NotificationPreference>>,
+    }
+);
+
+/// Validate that every preference is keyed by a valid id
+#[cfg(feature = "validator")]
+fn validate_ids(
+    preferences: &HashMap<String, Option<NotificationPreference>>,
+) -> Result<(), validator::ValidationError> {
+    let is_id = |id: &String| {
+        id.len() == 26
+            && id.chars().all(|c| {
+                matches!(c, '0'..='9' | 'A'..='H' | 'J' | 'K' | 'M' | 'N' | 'P'..='T' | 'V'..='Z')
+            })
+    };
+
+    if preferences.keys().all(is_id) {
+        Ok(())
+    } else {
+        Err(validator::ValidationError::new("id"))
+    }
+}
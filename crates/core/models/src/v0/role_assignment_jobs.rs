@@ -0,0 +1,61 @@
+auto_derived!(
+    /// Bulk role assignment job
+    pub struct RoleAssignmentJob {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the server this job belongs to
+        pub server: String,
+        /// Id of the role being assigned or removed
+        pub role: String,
+        /// Id of the user who started this job
+        pub creator: String,
+        /// Whether the role is being assigned or removed
+        pub action: RoleAssignmentAction,
+
+        /// Number of members this job applies to
+        pub total: usize,
+        /// Number of members processed so far
+        pub processed: usize,
+        /// Current state of this job
+        pub status: RoleAssignmentStatus,
+    }
+
+    /// Change made to each member's roles
+    pub enum RoleAssignmentAction {
+        Add,
+        Remove,
+    }
+
+    /// State of a role assignment job
+    pub enum RoleAssignmentStatus {
+        Running,
+        Completed,
+        Failed,
+    }
+
+    /// Members a role assignment applies to
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum RoleAssignmentTarget {
+        /// Every member of the server
+        Everyone,
+        /// Every member who has a given role
+        Role {
+            /// Role Id
+            id: String,
+        },
+        /// Specific members
+        Members {
+            /// User Ids
+            ids: Vec<String>,
+        },
+    }
+
+    /// New role assignment job information
+    pub struct DataCreateRoleAssignment {
+        /// Whether to assign or remove the role
+        pub action: RoleAssignmentAction,
+        /// Members to apply the change to
+        pub target: RoleAssignmentTarget,
+    }
+);
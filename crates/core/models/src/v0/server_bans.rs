@@ -1,5 +1,10 @@
+use iso8601_timestamp::Timestamp;
+
 use super::{File, MemberCompositeKey, User};
 
+#[cfg(feature = "rocket")]
+use rocket::FromForm;
+
 #[cfg(feature = "validator")]
 use validator::Validate;
 
@@ -11,6 +16,19 @@ auto_derived!(
         pub id: MemberCompositeKey,
         /// Reason for ban creation
         pub reason: Option<String>,
+
+        /// Id of the moderator who issued this ban
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub moderator: Option<String>,
+        /// Time at which this ban was created
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub created_at: Option<Timestamp>,
+        /// Time at which this ban is lifted
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub expires_at: Option<Timestamp>,
+        /// Number of seconds remaining until this ban is lifted
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub remaining: Option<u64>,
     }
 
     /// Information for new server ban
@@ -19,6 +37,23 @@ auto_derived!(
         /// Ban reason
         #[cfg_attr(feature = "validator", validate(length(min = 0, max = 1024)))]
         pub reason: Option<String>,
+        /// Number of seconds until the ban is lifted
+        ///
+        /// Bans are permanent if not specified.
+        #[cfg_attr(feature = "validator", validate(range(min = 60, max = 31536000)))]
+        pub duration: Option<u64>,
+    }
+
+    /// Options for fetching server bans
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsFetchBans {
+        /// Maximum number of bans to fetch
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 1000)))]
+        pub limit: Option<i64>,
+        /// User id after which bans should be fetched
+        #[cfg_attr(feature = "validator", validate(length(min = 26, max = 26)))]
+        pub after: Option<String>,
     }
 
     /// Just enough information to list a ban
@@ -38,7 +73,7 @@ auto_derived!(
     pub struct BanListResult {
         /// Users objects
         pub users: Vec<BannedUser>,
-        /// Ban objects
+        /// Ban objects, ordered by user id
         pub bans: Vec<ServerBan>,
     }
 );
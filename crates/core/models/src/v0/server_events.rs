@@ -0,0 +1,111 @@
+use indexmap::IndexSet;
+use iso8601_timestamp::Timestamp;
+
+use super::File;
+
+#[cfg(feature = "validator")]
+use validator::Validate;
+
+auto_derived_partial!(
+    /// Scheduled server event
+    pub struct ServerEvent {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the server this event belongs to
+        pub server: String,
+        /// Id of the user who created this event
+        pub creator: String,
+
+        /// Title of the event
+        pub title: String,
+        /// Description of the event
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+        /// Where the event takes place
+        pub location: ServerEventLocation,
+        /// Cover image
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub cover: Option<File>,
+
+        /// Time at which the event starts
+        pub starts_at: Timestamp,
+        /// Time at which the event ends
+        pub ends_at: Timestamp,
+
+        /// Ids of members interested in this event
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "IndexSet::is_empty", default)
+        )]
+        pub interested: IndexSet<String>,
+    },
+    "PartialServerEvent"
+);
+
+auto_derived!(
+    /// Where a server event takes place
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum ServerEventLocation {
+        /// Voice channel within the server
+        Channel {
+            /// Channel Id
+            id: String,
+        },
+        /// Anywhere else
+        Text {
+            /// Description of the location
+            name: String,
+        },
+    }
+
+    /// Optional fields on server event object
+    pub enum FieldsServerEvent {
+        Description,
+        Cover,
+    }
+
+    /// New server event information
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataCreateServerEvent {
+        /// Title of the event
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub title: String,
+        /// Description of the event
+        #[cfg_attr(feature = "validator", validate(length(min = 0, max = 2000)))]
+        pub description: Option<String>,
+        /// Where the event takes place
+        pub location: ServerEventLocation,
+        /// Attachment Id for cover image
+        pub cover: Option<String>,
+
+        /// Time at which the event starts
+        pub starts_at: Timestamp,
+        /// Time at which the event ends
+        pub ends_at: Timestamp,
+    }
+
+    /// Server event changes
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataEditServerEvent {
+        /// Title of the event
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub title: Option<String>,
+        /// Description of the event
+        #[cfg_attr(feature = "validator", validate(length(min = 0, max = 2000)))]
+        pub description: Option<String>,
+        /// Where the event takes place
+        pub location: Option<ServerEventLocation>,
+        /// Attachment Id for cover image
+        pub cover: Option<String>,
+
+        /// Time at which the event starts
+        pub starts_at: Option<Timestamp>,
+        /// Time at which the event ends
+        pub ends_at: Option<Timestamp>,
+
+        /// Fields to remove from server event
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub remove: Vec<FieldsServerEvent>,
+    }
+);
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use iso8601_timestamp::Timestamp;
+
+#[cfg(feature = "validator")]
+use validator::Validate;
+
+#[cfg(feature = "rocket")]
+use rocket::FromForm;
+
+auto_derived!(
+    /// Daily activity rollup for a server
+    pub struct ServerInsights {
+        /// Start of the day covered by this rollup
+        pub date: Timestamp,
+
+        /// Number of users who joined
+        pub joins: i64,
+        /// Number of members who left
+        pub leaves: i64,
+        /// Number of members who were kicked or banned
+        pub kicks: i64,
+
+        /// Number of messages sent in each channel
+        pub messages: HashMap<String, i64>,
+        /// Number of members who sent at least one message
+        pub active_members: i64,
+        /// Highest number of members seen online at once
+        pub peak_online: i64,
+
+        /// Views and joins for each invite code
+        pub invites: HashMap<String, InviteConversion>,
+    }
+
+    /// Views and joins recorded for an invite
+    pub struct InviteConversion {
+        /// Number of times the invite was viewed
+        pub views: i64,
+        /// Number of users who joined through the invite
+        pub joins: i64,
+    }
+
+    /// Options for fetching server insights
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsFetchInsights {
+        /// Number of days to fetch, including today
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 90)))]
+        pub days: Option<i64>,
+    }
+);
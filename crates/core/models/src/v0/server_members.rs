@@ -57,6 +57,28 @@ auto_derived_partial!(
         /// Timestamp this member is timed out until
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub timeout: Option<Timestamp>,
+        /// Reason given for this member's timeout
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub timeout_reason: Option<String>,
+        /// Id of the moderator who timed out this member
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub timeout_moderator: Option<String>,
+        /// Whether this member has yet to accept the server's rules
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub pending: bool,
+
+        /// Code of the invite this member joined through
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub invite: Option<String>,
+        /// Whether this member is removed once they go offline
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub temporary: bool,
     },
     "PartialMember"
 );
@@ -77,6 +99,25 @@ auto_derived!(
         Avatar,
         Roles,
         Timeout,
+        TimeoutReason,
+        TimeoutModerator,
+        Bio,
+        Banner,
+        Pronouns,
+    }
+
+    /// Member's server-specific profile
+    #[derive(Default)]
+    pub struct MemberProfile {
+        /// Member's server-specific bio
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub bio: Option<String>,
+        /// Server-specific profile banner
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub banner: Option<File>,
+        /// Member's server-specific pronouns
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub pronouns: Option<String>,
     }
 
     /// Member removal intention
@@ -111,6 +152,66 @@ auto_derived!(
         pub users: Vec<User>,
     }
 
+    /// Options for previewing a member prune
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsMemberPrune {
+        /// Number of days members must have been inactive for
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 365)))]
+        pub days: u32,
+        /// Ids of roles members may hold and still be pruned
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub roles: Vec<String>,
+    }
+
+    /// Member prune information
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataMemberPrune {
+        /// Number of days members must have been inactive for
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 365)))]
+        pub days: u32,
+        /// Ids of roles members may hold and still be pruned
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub roles: Vec<String>,
+    }
+
+    /// Member prune result
+    pub struct MemberPruneResponse {
+        /// Number of members which are or will be kicked
+        pub count: usize,
+        /// Id of the job kicking these members
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub job: Option<String>,
+    }
+
+    /// Member prune job
+    pub struct PruneJob {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the server this job belongs to
+        pub server: String,
+        /// Id of the user who started this job
+        pub creator: String,
+
+        /// Number of members this job applies to
+        pub total: usize,
+        /// Number of members processed so far
+        pub processed: usize,
+        /// Number of members kicked so far
+        pub kicked: usize,
+        /// Current state of this job
+        pub status: PruneStatus,
+    }
+
+    /// State of a prune job
+    pub enum PruneStatus {
+        Pending,
+        Running,
+        Completed,
+        Failed,
+    }
+
     /// New member information
     #[cfg_attr(feature = "validator", derive(Validate))]
     pub struct DataMemberEdit {
@@ -119,10 +220,22 @@ auto_derived!(
         pub nickname: Option<String>,
         /// Attachment Id to set for avatar
         pub avatar: Option<String>,
+        /// Server-specific bio
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2000)))]
+        pub bio: Option<String>,
+        /// Attachment Id to set for server profile banner
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 128)))]
+        pub banner: Option<String>,
+        /// Server-specific pronouns
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub pronouns: Option<String>,
         /// Array of role ids
         pub roles: Option<Vec<String>>,
         /// Timestamp this member is timed out until
         pub timeout: Option<Timestamp>,
+        /// Reason for timing out this member
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 1024)))]
+        pub timeout_reason: Option<String>,
         /// Fields to remove from channel object
         #[cfg_attr(feature = "validator", validate(length(min = 1)))]
         pub remove: Option<Vec<FieldsMember>>,
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use iso8601_timestamp::Timestamp;
+use revolt_permissions::OverrideField;
+
+use super::{Category, Role, SystemMessageChannels};
+
+#[cfg(feature = "validator")]
+use validator::Validate;
+
+auto_derived!(
+    /// Server Template
+    pub struct ServerTemplate {
+        /// Template code
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub code: String,
+        /// Id of the server this template was captured from
+        pub server_id: String,
+        /// Id of the user who created this template
+        pub creator_id: String,
+
+        /// Name of the template
+        pub name: String,
+        /// Description of the template
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+
+        /// Captured server structure
+        pub structure: ServerStructure,
+        /// Time at which the structure was last captured
+        pub updated_at: Timestamp,
+    }
+
+    /// Snapshot of a server's structure
+    pub struct ServerStructure {
+        /// Default set of server and channel permissions
+        pub default_permissions: i64,
+        /// Roles, keyed by their id within the template
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                default = "HashMap::<String, Role>::new",
+                skip_serializing_if = "HashMap::<String, Role>::is_empty"
+            )
+        )]
+        pub roles: HashMap<String, Role>,
+        /// Channels in the order they appear in the server
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub channels: Vec<TemplateChannel>,
+        /// Categories for this server
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub categories: Vec<Category>,
+        /// Configuration for sending system event messages
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub system_messages: Option<SystemMessageChannels>,
+    }
+
+    /// Channel captured in a template
+    pub struct TemplateChannel {
+        /// Id of the channel within the template
+        pub id: String,
+        /// Type of channel
+        pub channel_type: TemplateChannelType,
+
+        /// Display name of the channel
+        pub name: String,
+        /// Channel description
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+
+        /// Default permissions assigned to users in this channel
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to this channel
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                default = "HashMap::<String, OverrideField>::new",
+                skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+            )
+        )]
+        pub role_permissions: HashMap<String, OverrideField>,
+        /// Whether this channel inherits permissions from its category
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub sync_permissions: bool,
+
+        /// Whether this channel is marked as not safe for work
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub nsfw: bool,
+    }
+
+    /// Type of channel captured in a template
+    pub enum TemplateChannelType {
+        Text,
+        Voice,
+    }
+
+    /// Information about new template to create
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataCreateServerTemplate {
+        /// Template name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+        /// Template description
+        #[cfg_attr(feature = "validator", validate(length(min = 0, max = 1024)))]
+        pub description: Option<String>,
+    }
+);
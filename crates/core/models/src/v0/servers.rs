@@ -1,5 +1,6 @@
 use super::{Channel, File, RE_COLOUR};
 
+use iso8601_timestamp::Timestamp;
 use revolt_permissions::{Override, OverrideField};
 use std::collections::HashMap;
 
@@ -66,6 +67,18 @@ auto_derived_partial!(
             serde(skip_serializing_if = "crate::if_false", default)
         )]
         pub nsfw: bool,
+        /// Rules new members must accept before participating
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub rules: Option<String>,
+        /// Requirements members must meet before participating
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub verification: Option<VerificationRequirements>,
+        /// Whether new members are prevented from joining
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub locked: bool,
         /// Whether to enable analytics
         #[cfg_attr(
             feature = "serde",
@@ -78,6 +91,29 @@ auto_derived_partial!(
             serde(skip_serializing_if = "crate::if_false", default)
         )]
         pub discoverable: bool,
+        /// Whether this server is featured on discovery
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub featured: bool,
+
+        /// Tags describing this server on discovery
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub tags: Vec<String>,
+        /// Category this server is listed under on discovery
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub category: Option<String>,
+        /// Primary language used in this server
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub language: Option<String>,
+
+        /// Time at which this server will be permanently deleted
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub scheduled_deletion: Option<Timestamp>,
     },
     "PartialServer"
 );
@@ -103,6 +139,24 @@ auto_derived_partial!(
         /// Ranking of this role
         #[cfg_attr(feature = "serde", serde(default))]
         pub rank: i64,
+
+        /// Icon attachment for this role
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub icon: Option<File>,
+        /// Unicode emoji shown in place of an icon
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub emoji: Option<String>,
+        /// Whether members can mention this role
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub mentionable: bool,
+        /// Number of members who have this role
+        ///
+        /// Only present when fetching a role or in the Ready payload.
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub member_count: Option<usize>,
     },
     "PartialRole"
 );
@@ -115,11 +169,45 @@ auto_derived!(
         SystemMessages,
         Icon,
         Banner,
+        Rules,
+        Category,
+        Language,
+        Verification,
+        ScheduledDeletion,
+    }
+
+    /// Requirements members must meet before participating in a server
+    ///
+    /// Members who hold any role are exempt.
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct VerificationRequirements {
+        /// Whether members must have a verified email address
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub email: bool,
+        /// Whether members must have multi-factor authentication enabled
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub mfa: bool,
+        /// Minimum age of a member's account in minutes
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 525600)))]
+        pub account_age: Option<u32>,
+        /// Minimum number of minutes since a member joined the server
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 525600)))]
+        pub membership_age: Option<u32>,
     }
 
     /// Optional fields on server object
     pub enum FieldsRole {
         Colour,
+        Icon,
+        Emoji,
     }
 
     /// Channel category
@@ -133,6 +221,103 @@ auto_derived!(
         pub title: String,
         /// Channels in this category
         pub channels: Vec<String>,
+
+        /// Default permissions assigned to users in this category
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to this category
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                default = "HashMap::<String, OverrideField>::new",
+                skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+            )
+        )]
+        pub role_permissions: HashMap<String, OverrideField>,
+    }
+
+    /// Partial representation of a category
+    #[derive(Default)]
+    pub struct PartialCategory {
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub id: Option<String>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub title: Option<String>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub channels: Option<Vec<String>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub default_permissions: Option<OverrideField>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub role_permissions: Option<HashMap<String, OverrideField>>,
+    }
+
+    /// Optional fields on category object
+    pub enum FieldsCategory {
+        DefaultPermissions,
+    }
+
+    /// Information about new category to create
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataCreateCategory {
+        /// Category title
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub title: String,
+        /// Channels to place in this category
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub channels: Vec<String>,
+    }
+
+    /// New category information
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataEditCategory {
+        /// Category title
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub title: Option<String>,
+        /// Channels in this category
+        pub channels: Option<Vec<String>>,
+        /// Fields to remove from category object
+        #[cfg_attr(feature = "validator", validate(length(min = 1)))]
+        pub remove: Option<Vec<FieldsCategory>>,
+    }
+
+    /// New default permissions for a category
+    pub struct DataDefaultCategoryPermissions {
+        /// Allow / deny values to set for members in this category
+        pub permissions: Override,
+    }
+
+    /// New category order
+    pub struct DataReorderCategories {
+        /// Category ids in the order they should be displayed
+        pub categories: Vec<String>,
+    }
+
+    /// Channels belonging to a category in a new layout
+    pub struct CategoryLayout {
+        /// Category id
+        pub id: String,
+        /// Channel ids in the order they should be displayed
+        pub channels: Vec<String>,
+    }
+
+    /// New channel layout
+    pub struct DataReorderChannels {
+        /// Channel ids in the order they should be displayed
+        ///
+        /// Every channel in the server must be listed exactly once.
+        pub channels: Option<Vec<String>>,
+        /// Categories in the order they should be displayed
+        ///
+        /// Every category in the server must be listed exactly once.
+        pub categories: Option<Vec<CategoryLayout>>,
+    }
+
+    /// New role ranking
+    pub struct DataEditRoleRanks {
+        /// Role ids from highest to lowest priority
+        ///
+        /// Roles are assigned the ranks they already hold between them in this order.
+        pub ranks: Vec<String>,
     }
 
     /// System message channel assignments
@@ -164,6 +349,9 @@ auto_derived!(
         /// Whether this server is age-restricted
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub nsfw: Option<bool>,
+        /// Code of the template to create this server from
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub template: Option<String>,
     }
 
     /// Information about new role to create
@@ -232,6 +420,14 @@ auto_derived!(
         pub categories: Option<Vec<Category>>,
         /// System message configuration
         pub system_messages: Option<SystemMessageChannels>,
+        /// Rules new members must accept before participating
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2000)))]
+        pub rules: Option<String>,
+        /// Requirements members must meet before participating
+        #[cfg_attr(feature = "validator", validate)]
+        pub verification: Option<VerificationRequirements>,
+        /// Whether new members are prevented from joining
+        pub locked: Option<bool>,
 
         /// Bitfield of server flags
         #[cfg_attr(feature = "validator", serde(skip_serializing_if = "Option::is_none"))]
@@ -246,11 +442,27 @@ auto_derived!(
         /// Must be enabled in order to show up on [Revolt Discover](https://rvlt.gg).
         pub analytics: Option<bool>,
 
+        /// Tags describing this server on discovery
+        #[cfg_attr(feature = "validator", validate(length(max = 5)))]
+        pub tags: Option<Vec<String>>,
+        /// Category this server is listed under on discovery
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub category: Option<String>,
+        /// Primary language used in this server, as a language tag
+        #[cfg_attr(feature = "validator", validate(length(min = 2, max = 16)))]
+        pub language: Option<String>,
+
         /// Fields to remove from server object
         #[cfg_attr(feature = "validator", validate(length(min = 1)))]
         pub remove: Option<Vec<FieldsServer>>,
     }
 
+    /// Server ownership transfer
+    pub struct DataTransferServerOwnership {
+        /// Id of the member to transfer ownership to
+        pub user: String,
+    }
+
     /// New role information
     #[cfg_attr(feature = "validator", derive(Validate))]
     pub struct DataEditRole {
@@ -260,11 +472,22 @@ auto_derived!(
         /// Role colour
         #[cfg_attr(
             feature = "validator",
-            validate(length(min = 1, max = 128), regex = "RE_COLOUR")
+            validate(
+                length(min = 1, max = 512),
+                regex = "RE_COLOUR",
+                custom = "validate_colour"
+            )
         )]
         pub colour: Option<String>,
         /// Whether this role should be displayed separately
         pub hoist: Option<bool>,
+        /// Attachment Id for role icon
+        pub icon: Option<String>,
+        /// Unicode emoji to show in place of an icon
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub emoji: Option<String>,
+        /// Whether members can mention this role
+        pub mentionable: Option<bool>,
         /// Ranking position
         ///
         /// Smaller values take priority.
@@ -287,3 +510,80 @@ auto_derived!(
         pub leave_silently: Option<bool>,
     }
 );
+
+/// Maximum number of colour stops in a role gradient
+#[cfg(feature = "validator")]
+const MAX_GRADIENT_STOPS: usize = 8;
+
+/// Validate that a role colour gradient is within sensible bounds
+///
+/// Gradients may have at most [`MAX_GRADIENT_STOPS`] colour stops
+/// and no stop position may exceed 100%.
+#[cfg(feature = "validator")]
+fn validate_colour(colour: &str) -> Result<(), validator::ValidationError> {
+    let Some(start) = colour.find("gradient(") else {
+        return Ok(());
+    };
+
+    let Some(inner) = colour
+        .get(start + "gradient(".len()..)
+        .and_then(|arguments| arguments.strip_suffix(')'))
+    else {
+        return Err(validator::ValidationError::new("gradient"));
+    };
+
+    // Split gradient arguments, ignoring commas inside of nested functions
+    let mut arguments = vec![];
+    let mut depth = 0;
+    let mut last = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                arguments.push(inner[last..i].trim());
+                last = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    arguments.push(inner[last..].trim());
+
+    let stops = arguments
+        .iter()
+        .filter(|argument| !argument.ends_with("deg"))
+        .count();
+
+    let positions_valid = arguments
+        .iter()
+        .filter_map(|argument| argument.rsplit_once(' '))
+        .filter_map(|(_, position)| position.strip_suffix('%'))
+        .all(|position| {
+            position
+                .parse::<u32>()
+                .is_ok_and(|position| position <= 100)
+        });
+
+    if stops > MAX_GRADIENT_STOPS || !positions_valid {
+        Err(validator::ValidationError::new("gradient"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "validator"))]
+mod tests {
+    use super::validate_colour;
+
+    #[test]
+    fn gradients() {
+        assert!(validate_colour("red").is_ok());
+        assert!(validate_colour("linear-gradient(30deg, red, blue 50%)").is_ok());
+        assert!(validate_colour("linear-gradient(red, blue 150%)").is_err());
+
+        // Unterminated gradients must be rejected rather than panic
+        assert!(validate_colour("linear-gradient(").is_err());
+        assert!(validate_colour("linear-gradient(é").is_err());
+    }
+}
@@ -1,11 +1,14 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use super::File;
+use super::{File, MemberProfile};
 
 #[cfg(feature = "validator")]
 use validator::Validate;
 
+#[cfg(feature = "rocket")]
+use rocket::FromForm;
+
 /// Regex for valid usernames
 ///
 /// Block zero width space
@@ -160,6 +163,12 @@ auto_derived!(
         /// Background visible on user's profile
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub background: Option<File>,
+        /// Server-specific profile, if fetched in the context of a server
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Option::is_none", default)
+        )]
+        pub member: Option<MemberProfile>,
     }
 
     /// User badge bitfield
@@ -202,6 +211,13 @@ auto_derived!(
         Spam = 8,
     }
 
+    /// Options for fetching a user's profile
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsFetchProfile {
+        /// Id of the server to include the user's server profile from
+        pub server: Option<String>,
+    }
+
     /// New user profile data
     #[cfg_attr(feature = "validator", derive(Validate))]
     pub struct DataUserProfile {
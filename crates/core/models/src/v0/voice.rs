@@ -0,0 +1,35 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Voice state of a user connected to a call
+    pub struct VoiceState {
+        /// Id of the user
+        pub id: String,
+        /// Id of the channel the user is connected to
+        pub channel: String,
+        /// Whether the user has been muted by a moderator
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub muted: bool,
+        /// Whether the user has been deafened by a moderator
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub deafened: bool,
+        /// Time at which the user joined the call
+        pub joined_at: Timestamp,
+    }
+
+    /// New voice state information for a member
+    pub struct DataMemberVoiceEdit {
+        /// Whether the member should be muted
+        pub muted: Option<bool>,
+        /// Whether the member should be deafened
+        pub deafened: Option<bool>,
+        /// Id of the channel to move the member to
+        pub channel: Option<String>,
+    }
+);
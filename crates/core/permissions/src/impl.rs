@@ -61,7 +61,10 @@ pub async fn calculate_server_permissions<P: PermissionQuery>(query: &mut P) ->
         permissions.apply(role_override);
     }
 
-    if query.are_we_timed_out().await {
+    if query.are_we_timed_out().await
+        || query.are_we_pending().await
+        || query.are_we_unverified().await
+    {
         permissions.restrict(*ALLOW_IN_TIMEOUT);
     }
 
@@ -114,13 +117,22 @@ pub async fn calculate_channel_permissions<P: PermissionQuery>(query: &mut P) ->
                 ChannelPermission::GrantAllSafe.into()
             } else if query.are_we_a_member().await {
                 let mut permissions = calculate_server_permissions(query).await;
+                permissions.apply(query.get_default_category_permissions().await);
+
+                for role_override in query.get_our_category_role_overrides().await {
+                    permissions.apply(role_override);
+                }
+
                 permissions.apply(query.get_default_channel_permissions().await);
 
                 for role_override in query.get_our_channel_role_overrides().await {
                     permissions.apply(role_override);
                 }
 
-                if query.are_we_timed_out().await {
+                if query.are_we_timed_out().await
+                    || query.are_we_pending().await
+                    || query.are_we_unverified().await
+                {
                     permissions.restrict(*ALLOW_IN_TIMEOUT);
                 }
 
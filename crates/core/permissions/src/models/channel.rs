@@ -31,8 +31,8 @@ pub enum ChannelPermission {
     ManageRole = 1 << 3,
     /// Manage server customisation (includes emoji)
     ManageCustomisation = 1 << 4,
-
-    // % 1 bit reserved
+    /// View the server audit log
+    ViewAuditLog = 1 << 5,
 
     // * Member permissions
     /// Kick other members below their ranking
@@ -64,10 +64,26 @@ async fn validate_user_permissions() {
             unreachable!()
         }
 
+        async fn are_we_pending(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_unverified(&mut self) -> bool {
+            unreachable!()
+        }
+
         async fn get_channel_type(&mut self) -> ChannelType {
             ChannelType::DirectMessage
         }
 
+        async fn get_default_category_permissions(&mut self) -> Override {
+            unreachable!()
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<Override> {
+            unreachable!()
+        }
+
         async fn get_default_channel_permissions(&mut self) -> Override {
             unreachable!()
         }
@@ -153,10 +169,26 @@ async fn validate_group_permissions() {
             unreachable!()
         }
 
+        async fn are_we_pending(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_unverified(&mut self) -> bool {
+            unreachable!()
+        }
+
         async fn get_channel_type(&mut self) -> ChannelType {
             ChannelType::Group
         }
 
+        async fn get_default_category_permissions(&mut self) -> Override {
+            unreachable!()
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<Override> {
+            unreachable!()
+        }
+
         async fn get_default_channel_permissions(&mut self) -> Override {
             Override {
                 allow: ChannelPermission::SendMessage as u64,
@@ -254,10 +286,26 @@ async fn validate_server_permissions() {
             false
         }
 
+        async fn are_we_pending(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_unverified(&mut self) -> bool {
+            false
+        }
+
         async fn get_channel_type(&mut self) -> ChannelType {
             ChannelType::ServerChannel
         }
 
+        async fn get_default_category_permissions(&mut self) -> Override {
+            Override { allow: 0, deny: 0 }
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<Override> {
+            vec![]
+        }
+
         async fn get_default_channel_permissions(&mut self) -> Override {
             Override {
                 allow: 0,
@@ -346,10 +394,26 @@ async fn validate_timed_out_member() {
             true
         }
 
+        async fn are_we_pending(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_unverified(&mut self) -> bool {
+            false
+        }
+
         async fn get_channel_type(&mut self) -> ChannelType {
             ChannelType::ServerChannel
         }
 
+        async fn get_default_category_permissions(&mut self) -> Override {
+            Override { allow: 0, deny: 0 }
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<Override> {
+            vec![]
+        }
+
         async fn get_default_channel_permissions(&mut self) -> Override {
             Override { allow: 0, deny: 0 }
         }
@@ -375,3 +439,329 @@ async fn validate_timed_out_member() {
         }
     }
 }
+
+#[async_std::test]
+async fn validate_pending_member() {
+    /// Scenario in which we have joined a server but not yet accepted its rules
+    struct Scenario {}
+    let mut query = Scenario {};
+
+    let perms = calculate_channel_permissions(&mut query).await;
+    let value: u64 = perms.into();
+    assert_eq!(value, *DEFAULT_PERMISSION_VIEW_ONLY);
+
+    #[async_trait]
+    impl PermissionQuery for Scenario {
+        async fn are_we_privileged(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_the_users_same(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn user_relationship(&mut self) -> RelationshipStatus {
+            unreachable!()
+        }
+
+        async fn user_is_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn have_mutual_connection(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_server_owner(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_member(&mut self) -> bool {
+            true
+        }
+
+        async fn get_default_server_permissions(&mut self) -> u64 {
+            *DEFAULT_PERMISSION_SERVER
+        }
+
+        async fn get_our_server_role_overrides(&mut self) -> Vec<Override> {
+            vec![]
+        }
+
+        async fn are_we_timed_out(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_pending(&mut self) -> bool {
+            true
+        }
+
+        async fn are_we_unverified(&mut self) -> bool {
+            false
+        }
+
+        async fn get_channel_type(&mut self) -> ChannelType {
+            ChannelType::ServerChannel
+        }
+
+        async fn get_default_category_permissions(&mut self) -> Override {
+            Override { allow: 0, deny: 0 }
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<Override> {
+            vec![]
+        }
+
+        async fn get_default_channel_permissions(&mut self) -> Override {
+            Override { allow: 0, deny: 0 }
+        }
+
+        async fn get_our_channel_role_overrides(&mut self) -> Vec<Override> {
+            vec![]
+        }
+
+        async fn do_we_own_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_part_of_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn set_recipient_as_user(&mut self) {
+            unreachable!()
+        }
+
+        async fn set_server_from_channel(&mut self) {
+            // no-op
+        }
+    }
+}
+
+#[async_std::test]
+async fn validate_unverified_member() {
+    /// Scenario in which we have joined a server but do not meet its verification requirements
+    struct Scenario {}
+    let mut query = Scenario {};
+
+    let perms = calculate_channel_permissions(&mut query).await;
+    let value: u64 = perms.into();
+    assert_eq!(value, *DEFAULT_PERMISSION_VIEW_ONLY);
+
+    #[async_trait]
+    impl PermissionQuery for Scenario {
+        async fn are_we_privileged(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_the_users_same(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn user_relationship(&mut self) -> RelationshipStatus {
+            unreachable!()
+        }
+
+        async fn user_is_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn have_mutual_connection(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_server_owner(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_member(&mut self) -> bool {
+            true
+        }
+
+        async fn get_default_server_permissions(&mut self) -> u64 {
+            *DEFAULT_PERMISSION_SERVER
+        }
+
+        async fn get_our_server_role_overrides(&mut self) -> Vec<Override> {
+            vec![]
+        }
+
+        async fn are_we_timed_out(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_pending(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_unverified(&mut self) -> bool {
+            true
+        }
+
+        async fn get_channel_type(&mut self) -> ChannelType {
+            ChannelType::ServerChannel
+        }
+
+        async fn get_default_category_permissions(&mut self) -> Override {
+            Override { allow: 0, deny: 0 }
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<Override> {
+            vec![]
+        }
+
+        async fn get_default_channel_permissions(&mut self) -> Override {
+            Override { allow: 0, deny: 0 }
+        }
+
+        async fn get_our_channel_role_overrides(&mut self) -> Vec<Override> {
+            vec![]
+        }
+
+        async fn do_we_own_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_part_of_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn set_recipient_as_user(&mut self) {
+            unreachable!()
+        }
+
+        async fn set_server_from_channel(&mut self) {
+            // no-op
+        }
+    }
+}
+
+#[async_std::test]
+async fn validate_category_permissions() {
+    /// Scenario in which we are in a server channel synced to its category where:
+    /// - the server grants reading history and sending messages by default
+    /// - the category denies sending messages
+    /// - our role in the category allows sending messages and reacting
+    /// - however the channel disallows reacting
+    struct Scenario {}
+    let mut query = Scenario {};
+
+    let perms = calculate_channel_permissions(&mut query).await;
+    let value: u64 = perms.into();
+    assert_eq!(
+        value,
+        ChannelPermission::ViewChannel as u64
+            | ChannelPermission::SendMessage as u64
+            | ChannelPermission::ReadMessageHistory as u64
+    );
+
+    #[async_trait]
+    impl PermissionQuery for Scenario {
+        async fn are_we_privileged(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_the_users_same(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn user_relationship(&mut self) -> RelationshipStatus {
+            unreachable!()
+        }
+
+        async fn user_is_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn have_mutual_connection(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_server_owner(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_member(&mut self) -> bool {
+            true
+        }
+
+        async fn get_default_server_permissions(&mut self) -> u64 {
+            ChannelPermission::ViewChannel as u64
+                | ChannelPermission::SendMessage as u64
+                | ChannelPermission::ReadMessageHistory as u64
+        }
+
+        async fn get_our_server_role_overrides(&mut self) -> Vec<Override> {
+            vec![]
+        }
+
+        async fn are_we_timed_out(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_pending(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_unverified(&mut self) -> bool {
+            false
+        }
+
+        async fn get_channel_type(&mut self) -> ChannelType {
+            ChannelType::ServerChannel
+        }
+
+        async fn get_default_category_permissions(&mut self) -> Override {
+            Override {
+                allow: 0,
+                deny: ChannelPermission::SendMessage as u64,
+            }
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<Override> {
+            vec![Override {
+                allow: ChannelPermission::SendMessage as u64 | ChannelPermission::React as u64,
+                deny: 0,
+            }]
+        }
+
+        async fn get_default_channel_permissions(&mut self) -> Override {
+            Override {
+                allow: 0,
+                deny: ChannelPermission::React as u64,
+            }
+        }
+
+        async fn get_our_channel_role_overrides(&mut self) -> Vec<Override> {
+            vec![]
+        }
+
+        async fn do_we_own_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_part_of_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn set_recipient_as_user(&mut self) {
+            unreachable!()
+        }
+
+        async fn set_server_from_channel(&mut self) {
+            // no-op
+        }
+    }
+}
@@ -39,11 +39,24 @@ pub trait PermissionQuery {
     /// Is our perspective user timed out on this server?
     async fn are_we_timed_out(&mut self) -> bool;
 
+    /// Is our perspective user yet to accept this server's rules?
+    async fn are_we_pending(&mut self) -> bool;
+
+    /// Does our perspective user fail to meet this server's verification requirements?
+    async fn are_we_unverified(&mut self) -> bool;
+
     // * For calculating channel permission
 
     /// Get the type of the channel
     async fn get_channel_type(&mut self) -> ChannelType;
 
+    /// Get the default permissions of the category this channel inherits from
+    /// (use an empty override if the channel is not synced to a category)
+    async fn get_default_category_permissions(&mut self) -> Override;
+
+    /// Get the ordered role overrides (from lowest to highest) for this member in the category this channel inherits from
+    async fn get_our_category_role_overrides(&mut self) -> Vec<Override>;
+
     /// Get the default channel permissions
     /// Group channel defaults should be mapped to an allow-only override
     async fn get_default_channel_permissions(&mut self) -> Override;
@@ -12,6 +12,9 @@ use operations::{
     __get_set_size, __remove_from_set_string, __remove_from_set_u32,
 };
 
+mod voice;
+pub use voice::*;
+
 pub static REGION_ID: Lazy<u16> = Lazy::new(|| {
     std::env::var("REGION_ID")
         .unwrap_or_else(|_| "0".to_string())
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use redis_kiss::{AsyncCommands, Conn};
 
 /// Add to set (string)
@@ -40,3 +42,20 @@ pub async fn __delete_key(conn: &mut Conn, id: &str) {
         .await
         .expect("could not delete key by id");
 }
+
+/// Set a key to expire after a number of seconds
+pub async fn __expire_key(conn: &mut Conn, key: &str, seconds: usize) {
+    let _: Option<()> = conn.expire(key, seconds).await.ok();
+}
+
+/// Set fields on a hash
+pub async fn __set_hash_fields(conn: &mut Conn, key: &str, fields: &[(&str, String)]) {
+    let _: Option<()> = conn.hset_multiple(key, fields).await.ok();
+}
+
+/// Get all fields on a hash
+pub async fn __get_hash_fields(conn: &mut Conn, key: &str) -> HashMap<String, String> {
+    conn.hgetall::<_, HashMap<String, String>>(key)
+        .await
+        .unwrap_or_default()
+}
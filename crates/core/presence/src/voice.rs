@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use redis_kiss::get_connection;
+
+use crate::operations::{
+    __add_to_set_string, __delete_key, __expire_key, __get_hash_fields,
+    __get_set_members_as_string, __remove_from_set_string, __set_hash_fields,
+};
+
+/// Number of seconds a voice state is kept for after it was last written
+///
+/// Clients which vanish without leaving are dropped from the call once this passes.
+pub const VOICE_STATE_TTL: usize = 60 * 60 * 12;
+
+/// Voice state of a user connected to a call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceState {
+    /// Id of the channel the user is connected to
+    pub channel_id: String,
+    /// Id of the user
+    pub user_id: String,
+    /// Whether the user has been muted by a moderator
+    pub muted: bool,
+    /// Whether the user has been deafened by a moderator
+    pub deafened: bool,
+    /// Time at which the user joined the call (milliseconds since epoch)
+    pub joined_at: u64,
+    /// Region of the voice node the user is connected to
+    pub region: Option<String>,
+}
+
+/// Key of the hash holding a user's voice state
+fn user_key(user_id: &str) -> String {
+    format!("voice:{user_id}")
+}
+
+/// Key of the set holding the users connected to a channel
+fn channel_key(channel_id: &str) -> String {
+    format!("voice_channel:{channel_id}")
+}
+
+impl VoiceState {
+    /// Create a new voice state for a user joining a channel now
+    pub fn new(channel_id: &str, user_id: &str) -> VoiceState {
+        VoiceState {
+            channel_id: channel_id.to_string(),
+            user_id: user_id.to_string(),
+            muted: false,
+            deafened: false,
+            joined_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time went backwards")
+                .as_millis() as u64,
+            region: None,
+        }
+    }
+
+    /// Read a voice state from stored hash fields
+    fn from_fields(user_id: &str, fields: HashMap<String, String>) -> Option<VoiceState> {
+        Some(VoiceState {
+            channel_id: fields.get("channel")?.to_string(),
+            user_id: user_id.to_string(),
+            muted: fields.get("muted").map(|v| v == "1").unwrap_or_default(),
+            deafened: fields.get("deafened").map(|v| v == "1").unwrap_or_default(),
+            joined_at: fields
+                .get("joined_at")
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            region: fields.get("region").filter(|v| !v.is_empty()).cloned(),
+        })
+    }
+}
+
+/// Store a user's voice state, returns their previous state if they had one
+pub async fn set_voice_state(state: &VoiceState) -> Option<VoiceState> {
+    if let Ok(mut conn) = get_connection().await {
+        let key = user_key(&state.user_id);
+        let previous =
+            VoiceState::from_fields(&state.user_id, __get_hash_fields(&mut conn, &key).await);
+
+        if let Some(previous) = &previous {
+            if previous.channel_id != state.channel_id {
+                __remove_from_set_string(
+                    &mut conn,
+                    &channel_key(&previous.channel_id),
+                    &state.user_id,
+                )
+                .await;
+            }
+        }
+
+        __set_hash_fields(
+            &mut conn,
+            &key,
+            &[
+                ("channel", state.channel_id.clone()),
+                ("muted", (state.muted as u8).to_string()),
+                ("deafened", (state.deafened as u8).to_string()),
+                ("joined_at", state.joined_at.to_string()),
+                ("region", state.region.clone().unwrap_or_default()),
+            ],
+        )
+        .await;
+
+        __add_to_set_string(&mut conn, &channel_key(&state.channel_id), &state.user_id).await;
+
+        __expire_key(&mut conn, &key, VOICE_STATE_TTL).await;
+        __expire_key(&mut conn, &channel_key(&state.channel_id), VOICE_STATE_TTL).await;
+
+        previous
+    } else {
+        None
+    }
+}
+
+/// Fetch a user's current voice state
+pub async fn get_voice_state(user_id: &str) -> Option<VoiceState> {
+    if let Ok(mut conn) = get_connection().await {
+        VoiceState::from_fields(
+            user_id,
+            __get_hash_fields(&mut conn, &user_key(user_id)).await,
+        )
+    } else {
+        None
+    }
+}
+
+/// Fetch the voice states of all users connected to a channel
+pub async fn get_channel_voice_states(channel_id: &str) -> Vec<VoiceState> {
+    let mut states = vec![];
+    if let Ok(mut conn) = get_connection().await {
+        for user_id in __get_set_members_as_string(&mut conn, &channel_key(channel_id)).await {
+            if let Some(state) = VoiceState::from_fields(
+                &user_id,
+                __get_hash_fields(&mut conn, &user_key(&user_id)).await,
+            ) {
+                // Ignore entries left behind by a channel change
+                if state.channel_id == channel_id {
+                    states.push(state);
+                }
+            } else {
+                // The user's state has expired
+                __remove_from_set_string(&mut conn, &channel_key(channel_id), &user_id).await;
+            }
+        }
+    }
+
+    states
+}
+
+/// Remove a user's voice state, returns the state that was removed
+pub async fn delete_voice_state(user_id: &str) -> Option<VoiceState> {
+    if let Ok(mut conn) = get_connection().await {
+        let key = user_key(user_id);
+        let previous = VoiceState::from_fields(user_id, __get_hash_fields(&mut conn, &key).await);
+
+        if let Some(previous) = &previous {
+            __remove_from_set_string(&mut conn, &channel_key(&previous.channel_id), user_id).await;
+            __delete_key(&mut conn, &key).await;
+        }
+
+        previous
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::{
+        delete_voice_state, get_channel_voice_states, get_voice_state, set_voice_state, VoiceState,
+    };
+
+    #[async_std::test]
+    async fn it_works() {
+        let user_id = rand::thread_rng().gen::<u32>().to_string();
+        let channel_id = rand::thread_rng().gen::<u32>().to_string();
+        let other_channel_id = rand::thread_rng().gen::<u32>().to_string();
+
+        // Join a channel
+        let state = VoiceState::new(&channel_id, &user_id);
+        assert!(set_voice_state(&state).await.is_none());
+        assert_eq!(get_voice_state(&user_id).await, Some(state.clone()));
+        assert_eq!(
+            get_channel_voice_states(&channel_id).await,
+            vec![state.clone()]
+        );
+
+        // Move to another channel
+        let moved = VoiceState {
+            channel_id: other_channel_id.clone(),
+            muted: true,
+            ..state.clone()
+        };
+
+        assert_eq!(set_voice_state(&moved).await, Some(state));
+        assert!(get_channel_voice_states(&channel_id).await.is_empty());
+        assert_eq!(
+            get_channel_voice_states(&other_channel_id).await,
+            vec![moved.clone()]
+        );
+
+        // Leave the call
+        assert_eq!(delete_voice_state(&user_id).await, Some(moved));
+        assert!(get_voice_state(&user_id).await.is_none());
+        assert!(get_channel_voice_states(&other_channel_id).await.is_empty());
+    }
+}
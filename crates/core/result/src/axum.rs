@@ -21,12 +21,16 @@ impl IntoResponse for Error {
             ErrorType::BlockedByOther => StatusCode::FORBIDDEN,
             ErrorType::NotFriends => StatusCode::FORBIDDEN,
             ErrorType::TooManyPendingFriendRequests { .. } => StatusCode::BAD_REQUEST,
+            ErrorType::TooManyNotificationSettings { .. } => StatusCode::BAD_REQUEST,
 
             ErrorType::UnknownChannel => StatusCode::NOT_FOUND,
             ErrorType::UnknownMessage => StatusCode::NOT_FOUND,
             ErrorType::UnknownAttachment => StatusCode::BAD_REQUEST,
             ErrorType::CannotEditMessage => StatusCode::FORBIDDEN,
             ErrorType::CannotJoinCall => StatusCode::BAD_REQUEST,
+            ErrorType::ChannelArchived => StatusCode::FORBIDDEN,
+            ErrorType::BlockedByAutomod { .. } => StatusCode::FORBIDDEN,
+            ErrorType::VoiceUnavailable => StatusCode::BAD_REQUEST,
             ErrorType::TooManyAttachments { .. } => StatusCode::BAD_REQUEST,
             ErrorType::TooManyReplies { .. } => StatusCode::BAD_REQUEST,
             ErrorType::EmptyMessage => StatusCode::UNPROCESSABLE_ENTITY,
@@ -42,6 +46,8 @@ impl IntoResponse for Error {
             ErrorType::InvalidRole => StatusCode::NOT_FOUND,
             ErrorType::Banned => StatusCode::FORBIDDEN,
             ErrorType::AlreadyInServer => StatusCode::CONFLICT,
+            ErrorType::InviteCodeTaken => StatusCode::CONFLICT,
+            ErrorType::ServerLocked => StatusCode::FORBIDDEN,
             ErrorType::CannotTimeoutYourself => StatusCode::BAD_REQUEST,
 
             ErrorType::TooManyServers { .. } => StatusCode::BAD_REQUEST,
@@ -71,7 +77,6 @@ impl IntoResponse for Error {
             ErrorType::InvalidSession => StatusCode::UNAUTHORIZED,
             ErrorType::NotAuthenticated => StatusCode::UNAUTHORIZED,
             ErrorType::DuplicateNonce => StatusCode::CONFLICT,
-            ErrorType::VosoUnavailable => StatusCode::BAD_REQUEST,
             ErrorType::NotFound => StatusCode::NOT_FOUND,
             ErrorType::NoEffect => StatusCode::OK,
             ErrorType::FailedValidation { .. } => StatusCode::BAD_REQUEST,
@@ -72,6 +72,9 @@ pub enum ErrorType {
     TooManyPendingFriendRequests {
         max: usize,
     },
+    TooManyNotificationSettings {
+        max: usize,
+    },
 
     // ? Channel related errors
     UnknownChannel,
@@ -79,6 +82,11 @@ pub enum ErrorType {
     UnknownMessage,
     CannotEditMessage,
     CannotJoinCall,
+    ChannelArchived,
+    BlockedByAutomod {
+        rule: String,
+    },
+    VoiceUnavailable,
     TooManyAttachments {
         max: usize,
     },
@@ -116,6 +124,8 @@ pub enum ErrorType {
         max: usize,
     },
     AlreadyInServer,
+    InviteCodeTaken,
+    ServerLocked,
     CannotTimeoutYourself,
 
     // ? Bot related errors
@@ -165,9 +175,6 @@ pub enum ErrorType {
     FileTypeNotAllowed,
     ImageProcessingFailed,
     NoEmbedData,
-
-    // ? Legacy errors
-    VosoUnavailable,
 }
 
 #[macro_export]
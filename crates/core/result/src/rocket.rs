@@ -26,12 +26,16 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::BlockedByOther => Status::Forbidden,
             ErrorType::NotFriends => Status::Forbidden,
             ErrorType::TooManyPendingFriendRequests { .. } => Status::BadRequest,
+            ErrorType::TooManyNotificationSettings { .. } => Status::BadRequest,
 
             ErrorType::UnknownChannel => Status::NotFound,
             ErrorType::UnknownMessage => Status::NotFound,
             ErrorType::UnknownAttachment => Status::BadRequest,
             ErrorType::CannotEditMessage => Status::Forbidden,
             ErrorType::CannotJoinCall => Status::BadRequest,
+            ErrorType::ChannelArchived => Status::Forbidden,
+            ErrorType::BlockedByAutomod { .. } => Status::Forbidden,
+            ErrorType::VoiceUnavailable => Status::BadRequest,
             ErrorType::TooManyAttachments { .. } => Status::BadRequest,
             ErrorType::TooManyReplies { .. } => Status::BadRequest,
             ErrorType::EmptyMessage => Status::UnprocessableEntity,
@@ -47,6 +51,8 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::InvalidRole => Status::NotFound,
             ErrorType::Banned => Status::Forbidden,
             ErrorType::AlreadyInServer => Status::Conflict,
+            ErrorType::InviteCodeTaken => Status::Conflict,
+            ErrorType::ServerLocked => Status::Forbidden,
             ErrorType::CannotTimeoutYourself => Status::BadRequest,
 
             ErrorType::TooManyServers { .. } => Status::BadRequest,
@@ -76,7 +82,6 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::InvalidSession => Status::Unauthorized,
             ErrorType::NotAuthenticated => Status::Unauthorized,
             ErrorType::DuplicateNonce => Status::Conflict,
-            ErrorType::VosoUnavailable => Status::BadRequest,
             ErrorType::NotFound => Status::NotFound,
             ErrorType::NoEffect => Status::Ok,
             ErrorType::FailedValidation { .. } => Status::BadRequest,
@@ -1,7 +1,15 @@
-use revolt_config::configure;
-use revolt_database::DatabaseInfo;
+use amqprs::{
+    channel::ExchangeDeclareArguments,
+    connection::{Connection, OpenConnectionArguments},
+};
+use revolt_config::{config, configure};
+use revolt_database::{DatabaseInfo, AMQP};
 use revolt_result::Result;
-use tasks::{file_deletion, prune_dangling_files};
+use tasks::{
+    destroy_deleted_servers, expire_timeouts, file_deletion, lift_expired_bans, prune_audit_logs,
+    prune_dangling_files, prune_expired_invites, remind_server_events, resume_role_assignment_jobs,
+    rollup_server_insights, run_prune_jobs, sample_online_members,
+};
 use tokio::try_join;
 
 pub mod tasks;
@@ -11,9 +19,47 @@ async fn main() -> Result<()> {
     configure!(crond);
 
     let db = DatabaseInfo::Auto.connect().await.expect("database");
+
+    // Configure Rabbit
+    let config = config().await;
+    let connection = Connection::open(&OpenConnectionArguments::new(
+        &config.rabbit.host,
+        config.rabbit.port,
+        &config.rabbit.username,
+        &config.rabbit.password,
+    ))
+    .await
+    .expect("Failed to connect to RabbitMQ");
+
+    let channel = connection
+        .open_channel(None)
+        .await
+        .expect("Failed to open RabbitMQ channel");
+
+    channel
+        .exchange_declare(
+            ExchangeDeclareArguments::new(&config.pushd.exchange, "direct")
+                .durable(true)
+                .finish(),
+        )
+        .await
+        .expect("Failed to declare exchange");
+
+    let amqp = AMQP::new(connection, channel);
+
     try_join!(
+        destroy_deleted_servers::task(db.clone()),
+        expire_timeouts::task(db.clone()),
         file_deletion::task(db.clone()),
-        prune_dangling_files::task(db)
+        lift_expired_bans::task(db.clone()),
+        prune_dangling_files::task(db.clone()),
+        prune_audit_logs::task(db.clone()),
+        prune_expired_invites::task(db.clone()),
+        remind_server_events::task(db.clone(), amqp),
+        resume_role_assignment_jobs::task(db.clone()),
+        rollup_server_insights::task(db.clone()),
+        run_prune_jobs::task(db.clone()),
+        sample_online_members::task(db)
     )
     .map(|_| ())
 }
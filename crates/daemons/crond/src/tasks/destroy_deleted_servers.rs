@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use log::{error, info};
+use revolt_database::Database;
+use revolt_result::Result;
+use tokio::time::sleep;
+
+pub async fn task(db: Database) -> Result<()> {
+    loop {
+        let servers: Vec<_> = db
+            .fetch_servers_scheduled_for_deletion()
+            .await?
+            .into_iter()
+            .filter(|server| server.is_deletion_due())
+            .collect();
+
+        for server in servers {
+            let id = server.id.clone();
+            match server.destroy(&db).await {
+                Ok(()) => info!("Destroyed deleted server {id}"),
+                Err(err) => error!("Failed to destroy deleted server {id}: {err:?}"),
+            }
+        }
+
+        sleep(Duration::from_secs(60 * 60)).await;
+    }
+}
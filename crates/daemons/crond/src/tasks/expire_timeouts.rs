@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use log::info;
+use revolt_database::{iso8601_timestamp::Timestamp, Database};
+use revolt_result::Result;
+use tokio::time::sleep;
+
+pub async fn task(db: Database) -> Result<()> {
+    loop {
+        let members = db.fetch_expired_timeouts(Timestamp::now_utc()).await?;
+
+        if !members.is_empty() {
+            let count = members.len();
+            for mut member in members {
+                member.expire_timeout(&db).await?;
+            }
+
+            info!("Cleared {count} expired timeouts");
+        }
+
+        sleep(Duration::from_secs(15)).await;
+    }
+}
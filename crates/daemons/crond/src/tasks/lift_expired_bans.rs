@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use log::info;
+use revolt_database::{iso8601_timestamp::Timestamp, Database};
+use revolt_result::Result;
+use tokio::time::sleep;
+
+pub async fn task(db: Database) -> Result<()> {
+    loop {
+        let bans = db.fetch_expired_bans(Timestamp::now_utc()).await?;
+
+        if !bans.is_empty() {
+            let count = bans.len();
+            for ban in bans {
+                ban.delete(&db).await?;
+            }
+
+            info!("Lifted {count} expired bans");
+        }
+
+        sleep(Duration::from_secs(60)).await;
+    }
+}
@@ -1,2 +1,12 @@
+pub mod destroy_deleted_servers;
+pub mod expire_timeouts;
 pub mod file_deletion;
+pub mod lift_expired_bans;
+pub mod prune_audit_logs;
 pub mod prune_dangling_files;
+pub mod prune_expired_invites;
+pub mod remind_server_events;
+pub mod resume_role_assignment_jobs;
+pub mod rollup_server_insights;
+pub mod run_prune_jobs;
+pub mod sample_online_members;
@@ -0,0 +1,25 @@
+use std::time::{Duration, SystemTime};
+
+use log::info;
+use revolt_config::config;
+use revolt_database::Database;
+use revolt_result::Result;
+use tokio::time::sleep;
+use ulid::Ulid;
+
+pub async fn task(db: Database) -> Result<()> {
+    loop {
+        let config = config().await;
+        let retention =
+            Duration::from_secs(config.features.advanced.audit_log_retention_days * 24 * 60 * 60);
+
+        // Entry ids are ULIDs, so anything sorting below this id has expired
+        if let Some(cutoff) = SystemTime::now().checked_sub(retention) {
+            let id = Ulid::from_datetime(cutoff).to_string();
+            db.delete_audit_log_entries_before(&id).await?;
+            info!("Pruned audit log entries older than {id}");
+        }
+
+        sleep(Duration::from_secs(60 * 60)).await;
+    }
+}
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use log::info;
+use revolt_database::{iso8601_timestamp::Timestamp, Database};
+use revolt_result::Result;
+use tokio::time::sleep;
+
+/// Number of invites to delete at once
+const BATCH_SIZE: i64 = 100;
+
+pub async fn task(db: Database) -> Result<()> {
+    loop {
+        loop {
+            let codes: Vec<String> = db
+                .fetch_expired_invites(Timestamp::now_utc(), BATCH_SIZE)
+                .await?
+                .into_iter()
+                .map(|invite| invite.code().to_string())
+                .collect();
+
+            if codes.is_empty() {
+                break;
+            }
+
+            db.delete_invites(&codes).await?;
+            info!("Deleted {} expired invites", codes.len());
+
+            if (codes.len() as i64) < BATCH_SIZE {
+                break;
+            }
+        }
+
+        sleep(Duration::from_secs(60)).await;
+    }
+}
@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use log::info;
+use revolt_database::{Database, ServerEvent, AMQP};
+use revolt_result::Result;
+use tokio::time::sleep;
+
+pub async fn task(db: Database, amqp: AMQP) -> Result<()> {
+    loop {
+        let events = ServerEvent::fetch_due_for_reminder(&db).await?;
+
+        if !events.is_empty() {
+            let count = events.len();
+            for mut event in events {
+                event.remind(&db, &amqp).await?;
+            }
+
+            info!("Sent reminders for {count} server events");
+        }
+
+        sleep(Duration::from_secs(60)).await;
+    }
+}
@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use log::{error, info};
+use revolt_database::{
+    iso8601_timestamp::{self, Timestamp},
+    Database,
+};
+use revolt_result::Result;
+use tokio::time::sleep;
+
+pub async fn task(db: Database) -> Result<()> {
+    loop {
+        // Running jobs which have not recorded progress recently were orphaned by their runner
+        if let Some(before) =
+            Timestamp::now_utc().checked_sub(iso8601_timestamp::Duration::minutes(5))
+        {
+            for job in db.fetch_stale_role_assignment_jobs(before).await? {
+                let id = job.id.clone();
+                match job.resume(&db).await {
+                    Ok(()) => info!("Resumed role assignment job {id}"),
+                    Err(err) => error!("Failed to resume role assignment job {id}: {err:?}"),
+                }
+            }
+        }
+
+        sleep(Duration::from_secs(60)).await;
+    }
+}
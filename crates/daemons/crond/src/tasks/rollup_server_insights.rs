@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use log::info;
+use revolt_database::{Database, ServerInsights};
+use revolt_result::Result;
+use tokio::time::sleep;
+
+pub async fn task(db: Database) -> Result<()> {
+    loop {
+        let servers = db.fetch_servers_with_analytics().await?;
+        let today = ServerInsights::today();
+
+        for server in &servers {
+            // Yesterday is recounted so that its final hour is not lost
+            ServerInsights::rollup_activity(&db, server, today - 1).await?;
+            ServerInsights::rollup_activity(&db, server, today).await?;
+        }
+
+        if !servers.is_empty() {
+            info!("Rolled up insights for {} servers", servers.len());
+        }
+
+        sleep(Duration::from_secs(60 * 60)).await;
+    }
+}
@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use log::{error, info};
+use revolt_database::Database;
+use revolt_result::Result;
+use tokio::time::sleep;
+
+pub async fn task(db: Database) -> Result<()> {
+    loop {
+        // Jobs left running by a previous instance are resumed from their last batch
+        for job in db.fetch_unfinished_prune_jobs().await? {
+            let id = job.id.clone();
+            match job.run(&db).await {
+                Ok(()) => info!("Finished prune job {id}"),
+                Err(err) => error!("Failed to run prune job {id}: {err:?}"),
+            }
+        }
+
+        sleep(Duration::from_secs(5)).await;
+    }
+}
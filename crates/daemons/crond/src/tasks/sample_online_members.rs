@@ -0,0 +1,15 @@
+use std::time::Duration;
+
+use revolt_database::{Database, ServerInsights};
+use revolt_result::Result;
+use tokio::time::sleep;
+
+pub async fn task(db: Database) -> Result<()> {
+    loop {
+        for server in db.fetch_servers_with_analytics().await? {
+            ServerInsights::sample_online(&db, &server).await?;
+        }
+
+        sleep(Duration::from_secs(5 * 60)).await;
+    }
+}
@@ -68,22 +68,16 @@ impl AsyncConsumer for GenericConsumer {
         content: Vec<u8>,
     ) {
         let content = String::from_utf8(content).unwrap();
-        let payload: MessageSentPayload = serde_json::from_str(content.as_str()).unwrap();
+        let payload: GenericPayload = serde_json::from_str(content.as_str()).unwrap();
 
-        debug!("Received message event on origin");
+        debug!("Received generic event");
 
-        if let Ok(sessions) = self
-            .authifier_db
-            .find_sessions_with_subscription(&payload.users)
-            .await
-        {
+        if let Ok(sessions) = self.authifier_db.find_sessions(&payload.user.id).await {
             let config = revolt_config::config().await;
             for session in sessions {
                 if let Some(sub) = session.subscription {
                     let mut sendable = PayloadToService {
-                        notification: PayloadKind::MessageNotification(
-                            payload.notification.clone(),
-                        ),
+                        notification: PayloadKind::Generic(payload.clone()),
                         token: sub.auth,
                         user_id: session.user_id,
                         session_id: session.id,
@@ -9,10 +9,9 @@ use amqprs::{
 };
 use async_trait::async_trait;
 use log::debug;
-use revolt_database::{events::rabbit::*, Database};
+use revolt_database::{events::rabbit::*, Database, NotificationSettings};
 
 pub struct MessageConsumer {
-    #[allow(dead_code)]
     db: Database,
     authifier_db: authifier::Database,
     conn: Option<Connection>,
@@ -72,9 +71,26 @@ impl AsyncConsumer for MessageConsumer {
 
         debug!("Received message event on origin");
 
+        // Settings may have changed since the message was queued
+        let channel_info = &payload.notification.channel;
+        let users = NotificationSettings::filter_push_recipients(
+            &self.db,
+            channel_info.server(),
+            channel_info.id(),
+            payload
+                .notification
+                .message
+                .mentions
+                .as_deref()
+                .unwrap_or_default(),
+            payload.users.clone(),
+        )
+        .await
+        .unwrap_or(payload.users);
+
         if let Ok(sessions) = self
             .authifier_db
-            .find_sessions_with_subscription(&payload.users)
+            .find_sessions_with_subscription(&users)
             .await
         {
             let config = revolt_config::config().await;
@@ -0,0 +1,43 @@
+use revolt_database::{Bot, BotDirectoryQuery, Database};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Browse Bot Directory
+///
+/// Search public bots which are listed on the directory.
+#[openapi(tag = "Bots")]
+#[get("/directory?<options..>")]
+pub async fn browse_directory(
+    db: &State<Database>,
+    options: v0::OptionsBotDirectory,
+) -> Result<Json<v0::BotDirectoryResponse>> {
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let query = BotDirectoryQuery {
+        query: options.query,
+        tags: options
+            .tags
+            .into_iter()
+            .map(|tag| tag.trim().to_lowercase())
+            .collect(),
+        verified: options.verified.unwrap_or_default(),
+        offset: options.offset,
+        limit: options.limit,
+    };
+
+    let (bots, total) = Bot::directory(db, &query).await?;
+
+    Ok(Json(v0::BotDirectoryResponse {
+        bots: bots
+            .into_iter()
+            .map(|(bot, user, server_count)| bot.into_directory_bot(user, server_count))
+            .collect(),
+        total: total as i64,
+    }))
+}
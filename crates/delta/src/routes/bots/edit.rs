@@ -37,6 +37,12 @@ pub async fn edit_bot(
     if data.public.is_none()
         && data.analytics.is_none()
         && data.interactions_url.is_none()
+        && data.discoverable.is_none()
+        && data.tags.is_none()
+        && data.short_description.is_none()
+        && data.long_description.is_none()
+        && data.terms_of_service_url.is_none()
+        && data.privacy_policy_url.is_none()
         && data.remove.is_none()
     {
         return Ok(Json(v0::BotWithUserResponse {
@@ -49,14 +55,38 @@ pub async fn edit_bot(
         public,
         analytics,
         interactions_url,
+        discoverable,
+        tags,
+        short_description,
+        long_description,
+        terms_of_service_url,
+        privacy_policy_url,
         remove,
         ..
     } = data;
 
+    let tags = tags.map(|tags| {
+        let mut normalised: Vec<String> = vec![];
+        for tag in tags {
+            let tag = tag.trim().to_lowercase();
+            if !tag.is_empty() && !normalised.contains(&tag) {
+                normalised.push(tag);
+            }
+        }
+
+        normalised
+    });
+
     let partial = PartialBot {
         public,
         analytics,
         interactions_url,
+        discoverable,
+        tags,
+        short_description,
+        long_description,
+        terms_of_service_url,
+        privacy_policy_url,
         ..Default::default()
     };
 
@@ -0,0 +1,35 @@
+use revolt_database::{util::reference::Reference, Database, PartialBot, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Edit Bot Listing
+///
+/// Remove a bot from, or restore it to, the directory.
+///
+/// This is only available to privileged users.
+#[openapi(tag = "Bots")]
+#[patch("/<target>/listing", data = "<data>")]
+pub async fn edit_listing(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataEditBotListing>,
+) -> Result<Json<v0::Bot>> {
+    if !user.privileged {
+        return Err(create_error!(NotPrivileged));
+    }
+
+    let mut bot = target.as_bot(db).await?;
+    bot.update(
+        db,
+        PartialBot {
+            delisted: Some(data.delisted),
+            ..Default::default()
+        },
+        vec![],
+    )
+    .await?;
+
+    Ok(Json(bot.into()))
+}
@@ -3,11 +3,16 @@ use rocket::Route;
 
 mod create;
 mod delete;
+mod directory;
 mod edit;
 mod fetch;
 mod fetch_owned;
 mod fetch_public;
 mod invite;
+mod listing_edit;
+mod verification_list;
+mod verification_request;
+mod verification_review;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
     openapi_get_routes_spec![
@@ -18,5 +23,10 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         fetch_owned::fetch_owned_bots,
         edit::edit_bot,
         delete::delete_bot,
+        directory::browse_directory,
+        verification_request::request_verification,
+        verification_list::fetch_pending_verifications,
+        verification_review::review_verification,
+        listing_edit::edit_listing,
     ]
 }
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use revolt_database::{BotVerificationStatus, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Pending Verifications
+///
+/// Fetch bots waiting for verification.
+///
+/// This is only available to privileged users.
+#[openapi(tag = "Bots")]
+#[get("/verification")]
+pub async fn fetch_pending_verifications(
+    db: &State<Database>,
+    user: User,
+) -> Result<Json<Vec<v0::DirectoryBot>>> {
+    if !user.privileged {
+        return Err(create_error!(NotPrivileged));
+    }
+
+    let bots = db
+        .fetch_bots_by_verification(&BotVerificationStatus::Pending)
+        .await?;
+
+    let ids: Vec<String> = bots.iter().map(|bot| bot.id.clone()).collect();
+    let mut users: HashMap<String, User> = db
+        .fetch_users(&ids)
+        .await?
+        .into_iter()
+        .map(|user| (user.id.clone(), user))
+        .collect();
+
+    let counts = db.fetch_server_counts(&ids).await?;
+
+    Ok(Json(
+        bots.into_iter()
+            .filter_map(|bot| {
+                let user = users.remove(&bot.id)?;
+                let count = counts.get(&bot.id).copied().unwrap_or_default();
+                Some(bot.into_directory_bot(user, count))
+            })
+            .collect(),
+    ))
+}
@@ -0,0 +1,47 @@
+use revolt_database::{
+    util::reference::Reference, BotVerificationStatus, Database, PartialBot, User,
+};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Request Bot Verification
+///
+/// Ask platform moderators to verify a bot you own.
+///
+/// The bot must be public and have terms of service.
+#[openapi(tag = "Bots")]
+#[post("/<target>/verification")]
+pub async fn request_verification(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<v0::Bot>> {
+    let mut bot = target.as_bot(db).await?;
+    if bot.owner != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    if bot.is_verified()
+        || matches!(
+            bot.verification,
+            Some(BotVerificationStatus::Pending) | Some(BotVerificationStatus::Approved)
+        )
+        || !bot.public
+        || bot.terms_of_service_url.is_empty()
+    {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    bot.update(
+        db,
+        PartialBot {
+            verification: Some(BotVerificationStatus::Pending),
+            ..Default::default()
+        },
+        vec![],
+    )
+    .await?;
+
+    Ok(Json(bot.into()))
+}
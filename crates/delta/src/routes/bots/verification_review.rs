@@ -0,0 +1,45 @@
+use revolt_database::{
+    util::reference::Reference, BotVerificationStatus, Database, PartialBot, User,
+};
+use revolt_models::v0::{self, BotFlags};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Review Bot Verification
+///
+/// Approve or reject a pending verification request.
+///
+/// This is only available to privileged users.
+#[openapi(tag = "Bots")]
+#[put("/<target>/verification", data = "<data>")]
+pub async fn review_verification(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataReviewBotVerification>,
+) -> Result<Json<v0::Bot>> {
+    if !user.privileged {
+        return Err(create_error!(NotPrivileged));
+    }
+
+    let mut bot = target.as_bot(db).await?;
+    if bot.verification != Some(BotVerificationStatus::Pending) {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    let partial = if data.approve {
+        PartialBot {
+            verification: Some(BotVerificationStatus::Approved),
+            flags: Some(bot.flags.unwrap_or_default() | BotFlags::Verified as i32),
+            ..Default::default()
+        }
+    } else {
+        PartialBot {
+            verification: Some(BotVerificationStatus::Rejected),
+            ..Default::default()
+        }
+    };
+
+    bot.update(db, partial, vec![]).await?;
+    Ok(Json(bot.into()))
+}
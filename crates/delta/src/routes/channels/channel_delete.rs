@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Channel, Database, PartialChannel, User, AMQP,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Channel, Database, PartialChannel, User, AMQP,
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
@@ -18,6 +18,7 @@ pub async fn delete(
     amqp: &State<AMQP>,
     user: User,
     target: Reference,
+    reason: AuditLogReason,
     options: v0::OptionsChannelDelete,
 ) -> Result<EmptyResponse> {
     let mut channel = target.as_channel(db).await?;
@@ -51,7 +52,22 @@ pub async fn delete(
             .map(|_| EmptyResponse),
         Channel::TextChannel { .. } | Channel::VoiceChannel { .. } => {
             permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
-            channel.delete(db).await.map(|_| EmptyResponse)
+            channel.delete(db).await?;
+
+            if let Some(server) = channel.server() {
+                AuditLogEntry::record(
+                    db,
+                    server,
+                    &user.id,
+                    AuditLogAction::ChannelDelete,
+                    Some(channel.id()),
+                    AuditLogChange::diff(Some(&channel), None),
+                    reason.into_inner(),
+                )
+                .await;
+            }
+
+            Ok(EmptyResponse)
         }
     }
 }
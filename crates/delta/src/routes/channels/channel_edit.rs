@@ -1,6 +1,7 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Channel, Database, File, PartialChannel, SystemMessage, User, AMQP,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Channel, Database, File, PartialChannel,
+    SystemMessage, User, AMQP,
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
@@ -18,6 +19,7 @@ pub async fn edit(
     amqp: &State<AMQP>,
     user: User,
     target: Reference,
+    reason: AuditLogReason,
     data: Json<v0::DataEditChannel>,
 ) -> Result<Json<v0::Channel>> {
     let data = data.into_inner();
@@ -29,20 +31,27 @@ pub async fn edit(
 
     let mut channel = target.as_channel(db).await?;
     let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
-    calculate_channel_permissions(&mut query)
-        .await
-        .throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
 
     if data.name.is_none()
         && data.description.is_none()
         && data.icon.is_none()
         && data.nsfw.is_none()
+        && data.archived.is_none()
         && data.owner.is_none()
+        && data.sync_permissions.is_none()
         && data.remove.is_none()
     {
         return Ok(Json(channel.into()));
     }
 
+    // Changing permission inheritance requires manage permissions
+    if data.sync_permissions.is_some() {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
+    }
+
+    let before = channel.clone();
     let mut partial: PartialChannel = Default::default();
 
     // Transfer group ownership
@@ -152,6 +161,22 @@ pub async fn edit(
                 partial.nsfw = Some(new_nsfw);
             }
 
+            if let Some(sync_permissions) = data.sync_permissions {
+                if let Channel::Group { .. } = &channel {
+                    return Err(create_error!(InvalidOperation));
+                }
+
+                partial.sync_permissions = Some(sync_permissions);
+            }
+
+            if let Some(archived) = data.archived {
+                if !matches!(&channel, Channel::TextChannel { .. }) {
+                    return Err(create_error!(InvalidOperation));
+                }
+
+                partial.archived = Some(archived);
+            }
+
             // Send out mutation system messages.
             if let Channel::Group { .. } = &channel {
                 if let Some(name) = &partial.name {
@@ -225,5 +250,18 @@ pub async fn edit(
         _ => return Err(create_error!(InvalidOperation)),
     };
 
+    if let Some(server) = channel.server() {
+        AuditLogEntry::record(
+            db,
+            server,
+            &user.id,
+            AuditLogAction::ChannelUpdate,
+            Some(channel.id()),
+            AuditLogChange::diff(Some(&before), Some(&channel)),
+            reason.into_inner(),
+        )
+        .await;
+    }
+
     Ok(Json(channel.into()))
 }
@@ -1,4 +1,5 @@
 use revolt_database::{
+    iso8601_timestamp::{Duration, Timestamp},
     util::{permissions::DatabasePermissionQuery, reference::Reference},
     Database, Invite, User,
 };
@@ -7,6 +8,7 @@ use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
 
 use revolt_result::{create_error, Result};
 use rocket::{serde::json::Json, State};
+use validator::Validate;
 
 /// # Create Invite
 ///
@@ -14,24 +16,43 @@ use rocket::{serde::json::Json, State};
 ///
 /// Channel must be a `TextChannel`.
 #[openapi(tag = "Channel Invites")]
-#[post("/<target>/invites")]
+#[post("/<target>/invites", data = "<data>")]
 pub async fn create_invite(
     db: &State<Database>,
     user: User,
     target: Reference,
+    data: Option<Json<v0::DataCreateInvite>>,
 ) -> Result<Json<v0::Invite>> {
     if user.bot.is_some() {
         return Err(create_error!(IsBot));
     }
 
+    let data = data.map(Json::into_inner).unwrap_or_default();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
     let channel = target.as_channel(db).await?;
     let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
     calculate_channel_permissions(&mut query)
         .await
         .throw_if_lacking_channel_permission(ChannelPermission::InviteOthers)?;
 
-    Invite::create_channel_invite(db, &user, &channel)
-        .await
-        .map(|invite| invite.into())
-        .map(Json)
+    let expires_at = data
+        .max_age
+        .and_then(|max_age| Timestamp::now_utc().checked_add(Duration::seconds(max_age as i64)));
+
+    Invite::create_channel_invite(
+        db,
+        &user,
+        &channel,
+        data.max_uses,
+        expires_at,
+        data.temporary,
+    )
+    .await
+    .map(|invite| invite.into())
+    .map(Json)
 }
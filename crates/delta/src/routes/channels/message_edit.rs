@@ -43,6 +43,13 @@ pub async fn edit(
 
     permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
 
+    // Archived channels only accept changes from moderators
+    if channel.is_archived()
+        && !permissions.has_channel_permission(ChannelPermission::ManageChannel)
+    {
+        return Err(create_error!(ChannelArchived));
+    }
+
     let mut message = msg.as_message_in_channel(db, channel.id()).await?;
     if message.author != user.id {
         return Err(create_error!(CannotEditMessage));
@@ -3,7 +3,7 @@ use revolt_database::{
     Database, User,
 };
 use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
-use revolt_result::Result;
+use revolt_result::{create_error, Result};
 use rocket::State;
 use rocket_empty::EmptyResponse;
 
@@ -21,9 +21,15 @@ pub async fn react_message(
 ) -> Result<EmptyResponse> {
     let channel = target.as_channel(db).await?;
     let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
-    calculate_channel_permissions(&mut query)
-        .await
-        .throw_if_lacking_channel_permission(ChannelPermission::React)?;
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::React)?;
+
+    // Archived channels only accept changes from moderators
+    if channel.is_archived()
+        && !permissions.has_channel_permission(ChannelPermission::ManageChannel)
+    {
+        return Err(create_error!(ChannelArchived));
+    }
 
     // Fetch relevant message
     let message = msg.as_message_in_channel(db, channel.id()).await?;
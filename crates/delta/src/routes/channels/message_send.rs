@@ -38,6 +38,13 @@ pub async fn message_send(
     let permissions = calculate_channel_permissions(&mut query).await;
     permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
 
+    // Archived channels only accept changes from moderators
+    if channel.is_archived()
+        && !permissions.has_channel_permission(ChannelPermission::ManageChannel)
+    {
+        return Err(create_error!(ChannelArchived));
+    }
+
     // Verify permissions for masquerade
     if let Some(masq) = &data.masquerade {
         permissions.throw_if_lacking_channel_permission(ChannelPermission::Masquerade)?;
@@ -121,6 +128,7 @@ mod test {
     };
     use revolt_models::v0::{self, DataCreateServerChannel};
     use revolt_permissions::{ChannelPermission, OverrideField};
+    use rocket::http::{ContentType, Header, Status};
 
     #[rocket::async_test]
     async fn message_mention_constraints() {
@@ -161,6 +169,9 @@ mod test {
             colour: None,
             hoist: false,
             rank: 5,
+            icon: None,
+            emoji: None,
+            mentionable: false,
         };
 
         let role_id = role
@@ -190,6 +201,8 @@ mod test {
                 a: 0,
                 d: ChannelPermission::ViewChannel as i64,
             }),
+            sync_permissions: None,
+            archived: None,
             last_message_id: None,
         };
         locked_channel
@@ -283,8 +296,16 @@ mod test {
             joined_at: None,
             nickname: None,
             avatar: None,
+            bio: None,
+            banner: None,
+            pronouns: None,
             timeout: None,
+            timeout_reason: None,
+            timeout_moderator: None,
             roles: Some(second_member_roles),
+            pending: None,
+            invite: None,
+            temporary: None,
         };
         second_member
             .update(&harness.db, partial, vec![])
@@ -323,4 +344,122 @@ mod test {
             "Mention was scrubbed when the user can see the channel"
         );
     }
+
+    #[rocket::async_test]
+    async fn archived_channel_rejects_members() {
+        let harness = TestHarness::new().await;
+        let (_, _, owner) = harness.new_user().await;
+        let (_, session, user) = harness.new_user().await;
+
+        let (server, channels) = Server::create(
+            &harness.db,
+            v0::DataCreateServer {
+                name: "Test Server".to_string(),
+                ..Default::default()
+            },
+            &owner,
+            true,
+        )
+        .await
+        .expect("Failed to create test server");
+
+        let (owner_member, _) =
+            Member::create(&harness.db, &server, &owner, Some(channels.clone()))
+                .await
+                .expect("Failed to create owner member");
+        Member::create(&harness.db, &server, &user, Some(channels.clone()))
+            .await
+            .expect("Failed to create member");
+
+        let mut channel = channels[0].clone();
+        let message = Message::create_from_api(
+            &harness.db,
+            None,
+            channel.clone(),
+            v0::DataMessageSend {
+                content: Some("Test message".to_string()),
+                nonce: None,
+                attachments: None,
+                replies: None,
+                embeds: None,
+                masquerade: None,
+                interactions: None,
+                flags: None,
+            },
+            v0::MessageAuthor::User(&owner.clone().into(&harness.db, Some(&owner)).await),
+            Some(owner.clone().into(&harness.db, Some(&owner)).await),
+            Some(owner_member.into()),
+            owner.limits().await,
+            IdempotencyKey::unchecked_from_string("0".to_string()),
+            false,
+            false,
+        )
+        .await
+        .expect("Failed to create message");
+
+        channel
+            .update(
+                &harness.db,
+                PartialChannel {
+                    archived: Some(true),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to archive channel");
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/messages", channel.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataMessageSend {
+                    content: Some("Test message".to_string()),
+                    nonce: None,
+                    attachments: None,
+                    replies: None,
+                    embeds: None,
+                    masquerade: None,
+                    interactions: None,
+                    flags: None,
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        drop(response);
+
+        let response = harness
+            .client
+            .put(format!(
+                "/channels/{}/messages/{}/reactions/{}",
+                channel.id(),
+                message.id,
+                server.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        drop(response);
+
+        let response = harness
+            .client
+            .delete(format!(
+                "/channels/{}/messages/{}/reactions/{}",
+                channel.id(),
+                message.id,
+                server.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+    }
 }
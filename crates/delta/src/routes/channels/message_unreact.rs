@@ -4,7 +4,7 @@ use revolt_database::{
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
-use revolt_result::Result;
+use revolt_result::{create_error, Result};
 use rocket::State;
 use rocket_empty::EmptyResponse;
 
@@ -29,6 +29,13 @@ pub async fn unreact_message(
 
     permissions.throw_if_lacking_channel_permission(ChannelPermission::React)?;
 
+    // Archived channels only accept changes from moderators
+    if channel.is_archived()
+        && !permissions.has_channel_permission(ChannelPermission::ManageChannel)
+    {
+        return Err(create_error!(ChannelArchived));
+    }
+
     // Check if we need to escalate permissions
     let remove_all = options.remove_all.unwrap_or_default();
     if options.user_id.is_some() || remove_all {
@@ -25,6 +25,7 @@ mod message_unreact;
 mod permissions_set;
 mod permissions_set_default;
 mod voice_join;
+mod voice_leave;
 mod webhook_create;
 mod webhook_fetch_all;
 
@@ -49,6 +50,7 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         group_add_member::add_member,
         group_remove_member::remove_member,
         voice_join::call,
+        voice_leave::call,
         permissions_set::set_role_permissions,
         permissions_set_default::set_default_permissions,
         message_react::react_message,
@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Channel, Database, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Channel, Database, User,
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_channel_permissions, ChannelPermission, Override};
@@ -19,6 +19,7 @@ pub async fn set_role_permissions(
     db: &State<Database>,
     user: User,
     target: Reference,
+    reason: AuditLogReason,
     role_id: String,
     data: Json<v0::DataSetRolePermissions>,
 ) -> Result<Json<v0::Channel>> {
@@ -39,10 +40,22 @@ pub async fn set_role_permissions(
                 .throw_permission_override(current_value, &data.permissions)
                 .await?;
 
+            let before = channel.clone();
             channel
                 .set_role_permission(db, &role_id, data.permissions.clone().into())
                 .await?;
 
+            AuditLogEntry::record(
+                db,
+                &server.id,
+                &user.id,
+                AuditLogAction::ChannelUpdate,
+                Some(channel.id()),
+                AuditLogChange::diff(Some(&before), Some(&channel)),
+                reason.into_inner(),
+            )
+            .await;
+
             Ok(Json(channel.into()))
         } else {
             Err(create_error!(NotFound))
@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Channel, Database, PartialChannel, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Channel, Database, PartialChannel, User,
 };
 use revolt_models::v0::{self, DataDefaultChannelPermissions};
 use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
@@ -18,6 +18,7 @@ pub async fn set_default_permissions(
     db: &State<Database>,
     user: User,
     target: Reference,
+    reason: AuditLogReason,
     data: Json<v0::DataDefaultChannelPermissions>,
 ) -> Result<Json<v0::Channel>> {
     let data = data.into_inner();
@@ -28,6 +29,7 @@ pub async fn set_default_permissions(
 
     permissions.throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
 
+    let before = channel.clone();
     match &channel {
         Channel::Group { .. } => {
             if let DataDefaultChannelPermissions::Value { permissions } = data {
@@ -75,5 +77,18 @@ pub async fn set_default_permissions(
         _ => return Err(create_error!(InvalidOperation)),
     }
 
+    if let Some(server) = channel.server() {
+        AuditLogEntry::record(
+            db,
+            server,
+            &user.id,
+            AuditLogAction::ChannelUpdate,
+            Some(channel.id()),
+            AuditLogChange::diff(Some(&before), Some(&channel)),
+            reason.into_inner(),
+        )
+        .await;
+    }
+
     Ok(Json(channel.into()))
 }
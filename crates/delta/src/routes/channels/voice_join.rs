@@ -1,10 +1,11 @@
-use revolt_config::config;
 use revolt_database::{
     util::{permissions::DatabasePermissionQuery, reference::Reference},
+    voice::{update_voice_state, voice_backend, VoiceGrants},
     Channel, Database, User,
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_presence::{get_voice_state, VoiceState};
 use revolt_result::{create_error, Result};
 use rocket::{serde::json::Json, State};
 
@@ -12,22 +13,17 @@ use rocket::{serde::json::Json, State};
 ///
 /// Asks the voice server for a token to join the call.
 #[openapi(tag = "Voice")]
-#[post("/<target>/join_call")]
+#[post("/<target>/join_call?<options..>")]
 pub async fn call(
     db: &State<Database>,
     user: User,
     target: Reference,
-) -> Result<Json<v0::LegacyCreateVoiceUserResponse>> {
+    options: v0::OptionsJoinCall,
+) -> Result<Json<v0::CreateVoiceUserResponse>> {
     let channel = target.as_channel(db).await?;
     let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
-    calculate_channel_permissions(&mut query)
-        .await
-        .throw_if_lacking_channel_permission(ChannelPermission::Connect)?;
-
-    let config = config().await;
-    if config.api.security.voso_legacy_token.is_empty() {
-        return Err(create_error!(VosoUnavailable));
-    }
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::Connect)?;
 
     match channel {
         Channel::SavedMessages { .. } | Channel::TextChannel { .. } => {
@@ -36,70 +32,34 @@ pub async fn call(
         _ => {}
     }
 
-    // To join a call:
-    // - Check if the room exists.
-    // - If not, create it.
-    let client = reqwest::Client::new();
-    let result = client
-        .get(&format!(
-            "{}/room/{}",
-            config.hosts.voso_legacy,
-            channel.id()
-        ))
-        .header(
-            reqwest::header::AUTHORIZATION,
-            config.api.security.voso_legacy_token.clone(),
-        )
-        .send()
-        .await;
-
-    match result {
-        Err(_) => return Err(create_error!(VosoUnavailable)),
-        Ok(result) => match result.status() {
-            reqwest::StatusCode::OK => (),
-            reqwest::StatusCode::NOT_FOUND => {
-                if (client
-                    .post(&format!(
-                        "{}/room/{}",
-                        config.hosts.voso_legacy,
-                        channel.id()
-                    ))
-                    .header(
-                        reqwest::header::AUTHORIZATION,
-                        config.api.security.voso_legacy_token.clone(),
-                    )
-                    .send()
-                    .await)
-                    .is_err()
-                {
-                    return Err(create_error!(VosoUnavailable));
-                }
-            }
-            _ => return Err(create_error!(VosoUnavailable)),
-        },
-    }
-
-    // Then create a user for the room.
-    if let Ok(response) = client
-        .post(&format!(
-            "{}/room/{}/user/{}",
-            config.hosts.voso_legacy,
-            channel.id(),
-            user.id
-        ))
-        .header(
-            reqwest::header::AUTHORIZATION,
-            config.api.security.voso_legacy_token,
-        )
-        .send()
-        .await
-    {
-        response
-            .json()
+    // Carry over moderator mute and deafen across calls within the same server
+    let mut state = VoiceState::new(channel.id(), &user.id);
+    if let (Some(previous), Some(server)) = (get_voice_state(&user.id).await, channel.server()) {
+        if db
+            .fetch_channel(&previous.channel_id)
             .await
-            .map_err(|_| create_error!(InvalidOperation))
-            .map(Json)
-    } else {
-        Err(create_error!(VosoUnavailable))
+            .is_ok_and(|previous_channel| previous_channel.server() == Some(server))
+        {
+            state.muted = previous.muted;
+            state.deafened = previous.deafened;
+        }
     }
+
+    let mut grants = VoiceGrants::from_permissions(channel.id(), &permissions);
+    grants.apply_voice_state(&state);
+
+    let token = voice_backend()
+        .await?
+        .create_token(&user.id, grants, options.region.as_deref())
+        .await?;
+
+    state.region = Some(token.region.clone());
+
+    update_voice_state(db, &channel, state).await;
+
+    Ok(Json(v0::CreateVoiceUserResponse {
+        token: token.token,
+        url: token.url,
+        region: token.region,
+    }))
 }
@@ -0,0 +1,22 @@
+use revolt_database::{util::reference::Reference, voice::disconnect_voice_state, Database, User};
+use revolt_presence::get_voice_state;
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Leave Call
+///
+/// Disconnect from the call in this channel.
+#[openapi(tag = "Voice")]
+#[post("/<target>/leave_call")]
+pub async fn call(db: &State<Database>, user: User, target: Reference) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+
+    match get_voice_state(&user.id).await {
+        Some(state) if state.channel_id == channel.id() => {
+            disconnect_voice_state(db, &user.id).await;
+            Ok(EmptyResponse)
+        }
+        _ => Err(create_error!(NotFound)),
+    }
+}
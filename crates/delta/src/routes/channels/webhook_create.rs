@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Channel, Database, File, User, Webhook,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Channel, Database, File, User, Webhook,
 };
 use revolt_models::v0;
 use revolt_permissions::{
@@ -20,6 +20,7 @@ pub async fn create_webhook(
     db: &State<Database>,
     user: User,
     target: Reference,
+    reason: AuditLogReason,
     data: Json<v0::CreateWebhookBody>,
 ) -> Result<Json<v0::Webhook>> {
     let data = data.into_inner();
@@ -59,5 +60,18 @@ pub async fn create_webhook(
 
     webhook.create(db).await?;
 
+    if let Some(server) = channel.server() {
+        AuditLogEntry::record(
+            db,
+            server,
+            &webhook.creator_id,
+            AuditLogAction::WebhookCreate,
+            Some(&webhook.id),
+            AuditLogChange::diff(None, Some(&webhook)),
+            reason.into_inner(),
+        )
+        .await;
+    }
+
     Ok(Json(webhook.into()))
 }
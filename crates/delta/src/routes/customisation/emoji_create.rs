@@ -1,5 +1,8 @@
 use revolt_config::config;
-use revolt_database::{util::permissions::DatabasePermissionQuery, Database, Emoji, File, User};
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, Emoji, EmojiParent, File, User,
+};
 use revolt_models::v0;
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
 use revolt_result::{create_error, Result};
@@ -16,6 +19,7 @@ pub async fn create_emoji(
     db: &State<Database>,
     user: User,
     id: String,
+    reason: AuditLogReason,
     data: Json<v0::DataCreateEmoji>,
 ) -> Result<Json<v0::Emoji>> {
     let config = config().await;
@@ -69,5 +73,19 @@ pub async fn create_emoji(
 
     // Save emoji
     emoji.create(db).await?;
+
+    if let EmojiParent::Server { id: server } = &emoji.parent {
+        AuditLogEntry::record(
+            db,
+            server,
+            &emoji.creator_id,
+            AuditLogAction::EmojiCreate,
+            Some(&emoji.id),
+            AuditLogChange::diff(None, Some(&emoji)),
+            reason.into_inner(),
+        )
+        .await;
+    }
+
     Ok(Json(emoji.into()))
 }
@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, EmojiParent, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, EmojiParent, User,
 };
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
 use revolt_result::{create_error, Result};
@@ -17,6 +17,7 @@ pub async fn delete_emoji(
     db: &State<Database>,
     user: User,
     emoji_id: Reference,
+    reason: AuditLogReason,
 ) -> Result<EmptyResponse> {
     // Bots cannot manage emoji
     if user.bot.is_some() {
@@ -44,5 +45,22 @@ pub async fn delete_emoji(
     }
 
     // Delete the emoji
-    emoji.delete(db).await.map(|_| EmptyResponse)
+    let changes = AuditLogChange::diff(Some(&emoji), None);
+    let (emoji_id, parent) = (emoji.id.clone(), emoji.parent.clone());
+    emoji.delete(db).await?;
+
+    if let EmojiParent::Server { id: server } = &parent {
+        AuditLogEntry::record(
+            db,
+            server,
+            &user.id,
+            AuditLogAction::EmojiDelete,
+            Some(&emoji_id),
+            changes,
+            reason.into_inner(),
+        )
+        .await;
+    }
+
+    Ok(EmptyResponse)
 }
@@ -0,0 +1,41 @@
+use revolt_database::{util::reference::Reference, Database, PartialServer, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Edit Discovery Listing
+///
+/// Feature, list or delist a server on discovery.
+///
+/// This is only available to privileged users.
+#[openapi(tag = "Discover")]
+#[patch("/servers/<target>", data = "<data>")]
+pub async fn edit_listing(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataEditDiscoveryListing>,
+) -> Result<Json<v0::Server>> {
+    if !user.privileged {
+        return Err(create_error!(NotPrivileged));
+    }
+
+    let data = data.into_inner();
+    let mut server = target.as_server(db).await?;
+
+    let mut partial = PartialServer {
+        discoverable: data.discoverable,
+        featured: data.featured,
+        ..Default::default()
+    };
+
+    // Delisted servers can no longer be featured
+    if data.discoverable == Some(false) {
+        partial.featured = Some(false);
+    } else if data.featured == Some(true) && !server.discoverable {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    server.update(db, partial, vec![]).await?;
+    Ok(Json(server.into()))
+}
@@ -0,0 +1,12 @@
+use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
+use rocket::Route;
+
+mod listing_edit;
+mod servers_discover;
+
+pub fn routes() -> (Vec<Route>, OpenApi) {
+    openapi_get_routes_spec![
+        servers_discover::discover_servers,
+        listing_edit::edit_listing,
+    ]
+}
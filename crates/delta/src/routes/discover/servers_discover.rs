@@ -0,0 +1,60 @@
+use revolt_database::{Database, DiscoveryQuery, Server};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Discover Servers
+///
+/// Search servers which are listed on discovery.
+#[openapi(tag = "Discover")]
+#[get("/servers?<options..>")]
+pub async fn discover_servers(
+    db: &State<Database>,
+    options: v0::OptionsDiscoverServers,
+) -> Result<Json<v0::DiscoveryResponse>> {
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let query = DiscoveryQuery {
+        query: options.query,
+        tags: options
+            .tags
+            .into_iter()
+            .map(|tag| tag.trim().to_lowercase())
+            .collect(),
+        category: options.category,
+        language: options.language,
+        featured: options.featured.unwrap_or_default(),
+        include_nsfw: options.include_nsfw.unwrap_or_default(),
+        sort: options.sort.unwrap_or_default(),
+        offset: options.offset,
+        limit: options.limit,
+    };
+
+    let (servers, total) = Server::discover(db, &query).await?;
+
+    Ok(Json(v0::DiscoveryResponse {
+        servers: servers
+            .into_iter()
+            .map(|(server, member_count)| v0::DiscoveryServer {
+                id: server.id,
+                name: server.name,
+                description: server.description,
+                icon: server.icon.map(Into::into),
+                banner: server.banner.map(Into::into),
+                flags: server.flags.unwrap_or_default() as u32,
+                nsfw: server.nsfw,
+                featured: server.featured,
+                tags: server.tags,
+                category: server.category,
+                language: server.language,
+                member_count: member_count as i64,
+            })
+            .collect(),
+        total: total as i64,
+    }))
+}
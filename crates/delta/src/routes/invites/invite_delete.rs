@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, Invite, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, Invite, User,
 };
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
 use revolt_result::Result;
@@ -10,26 +10,50 @@ use rocket_empty::EmptyResponse;
 /// # Delete Invite
 ///
 /// Delete an invite by its id.
+///
+/// Vanity invites always require permission to manage the server.
 #[openapi(tag = "Invites")]
 #[delete("/<target>")]
-pub async fn delete(db: &State<Database>, user: User, target: Reference) -> Result<EmptyResponse> {
+pub async fn delete(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+) -> Result<EmptyResponse> {
     let invite = target.as_invite(db).await?;
 
-    if user.id == invite.creator() {
-        db.delete_invite(invite.code()).await
-    } else {
-        match invite {
-            Invite::Server { code, server, .. } => {
-                let server = db.fetch_server(&server).await?;
+    let vanity = matches!(invite, Invite::Server { vanity: true, .. });
+    if vanity || user.id != invite.creator() {
+        match &invite {
+            Invite::Server { server, .. } => {
+                let server = db.fetch_server(server).await?;
                 let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
                 calculate_server_permissions(&mut query)
                     .await
                     .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
-
-                db.delete_invite(&code).await
             }
             _ => unreachable!(),
         }
     }
-    .map(|_| EmptyResponse)
+
+    db.delete_invite(invite.code()).await?;
+
+    if let Invite::Server { code, server, .. } = &invite {
+        AuditLogEntry::record(
+            db,
+            server,
+            &user.id,
+            if vanity {
+                AuditLogAction::VanityInviteDelete
+            } else {
+                AuditLogAction::InviteDelete
+            },
+            Some(code),
+            AuditLogChange::diff(Some(&invite), None),
+            reason.into_inner(),
+        )
+        .await;
+    }
+
+    Ok(EmptyResponse)
 }
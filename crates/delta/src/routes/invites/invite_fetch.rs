@@ -1,4 +1,6 @@
-use revolt_database::{util::reference::Reference, Channel, Database, Invite};
+use revolt_database::{
+    util::reference::Reference, Channel, Database, InsightsCounter, Invite, ServerInsights,
+};
 use revolt_models::v0;
 use revolt_result::Result;
 use rocket::{serde::json::Json, State};
@@ -11,10 +13,12 @@ use rocket::{serde::json::Json, State};
 pub async fn fetch(db: &State<Database>, target: Reference) -> Result<Json<v0::InviteResponse>> {
     Ok(Json(match target.as_invite(db).await? {
         Invite::Server {
-            channel, creator, ..
+            channel,
+            creator,
+            vanity,
+            ..
         } => {
             let channel = db.fetch_channel(&channel).await?;
-            let user = db.fetch_user(&creator).await?;
 
             match channel {
                 Channel::TextChannel {
@@ -32,6 +36,17 @@ pub async fn fetch(db: &State<Database>, target: Reference) -> Result<Json<v0::I
                     ..
                 } => {
                     let server = db.fetch_server(&server).await?;
+                    ServerInsights::record(
+                        db,
+                        &server,
+                        InsightsCounter::InviteView(target.id.clone()),
+                    )
+                    .await;
+
+                    // Vanity invites belong to the server rather than whoever set them up
+                    let user = db
+                        .fetch_user(if vanity { &server.owner } else { &creator })
+                        .await?;
 
                     v0::InviteResponse::Server {
                         code: target.id,
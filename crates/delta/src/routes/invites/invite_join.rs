@@ -24,7 +24,20 @@ pub async fn join(
     match &invite {
         Invite::Server { server, .. } => {
             let server = db.fetch_server(server).await?;
-            let (_, channels) = Member::create(db, &server, &user, None).await?;
+            if server.locked {
+                return Err(create_error!(ServerLocked));
+            }
+
+            // Claim a use up front so concurrent joins can't exceed the limit
+            db.claim_invite_use(invite.code()).await?;
+            let (_, channels) =
+                match Member::create_with_invite(db, &server, &user, None, Some(&invite)).await {
+                    Ok(result) => result,
+                    Err(error) => {
+                        db.release_invite_use(invite.code()).await.ok();
+                        return Err(error);
+                    }
+                };
 
             Ok(Json(InviteJoinResponse::Server {
                 channels: channels.into_iter().map(|c| c.into()).collect(),
@@ -35,7 +48,12 @@ pub async fn join(
             channel, creator, ..
         } => {
             let mut channel = db.fetch_channel(channel).await?;
-            channel.add_user_to_group(db, amqp, &user, creator).await?;
+            db.claim_invite_use(invite.code()).await?;
+            if let Err(error) = channel.add_user_to_group(db, amqp, &user, creator).await {
+                db.release_invite_use(invite.code()).await.ok();
+                return Err(error);
+            }
+
             if let Channel::Group { recipients, .. } = &channel {
                 Ok(Json(InviteJoinResponse::Group {
                     users: User::fetch_many_ids_as_mutuals(db, &user, recipients).await?,
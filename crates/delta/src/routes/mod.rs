@@ -7,6 +7,7 @@ use rocket::{Build, Rocket};
 mod bots;
 mod channels;
 mod customisation;
+mod discover;
 mod invites;
 mod onboard;
 mod push;
@@ -14,6 +15,7 @@ mod root;
 mod safety;
 mod servers;
 mod sync;
+mod templates;
 mod users;
 mod webhooks;
 
@@ -30,6 +32,8 @@ pub fn mount(config: Settings, mut rocket: Rocket<Build>) -> Rocket<Build> {
             "/channels" => channels::routes(),
             "/servers" => servers::routes(),
             "/invites" => invites::routes(),
+            "/discover" => discover::routes(),
+            "/templates" => templates::routes(),
             "/custom" => customisation::routes(),
             "/safety" => safety::routes(),
             "/auth/account" => rocket_authifier::routes::account::routes(),
@@ -50,6 +54,8 @@ pub fn mount(config: Settings, mut rocket: Rocket<Build>) -> Rocket<Build> {
             "/channels" => channels::routes(),
             "/servers" => servers::routes(),
             "/invites" => invites::routes(),
+            "/discover" => discover::routes(),
+            "/templates" => templates::routes(),
             "/custom" => customisation::routes(),
             "/safety" => safety::routes(),
             "/auth/account" => rocket_authifier::routes::account::routes(),
@@ -71,6 +77,8 @@ pub fn mount(config: Settings, mut rocket: Rocket<Build>) -> Rocket<Build> {
             "/channels" => channels::routes(),
             "/servers" => servers::routes(),
             "/invites" => invites::routes(),
+            "/discover" => discover::routes(),
+            "/templates" => templates::routes(),
             "/custom" => customisation::routes(),
             "/safety" => safety::routes(),
             "/auth/account" => rocket_authifier::routes::account::routes(),
@@ -91,6 +99,8 @@ pub fn mount(config: Settings, mut rocket: Rocket<Build>) -> Rocket<Build> {
             "/channels" => channels::routes(),
             "/servers" => servers::routes(),
             "/invites" => invites::routes(),
+            "/discover" => discover::routes(),
+            "/templates" => templates::routes(),
             "/custom" => customisation::routes(),
             "/safety" => safety::routes(),
             "/auth/account" => rocket_authifier::routes::account::routes(),
@@ -158,7 +168,11 @@ fn custom_openapi_spec() -> OpenApi {
             "tags": [
               "Server Information",
               "Server Members",
-              "Server Permissions"
+              "Server Permissions",
+              "Server Categories",
+              "Server Templates",
+              "Server Events",
+              "Automod"
             ]
           },
           {
@@ -167,6 +181,12 @@ fn custom_openapi_spec() -> OpenApi {
               "Invites"
             ]
           },
+          {
+            "name": "Discover",
+            "tags": [
+              "Discover"
+            ]
+          },
           {
             "name": "Customisation",
             "tags": [
@@ -323,11 +343,36 @@ fn custom_openapi_spec() -> OpenApi {
                 description: Some("Manage permissions for servers".to_owned()),
                 ..Default::default()
             },
+            Tag {
+                name: "Server Categories".to_owned(),
+                description: Some("Organise and manage channel categories".to_owned()),
+                ..Default::default()
+            },
+            Tag {
+                name: "Server Templates".to_owned(),
+                description: Some("Capture and reuse server structures".to_owned()),
+                ..Default::default()
+            },
+            Tag {
+                name: "Server Events".to_owned(),
+                description: Some("Schedule events and track who is interested".to_owned()),
+                ..Default::default()
+            },
+            Tag {
+                name: "Automod".to_owned(),
+                description: Some("Configure automatic moderation rules".to_owned()),
+                ..Default::default()
+            },
             Tag {
                 name: "Invites".to_owned(),
                 description: Some("View, join and delete invites".to_owned()),
                 ..Default::default()
             },
+            Tag {
+                name: "Discover".to_owned(),
+                description: Some("Find public servers to join".to_owned()),
+                ..Default::default()
+            },
             Tag {
                 name: "Account".to_owned(),
                 description: Some("Manage your account".to_owned()),
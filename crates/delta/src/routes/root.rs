@@ -21,15 +21,24 @@ pub struct Feature {
     pub url: String,
 }
 
+/// # Voice Node Information
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct VoiceNode {
+    /// Region this node serves
+    pub region: String,
+    /// URL pointing to the voice server
+    pub url: String,
+}
+
 /// # Voice Server Configuration
 #[derive(Serialize, JsonSchema, Debug)]
 pub struct VoiceFeature {
     /// Whether voice is enabled
     pub enabled: bool,
-    /// URL pointing to the voice API
-    pub url: String,
-    /// URL pointing to the voice WebSocket server
-    pub ws: String,
+    /// Region used if the client does not request one
+    pub default_region: String,
+    /// Voice servers available to clients
+    pub nodes: Vec<VoiceNode>,
 }
 
 /// # Feature Configuration
@@ -46,7 +55,7 @@ pub struct RevoltFeatures {
     /// Proxy service configuration
     pub january: Feature,
     /// Voice server configuration
-    pub voso: VoiceFeature,
+    pub voice: VoiceFeature,
 }
 
 /// # Build Information
@@ -106,10 +115,19 @@ pub async fn root() -> Result<Json<RevoltConfig>> {
                 enabled: !config.hosts.january.is_empty(),
                 url: config.hosts.january,
             },
-            voso: VoiceFeature {
-                enabled: !config.hosts.voso_legacy.is_empty(),
-                url: config.hosts.voso_legacy,
-                ws: config.hosts.voso_legacy_ws,
+            voice: VoiceFeature {
+                enabled: !config.api.voice.nodes.is_empty(),
+                default_region: config.api.voice.default_region,
+                nodes: config
+                    .api
+                    .voice
+                    .nodes
+                    .into_iter()
+                    .map(|(region, node)| VoiceNode {
+                        region,
+                        url: node.url,
+                    })
+                    .collect(),
             },
         },
         ws: config.hosts.events,
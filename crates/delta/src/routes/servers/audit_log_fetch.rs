@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogQuery, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Fetch Audit Log
+///
+/// Fetch entries from the server audit log, newest first.
+#[openapi(tag = "Server Information")]
+#[get("/<target>/audit_log?<options..>")]
+pub async fn fetch_audit_log(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    options: v0::OptionsFetchAuditLog,
+) -> Result<Json<v0::AuditLogResult>> {
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewAuditLog)?;
+
+    let entries = db
+        .fetch_audit_log_entries(
+            &server.id,
+            AuditLogQuery {
+                actor: options.actor,
+                action: options.action.map(Into::into),
+                target: options.target,
+                before: options.before,
+                after: options.after,
+                limit: options.limit,
+            },
+        )
+        .await?;
+
+    let user_ids: Vec<String> = entries
+        .iter()
+        .map(|entry| entry.actor.clone())
+        .collect::<HashSet<String>>()
+        .into_iter()
+        .collect();
+
+    Ok(Json(v0::AuditLogResult {
+        users: User::fetch_many_ids_as_mutuals(db, &user, &user_ids).await?,
+        entries: entries.into_iter().map(Into::into).collect(),
+    }))
+}
@@ -0,0 +1,63 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, AutomodRule, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Create Automod Rule
+///
+/// Create a new automod rule for this server.
+#[openapi(tag = "Automod")]
+#[post("/<target>/automod", data = "<data>")]
+pub async fn create_automod_rule(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    data: Json<v0::DataCreateAutomodRule>,
+) -> Result<Json<v0::AutomodRule>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let rule = AutomodRule {
+        id: Ulid::new().to_string(),
+        server: server.id.clone(),
+        name: data.name,
+        enabled: data.enabled.unwrap_or(true),
+        trigger: data.trigger.into(),
+        actions: data.actions.into_iter().map(Into::into).collect(),
+        exempt_roles: data.exempt_roles,
+        exempt_channels: data.exempt_channels,
+    };
+
+    rule.validate_targets(&server)?;
+    rule.create(db).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::AutomodRuleCreate,
+        Some(&rule.id),
+        AuditLogChange::diff(None, Some(&rule)),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(rule.into()))
+}
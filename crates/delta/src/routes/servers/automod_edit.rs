@@ -0,0 +1,73 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, PartialAutomodRule, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Automod Rule
+///
+/// Edit an automod rule by its id.
+#[openapi(tag = "Automod")]
+#[patch("/<target>/automod/<rule_id>", data = "<data>")]
+pub async fn edit_automod_rule(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    rule_id: String,
+    data: Json<v0::DataEditAutomodRule>,
+) -> Result<Json<v0::AutomodRule>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let mut rule = db.fetch_automod_rule(&rule_id).await?;
+    if rule.server != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    let partial = PartialAutomodRule {
+        name: data.name,
+        enabled: data.enabled,
+        trigger: data.trigger.map(Into::into),
+        actions: data
+            .actions
+            .map(|actions| actions.into_iter().map(Into::into).collect()),
+        exempt_roles: data.exempt_roles,
+        exempt_channels: data.exempt_channels,
+        ..Default::default()
+    };
+
+    let before = rule.clone();
+    let mut updated = rule.clone();
+    updated.apply_options(partial.clone());
+    updated.validate_targets(&server)?;
+
+    rule.update(db, partial).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::AutomodRuleUpdate,
+        Some(&rule.id),
+        AuditLogChange::diff(Some(&before), Some(&rule)),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(rule.into()))
+}
@@ -0,0 +1,30 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Automod Rules
+///
+/// Fetch all automod rules configured for this server.
+#[openapi(tag = "Automod")]
+#[get("/<target>/automod")]
+pub async fn fetch_automod_rules(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<Vec<v0::AutomodRule>>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    db.fetch_automod_rules(&server.id)
+        .await
+        .map(|rules| rules.into_iter().map(Into::into).collect())
+        .map(Json)
+}
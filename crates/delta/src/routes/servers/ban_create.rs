@@ -1,6 +1,8 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, RemovalIntention, ServerBan, User,
+    iso8601_timestamp::{Duration, Timestamp},
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogEntry, Database, ModerationAction, ModerationRecord, RemovalIntention,
+    ServerBan, User,
 };
 use revolt_models::v0;
 
@@ -22,6 +24,7 @@ pub async fn ban(
     user: User,
     server: Reference,
     target: Reference,
+    reason: AuditLogReason,
     data: Json<v0::DataBanCreate>,
 ) -> Result<Json<v0::ServerBan>> {
     let data = data.into_inner();
@@ -59,8 +62,42 @@ pub async fn ban(
             .await?;
     }
 
-    ServerBan::create(db, &server, &target.id, data.reason)
-        .await
-        .map(Into::into)
-        .map(Json)
+    let expires_at = data
+        .duration
+        .and_then(|duration| Timestamp::now_utc().checked_add(Duration::seconds(duration as i64)));
+
+    let ban = ServerBan::create(
+        db,
+        &server,
+        &target.id,
+        &user.id,
+        data.reason.clone(),
+        expires_at,
+    )
+    .await?;
+
+    let reason = reason.into_inner().or(data.reason);
+    ModerationRecord::create(
+        db,
+        &server.id,
+        &target.id,
+        Some(&user.id),
+        ModerationAction::Ban,
+        reason.clone(),
+        expires_at,
+    )
+    .await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::MemberBan,
+        Some(&target.id),
+        vec![],
+        reason,
+    )
+    .await;
+
+    Ok(Json(ban.into()))
 }
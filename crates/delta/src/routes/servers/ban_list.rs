@@ -5,27 +5,37 @@ use revolt_database::{Database, User};
 use revolt_models::v0;
 
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
-use revolt_result::Result;
+use revolt_result::{create_error, Result};
 use rocket::serde::json::Json;
 use rocket::State;
+use validator::Validate;
 
 /// # Fetch Bans
 ///
-/// Fetch all bans on a server.
+/// Fetch bans on a server, ordered by user id.
 #[openapi(tag = "Server Members")]
-#[get("/<target>/bans")]
+#[get("/<target>/bans?<options..>")]
 pub async fn list(
     db: &State<Database>,
     user: User,
     target: Reference,
+    options: v0::OptionsFetchBans,
 ) -> Result<Json<v0::BanListResult>> {
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
     let server = target.as_server(db).await?;
     let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
     calculate_server_permissions(&mut query)
         .await
         .throw_if_lacking_channel_permission(ChannelPermission::BanMembers)?;
 
-    let bans = db.fetch_bans(&server.id).await?;
+    let bans = db
+        .fetch_bans(&server.id, options.after, options.limit.unwrap_or(100))
+        .await?;
     let users = join_all(
         db.fetch_users(
             &bans
@@ -45,3 +55,78 @@ pub async fn list(
         bans: bans.into_iter().map(Into::into).collect(),
     }))
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Server, ServerBan};
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+
+    #[rocket::async_test]
+    async fn paginate_bans() {
+        let harness = TestHarness::new().await;
+        let (_, session, owner) = harness.new_user().await;
+
+        let (server, _) = Server::create(
+            &harness.db,
+            v0::DataCreateServer {
+                name: "Test Server".to_string(),
+                ..Default::default()
+            },
+            &owner,
+            false,
+        )
+        .await
+        .expect("Failed to create test server");
+
+        let mut banned = vec![];
+        for _ in 0..3 {
+            let (_, _, user) = harness.new_user().await;
+            ServerBan::create(&harness.db, &server, &user.id, &owner.id, None, None)
+                .await
+                .expect("Failed to ban user");
+            banned.push(user.id);
+        }
+
+        banned.sort();
+
+        let response = harness
+            .client
+            .get(format!("/servers/{}/bans?limit=2", server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let page: v0::BanListResult = response.into_json().await.expect("`BanListResult`");
+        let ids: Vec<String> = page.bans.into_iter().map(|ban| ban.id.user).collect();
+        assert_eq!(ids, banned[..2]);
+        assert_eq!(page.users.len(), 2);
+
+        let response = harness
+            .client
+            .get(format!(
+                "/servers/{}/bans?limit=2&after={}",
+                server.id, banned[1]
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let page: v0::BanListResult = response.into_json().await.expect("`BanListResult`");
+        let ids: Vec<String> = page.bans.into_iter().map(|ban| ban.id.user).collect();
+        assert_eq!(ids, banned[2..]);
+
+        // Page sizes are validated
+        let response = harness
+            .client
+            .get(format!("/servers/{}/bans?limit=0", server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+}
@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogEntry, Database, User,
 };
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
 use revolt_result::Result;
@@ -17,6 +17,7 @@ pub async fn unban(
     user: User,
     server: Reference,
     target: Reference,
+    reason: AuditLogReason,
 ) -> Result<EmptyResponse> {
     let server = server.as_server(db).await?;
     let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
@@ -25,5 +26,19 @@ pub async fn unban(
         .throw_if_lacking_channel_permission(ChannelPermission::BanMembers)?;
 
     let ban = target.as_ban(db, &server.id).await?;
-    db.delete_ban(&ban.id).await.map(|_| EmptyResponse)
+    let target = ban.id.user.clone();
+    ban.delete(db).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::MemberUnban,
+        Some(&target),
+        vec![],
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(EmptyResponse)
 }
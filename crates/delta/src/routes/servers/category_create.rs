@@ -0,0 +1,63 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Category, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Create Category
+///
+/// Create a new channel category in this server.
+#[openapi(tag = "Server Categories")]
+#[post("/<target>/categories", data = "<data>")]
+pub async fn create_category(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    data: Json<v0::DataCreateCategory>,
+) -> Result<Json<v0::Category>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    let category = Category {
+        id: Ulid::new().to_string(),
+        title: data.title,
+        channels: data
+            .channels
+            .into_iter()
+            .filter(|id| server.channels.contains(id))
+            .collect(),
+        default_permissions: None,
+        role_permissions: Default::default(),
+    };
+
+    server.create_category(db, category.clone()).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::CategoryCreate,
+        Some(&category.id),
+        AuditLogChange::diff(None, Some(&category)),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(category.into()))
+}
@@ -0,0 +1,47 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, User,
+};
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Delete Category
+///
+/// Delete a channel category by its id, channels within it become uncategorised.
+#[openapi(tag = "Server Categories")]
+#[delete("/<target>/categories/<category_id>")]
+pub async fn delete_category(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    category_id: String,
+) -> Result<EmptyResponse> {
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    let category = server
+        .category(&category_id)
+        .cloned()
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    server.delete_category(db, &category_id).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::CategoryDelete,
+        Some(&category.id),
+        AuditLogChange::diff(Some(&category), None),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(EmptyResponse)
+}
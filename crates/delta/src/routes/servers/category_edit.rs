@@ -0,0 +1,86 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, PartialCategory, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Category
+///
+/// Edit a channel category by its id.
+#[openapi(tag = "Server Categories")]
+#[patch("/<target>/categories/<category_id>", data = "<data>")]
+pub async fn edit_category(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    category_id: String,
+    data: Json<v0::DataEditCategory>,
+) -> Result<Json<v0::Category>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    let permissions = calculate_server_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    let v0::DataEditCategory {
+        title,
+        channels,
+        remove,
+    } = data;
+
+    // Removing default permissions requires manage permissions
+    if remove.is_some() {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
+    }
+
+    let partial = PartialCategory {
+        title,
+        channels: channels.map(|channels| {
+            channels
+                .into_iter()
+                .filter(|id| server.channels.contains(id))
+                .collect()
+        }),
+        ..Default::default()
+    };
+
+    let before = server
+        .category(&category_id)
+        .cloned()
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    let category = server
+        .update_category(
+            db,
+            &category_id,
+            partial,
+            remove
+                .map(|v| v.into_iter().map(Into::into).collect())
+                .unwrap_or_default(),
+        )
+        .await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::CategoryUpdate,
+        Some(&category.id),
+        AuditLogChange::diff(Some(&before), Some(&category)),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(category.into()))
+}
@@ -0,0 +1,75 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission, Override};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Set Category Role Permission
+///
+/// Sets permissions for the specified role in this category.
+///
+/// Applies to channels which are synced to the category.
+#[openapi(tag = "Server Categories")]
+#[put(
+    "/<target>/categories/<category_id>/permissions/<role_id>",
+    data = "<data>",
+    rank = 2
+)]
+pub async fn set_category_role_permission(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    category_id: String,
+    role_id: String,
+    data: Json<v0::DataSetRolePermissions>,
+) -> Result<Json<v0::Category>> {
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    let permissions = calculate_server_permissions(&mut query).await;
+
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
+
+    let role = server
+        .roles
+        .get(&role_id)
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    if role.rank <= query.get_member_rank().unwrap_or(i64::MIN) {
+        return Err(create_error!(NotElevated));
+    }
+
+    let before = server
+        .category(&category_id)
+        .cloned()
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    let current_value: Option<Override> = before
+        .role_permissions
+        .get(&role_id)
+        .map(|value| (*value).into());
+
+    permissions
+        .throw_permission_override(current_value, &data.permissions)
+        .await?;
+
+    let category = server
+        .set_category_role_permission(db, &category_id, &role_id, data.permissions.clone().into())
+        .await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::CategoryUpdate,
+        Some(&category.id),
+        AuditLogChange::diff(Some(&before), Some(&category)),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(category.into()))
+}
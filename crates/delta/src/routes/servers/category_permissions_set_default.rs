@@ -0,0 +1,72 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, PartialCategory, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Set Category Default Permission
+///
+/// Sets permissions for the default role in this category.
+///
+/// Applies to channels which are synced to the category.
+#[openapi(tag = "Server Categories")]
+#[put(
+    "/<target>/categories/<category_id>/permissions/default",
+    data = "<data>",
+    rank = 1
+)]
+pub async fn set_category_default_permissions(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    category_id: String,
+    data: Json<v0::DataDefaultCategoryPermissions>,
+) -> Result<Json<v0::Category>> {
+    let data = data.into_inner();
+
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    let permissions = calculate_server_permissions(&mut query).await;
+
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
+
+    let before = server
+        .category(&category_id)
+        .cloned()
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    let current_value = before.default_permissions.map(|value| value.into());
+
+    permissions
+        .throw_permission_override(current_value, &data.permissions)
+        .await?;
+
+    let category = server
+        .update_category(
+            db,
+            &category_id,
+            PartialCategory {
+                default_permissions: Some(data.permissions.into()),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::CategoryUpdate,
+        Some(&category.id),
+        AuditLogChange::diff(Some(&before), Some(&category)),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(category.into()))
+}
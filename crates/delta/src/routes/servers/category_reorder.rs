@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, PartialServer, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Reorder Categories
+///
+/// Change the order in which channel categories are displayed.
+///
+/// Every category in the server must be listed exactly once.
+#[openapi(tag = "Server Categories")]
+#[patch("/<target>/categories", data = "<data>")]
+pub async fn reorder_categories(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    data: Json<v0::DataReorderCategories>,
+) -> Result<Json<v0::Server>> {
+    let data = data.into_inner();
+
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    let mut categories = server.categories.clone().unwrap_or_default();
+
+    // Ensure the new order is a permutation of the existing categories
+    let ids: HashSet<&String> = data.categories.iter().collect();
+    if ids.len() != data.categories.len()
+        || ids.len() != categories.len()
+        || categories
+            .iter()
+            .any(|category| !ids.contains(&category.id))
+    {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    let before = server.clone();
+    categories.sort_by_key(|category| {
+        data.categories
+            .iter()
+            .position(|id| id == &category.id)
+            .unwrap_or_default()
+    });
+
+    server
+        .update(
+            db,
+            PartialServer {
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::ServerUpdate,
+        Some(&server.id),
+        AuditLogChange::diff(Some(&before), Some(&server)),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(server.into()))
+}
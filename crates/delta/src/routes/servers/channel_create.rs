@@ -1,5 +1,8 @@
-use revolt_database::util::permissions::DatabasePermissionQuery;
-use revolt_database::{util::reference::Reference, Channel, Database, User};
+use revolt_database::util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery};
+use revolt_database::{
+    util::reference::Reference, AuditLogAction, AuditLogChange, AuditLogEntry, Channel, Database,
+    User,
+};
 use revolt_models::v0;
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
 use revolt_result::{create_error, Result};
@@ -17,6 +20,7 @@ pub async fn create_server_channel(
     db: &State<Database>,
     user: User,
     server: Reference,
+    reason: AuditLogReason,
     data: Json<v0::DataCreateServerChannel>,
 ) -> Result<Json<v0::Channel>> {
     let data = data.into_inner();
@@ -32,8 +36,18 @@ pub async fn create_server_channel(
         .await
         .throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
 
-    Channel::create_server_channel(db, &mut server, data, true)
-        .await
-        .map(|channel| channel.into())
-        .map(Json)
+    let channel = Channel::create_server_channel(db, &mut server, data, true).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::ChannelCreate,
+        Some(channel.id()),
+        AuditLogChange::diff(None, Some(&channel)),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(channel.into()))
 }
@@ -0,0 +1,135 @@
+use std::collections::{HashMap, HashSet};
+
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Category, Channel, Database, PartialServer,
+    User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Reorder Channels
+///
+/// Change the order of channels and categories, and which category each channel belongs to.
+///
+/// All changes are applied at once.
+#[openapi(tag = "Server Information")]
+#[patch("/<target>/channels", data = "<data>")]
+pub async fn reorder_channels(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    data: Json<v0::DataReorderChannels>,
+) -> Result<Json<v0::Server>> {
+    let data = data.into_inner();
+
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    let permissions = calculate_server_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    let known_channels: HashSet<&String> = server.channels.iter().collect();
+    let mut partial = PartialServer::default();
+
+    if let Some(channels) = data.channels {
+        // Ensure the new order is a permutation of the existing channels
+        let ids: HashSet<&String> = channels.iter().collect();
+        if ids.len() != channels.len() || ids != known_channels {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        partial.channels = Some(channels);
+    }
+
+    if let Some(layout) = data.categories {
+        let existing: HashMap<String, Category> = server
+            .categories
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|category| (category.id.clone(), category))
+            .collect();
+
+        let previous: HashMap<&String, &String> = existing
+            .values()
+            .flat_map(|category| category.channels.iter().map(|c| (c, &category.id)))
+            .collect();
+
+        let mut placed = HashMap::new();
+        let mut categories = Vec::with_capacity(layout.len());
+
+        for v0::CategoryLayout { id, channels } in layout {
+            let mut category = existing
+                .get(&id)
+                .cloned()
+                .ok_or(create_error!(InvalidOperation))?;
+
+            for channel in &channels {
+                // Each channel must exist and belong to at most one category
+                if !known_channels.contains(channel)
+                    || placed.insert(channel.clone(), id.clone()).is_some()
+                {
+                    return Err(create_error!(InvalidOperation));
+                }
+            }
+
+            category.channels = channels;
+            categories.push(category);
+        }
+
+        // Ensure every category was listed exactly once
+        let ids: HashSet<&String> = categories.iter().map(|category| &category.id).collect();
+        if ids.len() != categories.len() || ids.len() != existing.len() {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        let moved: Vec<String> = server
+            .channels
+            .iter()
+            .filter(|channel| previous.get(channel).copied() != placed.get(*channel))
+            .cloned()
+            .collect();
+
+        // Moving a synced channel changes the permissions it inherits
+        if !moved.is_empty()
+            && db.fetch_channels(&moved).await?.iter().any(|channel| {
+                matches!(
+                    channel,
+                    Channel::TextChannel {
+                        sync_permissions: true,
+                        ..
+                    } | Channel::VoiceChannel {
+                        sync_permissions: true,
+                        ..
+                    }
+                )
+            })
+        {
+            permissions
+                .throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
+        }
+
+        partial.categories = Some(categories);
+    }
+
+    if partial.channels.is_some() || partial.categories.is_some() {
+        let before = server.clone();
+        server.update(db, partial, vec![]).await?;
+
+        AuditLogEntry::record(
+            db,
+            &server.id,
+            &user.id,
+            AuditLogAction::ServerUpdate,
+            Some(&server.id),
+            AuditLogChange::diff(Some(&before), Some(&server)),
+            reason.into_inner(),
+        )
+        .await;
+    }
+
+    Ok(Json(server.into()))
+}
@@ -0,0 +1,72 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, File, ServerEvent, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Create Server Event
+///
+/// Schedule a new event for this server.
+#[openapi(tag = "Server Events")]
+#[post("/<target>/events", data = "<data>")]
+pub async fn create_server_event(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    data: Json<v0::DataCreateServerEvent>,
+) -> Result<Json<v0::ServerEvent>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let mut event = ServerEvent {
+        id: Ulid::new().to_string(),
+        server: server.id.clone(),
+        creator: user.id.clone(),
+        title: data.title,
+        description: data.description,
+        location: data.location.into(),
+        cover: None,
+        starts_at: data.starts_at,
+        ends_at: data.ends_at,
+        interested: Default::default(),
+        reminded: false,
+    };
+
+    event.validate()?;
+    event.validate_location(db, &server).await?;
+
+    if let Some(cover) = data.cover {
+        event.cover = Some(File::use_server_event_cover(db, &cover, &server.id, &user.id).await?);
+    }
+
+    event.create(db).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::ServerEventCreate,
+        Some(&event.id),
+        AuditLogChange::diff(None, Some(&event)),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(event.into()))
+}
@@ -0,0 +1,48 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, User,
+};
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Delete Server Event
+///
+/// Cancel a scheduled server event by its id.
+#[openapi(tag = "Server Events")]
+#[delete("/<target>/events/<event_id>")]
+pub async fn delete_server_event(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    event_id: String,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let event = db.fetch_server_event(&event_id).await?;
+    if event.server != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    let changes = AuditLogChange::diff(Some(&event), None);
+    event.delete(db).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::ServerEventDelete,
+        Some(&event_id),
+        changes,
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,98 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, FieldsServerEvent, File,
+    PartialServerEvent, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Server Event
+///
+/// Edit a scheduled server event by its id.
+#[openapi(tag = "Server Events")]
+#[patch("/<target>/events/<event_id>", data = "<data>")]
+pub async fn edit_server_event(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    event_id: String,
+    data: Json<v0::DataEditServerEvent>,
+) -> Result<Json<v0::ServerEvent>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let mut event = db.fetch_server_event(&event_id).await?;
+    if event.server != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    let v0::DataEditServerEvent {
+        title,
+        description,
+        location,
+        cover,
+        starts_at,
+        ends_at,
+        remove,
+    } = data;
+
+    let remove: Vec<FieldsServerEvent> = remove.into_iter().map(Into::into).collect();
+    let mut partial = PartialServerEvent {
+        title,
+        description,
+        location: location.map(Into::into),
+        starts_at,
+        ends_at,
+        ..Default::default()
+    };
+
+    // Validate the changes before touching any files
+    let mut updated = event.clone();
+    updated.apply_options(partial.clone());
+    updated.validate()?;
+
+    if partial.location.is_some() {
+        updated.validate_location(db, &server).await?;
+    }
+
+    // Release the old cover if it is being removed or replaced
+    if remove.contains(&FieldsServerEvent::Cover) || cover.is_some() {
+        if let Some(cover) = &event.cover {
+            db.mark_attachment_as_deleted(&cover.id).await?;
+        }
+    }
+
+    if let Some(cover) = cover {
+        partial.cover = Some(File::use_server_event_cover(db, &cover, &server.id, &user.id).await?);
+    }
+
+    let before = event.clone();
+    event.update(db, partial, remove).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::ServerEventUpdate,
+        Some(&event.id),
+        AuditLogChange::diff(Some(&before), Some(&event)),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(event.into()))
+}
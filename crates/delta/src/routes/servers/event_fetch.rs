@@ -0,0 +1,33 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::PermissionQuery;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Server Event
+///
+/// Fetch a scheduled server event by its id.
+#[openapi(tag = "Server Events")]
+#[get("/<target>/events/<event_id>")]
+pub async fn fetch_server_event(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    event_id: String,
+) -> Result<Json<v0::ServerEvent>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    if !query.are_we_a_member().await {
+        return Err(create_error!(NotFound));
+    }
+
+    let event = db.fetch_server_event(&event_id).await?;
+    if event.server != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    Ok(Json(event.into()))
+}
@@ -0,0 +1,38 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_permissions::PermissionQuery;
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Mark Interested
+///
+/// Mark yourself as interested in a scheduled server event.
+///
+/// You will be reminded shortly before the event starts.
+#[openapi(tag = "Server Events")]
+#[put("/<target>/events/<event_id>/interested")]
+pub async fn add_interest(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    event_id: String,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    if !query.are_we_a_member().await {
+        return Err(create_error!(NotFound));
+    }
+
+    let mut event = db.fetch_server_event(&event_id).await?;
+    if event.server != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    event
+        .add_interest(db, &user.id)
+        .await
+        .map(|_| EmptyResponse)
+}
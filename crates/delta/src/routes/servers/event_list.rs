@@ -0,0 +1,30 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::PermissionQuery;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Server Events
+///
+/// Fetch all events scheduled on a server, soonest first.
+#[openapi(tag = "Server Events")]
+#[get("/<target>/events")]
+pub async fn fetch_server_events(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<Vec<v0::ServerEvent>>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    if !query.are_we_a_member().await {
+        return Err(create_error!(NotFound));
+    }
+
+    db.fetch_server_events(&server.id)
+        .await
+        .map(|events| events.into_iter().map(Into::into).collect())
+        .map(Json)
+}
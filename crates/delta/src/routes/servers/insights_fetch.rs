@@ -0,0 +1,44 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, ServerInsights, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Fetch Server Insights
+///
+/// Fetch daily activity rollups for a server, oldest first.
+///
+/// Insights are only collected while analytics are enabled.
+#[openapi(tag = "Server Information")]
+#[get("/<target>/insights?<options..>")]
+pub async fn fetch_insights(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    options: v0::OptionsFetchInsights,
+) -> Result<Json<Vec<v0::ServerInsights>>> {
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let since = ServerInsights::today() - options.days.unwrap_or(30) + 1;
+    Ok(Json(
+        db.fetch_server_insights(&server.id, since)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+    ))
+}
@@ -4,27 +4,55 @@ use revolt_database::{
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
-use revolt_result::Result;
+use revolt_result::{create_error, Result};
 use rocket::{serde::json::Json, State};
+use validator::Validate;
 
 /// # Fetch Invites
 ///
 /// Fetch all server invites.
+///
+/// Optionally include the members who joined through each invite, ordered by user id.
 #[openapi(tag = "Server Members")]
-#[get("/<target>/invites")]
+#[get("/<target>/invites?<options..>")]
 pub async fn invites(
     db: &State<Database>,
     user: User,
     target: Reference,
-) -> Result<Json<Vec<v0::Invite>>> {
+    options: v0::OptionsFetchInvites,
+) -> Result<Json<v0::FetchInvitesResponse>> {
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
     let server = target.as_server(db).await?;
     let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
     calculate_server_permissions(&mut query)
         .await
         .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
 
-    db.fetch_invites_for_server(&server.id)
-        .await
-        .map(|v| v.into_iter().map(Into::into).collect())
-        .map(Json)
+    let invites: Vec<v0::Invite> = db
+        .fetch_invites_for_server(&server.id)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    if let Some(true) = options.include_members {
+        let members = db
+            .fetch_invited_members(&server.id, options.after, options.limit.unwrap_or(100))
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(Json(v0::FetchInvitesResponse::InvitesWithMembers {
+            invites,
+            members,
+        }))
+    } else {
+        Ok(Json(v0::FetchInvitesResponse::JustInvites(invites)))
+    }
 }
@@ -0,0 +1,96 @@
+use revolt_database::{util::reference::Reference, Database, PartialMember, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Accept Server Rules
+///
+/// Accept the rules of a server to gain full membership.
+#[openapi(tag = "Server Members")]
+#[post("/<target>/rules/accept")]
+pub async fn accept_rules(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<v0::Member>> {
+    let server = target.as_server(db).await?;
+    let mut member = db.fetch_member(&server.id, &user.id).await?;
+
+    if !member.pending {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    member
+        .update(
+            db,
+            PartialMember {
+                pending: Some(false),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    Ok(Json(member.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Member, Server};
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+
+    #[rocket::async_test]
+    async fn accept_rules() {
+        let harness = TestHarness::new().await;
+        let (_, _, owner) = harness.new_user().await;
+        let (_, session, user) = harness.new_user().await;
+
+        let (mut server, channels) = Server::create(
+            &harness.db,
+            v0::DataCreateServer {
+                name: "Test Server".to_string(),
+                ..Default::default()
+            },
+            &owner,
+            true,
+        )
+        .await
+        .expect("Failed to create test server");
+
+        server.rules = Some("Be nice".to_string());
+        let (member, _) = Member::create(&harness.db, &server, &user, Some(channels))
+            .await
+            .expect("Failed to create member");
+        assert!(member.pending);
+
+        let response = harness
+            .client
+            .post(format!("/servers/{}/rules/accept", server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let member: v0::Member = response.into_json().await.expect("`Member`");
+        assert!(!member.pending);
+
+        let member = harness
+            .db
+            .fetch_member(&server.id, &user.id)
+            .await
+            .expect("Failed to fetch member");
+        assert!(!member.pending);
+
+        // Rules can only be accepted once
+        let response = harness
+            .client
+            .post(format!("/servers/{}/rules/accept", server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+}
@@ -1,8 +1,9 @@
 use std::collections::HashSet;
 
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, File, PartialMember, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, FieldsMember, File, ModerationAction,
+    ModerationRecord, PartialMember, User,
 };
 use revolt_models::v0;
 
@@ -21,6 +22,7 @@ pub async fn edit(
     user: User,
     server: Reference,
     member: Reference,
+    reason: AuditLogReason,
     data: Json<v0::DataMemberEdit>,
 ) -> Result<Json<v0::Member>> {
     let data = data.into_inner();
@@ -67,6 +69,42 @@ pub async fn edit(
         }
     }
 
+    let removing = |field: v0::FieldsMember| {
+        data.remove
+            .as_ref()
+            .map(|x| x.contains(&field))
+            .unwrap_or_default()
+    };
+
+    // Server profiles may only be written by their owner, moderators may only clear them
+    if data.bio.is_some() || data.pronouns.is_some() {
+        if user.id == member.id.user {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::ChangeNickname)?;
+        } else {
+            return Err(create_error!(InvalidOperation));
+        }
+    } else if removing(v0::FieldsMember::Bio) || removing(v0::FieldsMember::Pronouns) {
+        if user.id == member.id.user {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::ChangeNickname)?;
+        } else {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageNicknames)?;
+        }
+    }
+
+    if data.banner.is_some() {
+        if user.id == member.id.user {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::ChangeAvatar)?;
+        } else {
+            return Err(create_error!(InvalidOperation));
+        }
+    } else if removing(v0::FieldsMember::Banner) {
+        if user.id == member.id.user {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::ChangeAvatar)?;
+        } else {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::RemoveAvatars)?;
+        }
+    }
+
     if data.roles.is_some()
         || data
             .remove
@@ -78,11 +116,9 @@ pub async fn edit(
     }
 
     if data.timeout.is_some()
-        || data
-            .remove
-            .as_ref()
-            .map(|x| x.contains(&v0::FieldsMember::Timeout))
-            .unwrap_or_default()
+        || removing(v0::FieldsMember::Timeout)
+        || removing(v0::FieldsMember::TimeoutReason)
+        || removing(v0::FieldsMember::TimeoutModerator)
     {
         if data.timeout.is_some() && member.id.user == user.id {
             return Err(create_error!(CannotTimeoutYourself));
@@ -123,24 +159,51 @@ pub async fn edit(
     let v0::DataMemberEdit {
         nickname,
         avatar,
+        bio,
+        banner,
+        pronouns,
         roles,
         timeout,
+        timeout_reason,
         remove,
     } = data;
 
+    let reason = reason.into_inner();
+    let timeout_reason = timeout.and(timeout_reason.or(reason.clone()));
+
+    let mut remove: Vec<FieldsMember> = remove
+        .map(|v| v.into_iter().map(Into::into).collect())
+        .unwrap_or_default();
+
+    // Lifting a timeout also clears who issued it and why
+    if remove.contains(&FieldsMember::Timeout) {
+        remove.retain(|field| !FieldsMember::timeout().contains(field));
+        remove.extend(FieldsMember::timeout());
+    }
+
+    // A new timeout without a reason should not keep the previous one's reason
+    if timeout.is_some()
+        && timeout_reason.is_none()
+        && !remove.contains(&FieldsMember::TimeoutReason)
+    {
+        remove.push(FieldsMember::TimeoutReason);
+    }
+
     let mut partial = PartialMember {
         nickname,
+        bio,
+        pronouns,
         roles,
         timeout,
+        timeout_reason: timeout_reason.clone(),
+        timeout_moderator: timeout.map(|_| user.id.clone()),
         ..Default::default()
     };
 
     // 1. Remove fields from object
-    if let Some(fields) = &remove {
-        if fields.contains(&v0::FieldsMember::Avatar) {
-            if let Some(avatar) = &member.avatar {
-                db.mark_attachment_as_deleted(&avatar.id).await?;
-            }
+    if remove.contains(&FieldsMember::Avatar) {
+        if let Some(avatar) = &member.avatar {
+            db.mark_attachment_as_deleted(&avatar.id).await?;
         }
     }
 
@@ -149,15 +212,239 @@ pub async fn edit(
         partial.avatar = Some(File::use_user_avatar(db, &avatar, &user.id, &user.id).await?);
     }
 
-    member
-        .update(
+    // 3. Apply new banner
+    if let Some(banner) = banner {
+        partial.banner = Some(File::use_member_banner(db, &banner, &user.id, &user.id).await?);
+    }
+
+    // The previous banner is only dropped once the member no longer refers to it
+    let replaced_banner = if partial.banner.is_some() || remove.contains(&FieldsMember::Banner) {
+        member.banner.as_ref().map(|banner| banner.id.clone())
+    } else {
+        None
+    };
+
+    let before = member.clone();
+    member.update(db, partial, remove).await?;
+
+    if let Some(id) = replaced_banner {
+        db.mark_attachment_as_deleted(&id).await?;
+    }
+
+    if timeout.is_some() {
+        ModerationRecord::create(
             db,
-            partial,
-            remove
-                .map(|v| v.into_iter().map(Into::into).collect())
-                .unwrap_or_default(),
+            &server.id,
+            &member.id.user,
+            Some(&user.id),
+            ModerationAction::Timeout,
+            timeout_reason,
+            timeout,
         )
         .await?;
+    }
+
+    // Members changing their own profile is not worth recording
+    let changes = AuditLogChange::diff(Some(&before), Some(&member));
+    if member.id.user != user.id
+        || changes
+            .iter()
+            .any(|change| change.key == "roles" || change.key == "timeout")
+    {
+        AuditLogEntry::record(
+            db,
+            &server.id,
+            &user.id,
+            AuditLogAction::MemberUpdate,
+            Some(&member.id.user),
+            changes,
+            reason,
+        )
+        .await;
+    }
 
     Ok(Json(member.into()))
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use iso8601_timestamp::{Duration, Timestamp};
+    use revolt_database::{Member, Server};
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn timeout_metadata() {
+        let harness = TestHarness::new().await;
+        let (_, session, owner) = harness.new_user().await;
+        let (_, _, user) = harness.new_user().await;
+
+        let (server, channels) = Server::create(
+            &harness.db,
+            v0::DataCreateServer {
+                name: "Test Server".to_string(),
+                ..Default::default()
+            },
+            &owner,
+            true,
+        )
+        .await
+        .expect("Failed to create test server");
+
+        Member::create(&harness.db, &server, &owner, Some(channels.clone()))
+            .await
+            .expect("Failed to create owner member");
+        Member::create(&harness.db, &server, &user, Some(channels))
+            .await
+            .expect("Failed to create member");
+
+        let url = format!("/servers/{}/members/{}", server.id, user.id);
+
+        let response = harness
+            .client
+            .patch(url.clone())
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!({
+                    "timeout": Timestamp::now_utc().checked_add(Duration::minutes(10)),
+                    "timeout_reason": "Spamming"
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let member: v0::Member = response.into_json().await.expect("`Member`");
+        assert!(member.timeout.is_some());
+        assert_eq!(member.timeout_reason.as_deref(), Some("Spamming"));
+        assert_eq!(member.timeout_moderator.as_deref(), Some(owner.id.as_str()));
+
+        // Extending the timeout without a reason drops the previous reason
+        let response = harness
+            .client
+            .patch(url.clone())
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!({
+                    "timeout": Timestamp::now_utc().checked_add(Duration::minutes(20))
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        let member = harness
+            .db
+            .fetch_member(&server.id, &user.id)
+            .await
+            .expect("`Member`");
+        assert!(member.timeout.is_some());
+        assert_eq!(member.timeout_reason, None);
+        assert_eq!(member.timeout_moderator.as_deref(), Some(owner.id.as_str()));
+
+        // Lifting the timeout clears everything about it
+        let response = harness
+            .client
+            .patch(url)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(json!({ "remove": ["Timeout"] }).to_string())
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        let member = harness
+            .db
+            .fetch_member(&server.id, &user.id)
+            .await
+            .expect("`Member`");
+        assert_eq!(member.timeout, None);
+        assert_eq!(member.timeout_reason, None);
+        assert_eq!(member.timeout_moderator, None);
+    }
+
+    #[rocket::async_test]
+    async fn moderate_server_profile() {
+        let harness = TestHarness::new().await;
+        let (_, session, owner) = harness.new_user().await;
+        let (_, user_session, user) = harness.new_user().await;
+
+        let (server, channels) = Server::create(
+            &harness.db,
+            v0::DataCreateServer {
+                name: "Test Server".to_string(),
+                ..Default::default()
+            },
+            &owner,
+            true,
+        )
+        .await
+        .expect("Failed to create test server");
+
+        Member::create(&harness.db, &server, &owner, Some(channels.clone()))
+            .await
+            .expect("Failed to create owner member");
+        Member::create(&harness.db, &server, &user, Some(channels))
+            .await
+            .expect("Failed to create member");
+
+        let url = format!("/servers/{}/members/{}", server.id, user.id);
+
+        let response = harness
+            .client
+            .patch(url.clone())
+            .header(Header::new(
+                "x-session-token",
+                user_session.token.to_string(),
+            ))
+            .header(ContentType::JSON)
+            .body(json!({ "bio": "Server bio" }).to_string())
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        // Moderators may not write someone else's profile
+        let response = harness
+            .client
+            .patch(url.clone())
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(json!({ "bio": "Replaced bio" }).to_string())
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+        drop(response);
+
+        // But they may clear it
+        let response = harness
+            .client
+            .patch(url)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(json!({ "remove": ["Bio"] }).to_string())
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        let member = harness
+            .db
+            .fetch_member(&server.id, &user.id)
+            .await
+            .expect("`Member`");
+        assert_eq!(member.bio, None);
+    }
+}
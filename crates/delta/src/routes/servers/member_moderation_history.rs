@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Fetch Moderation History
+///
+/// Fetch past timeouts, kicks and bans of a user, newest first.
+#[openapi(tag = "Server Members")]
+#[get("/<server>/members/<member>/moderation?<options..>")]
+pub async fn fetch_moderation_history(
+    db: &State<Database>,
+    user: User,
+    server: Reference,
+    member: Reference,
+    options: v0::OptionsFetchModerationHistory,
+) -> Result<Json<v0::ModerationHistoryResult>> {
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = server.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    let permissions = calculate_server_permissions(&mut query).await;
+
+    if !permissions.has_channel_permission(ChannelPermission::KickMembers)
+        && !permissions.has_channel_permission(ChannelPermission::BanMembers)
+    {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::TimeoutMembers)?;
+    }
+
+    let records = db
+        .fetch_moderation_records(
+            &server.id,
+            &member.id,
+            options.before,
+            options.limit.unwrap_or(50),
+        )
+        .await?;
+
+    let user_ids: Vec<String> = records
+        .iter()
+        .filter_map(|record| record.moderator.clone())
+        .collect::<HashSet<String>>()
+        .into_iter()
+        .collect();
+
+    Ok(Json(v0::ModerationHistoryResult {
+        users: User::fetch_many_ids_as_mutuals(db, &user, &user_ids).await?,
+        records: records.into_iter().map(Into::into).collect(),
+    }))
+}
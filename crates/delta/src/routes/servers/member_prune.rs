@@ -0,0 +1,82 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, Member, PruneJob, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Prune Members
+///
+/// Kick members who have not sent or acknowledged a message within a number of days
+/// and hold no roles outside of the given set.
+///
+/// Members are kicked in batches in the background, this returns the number of members being kicked
+/// and the prune job which can be used to follow its progress.
+#[openapi(tag = "Server Members")]
+#[post("/<target>/prune", data = "<data>")]
+pub async fn prune(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    data: Json<v0::DataMemberPrune>,
+) -> Result<Json<v0::MemberPruneResponse>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::KickMembers)?;
+
+    let members = Member::fetch_prunable(
+        db,
+        &server,
+        data.days,
+        &data.roles,
+        query.get_member_rank().unwrap_or(i64::MIN),
+    )
+    .await?;
+
+    let count = members.len();
+    if count == 0 {
+        return Ok(Json(v0::MemberPruneResponse { count, job: None }));
+    }
+
+    let job = PruneJob::create(db, &server.id, &user.id, members).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::MemberPrune,
+        None,
+        vec![
+            AuditLogChange {
+                key: "days".to_string(),
+                before: None,
+                after: Some(data.days.to_string()),
+            },
+            AuditLogChange {
+                key: "count".to_string(),
+                before: None,
+                after: Some(count.to_string()),
+            },
+        ],
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(v0::MemberPruneResponse {
+        count,
+        job: Some(job.id),
+    }))
+}
@@ -0,0 +1,33 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Prune Job
+///
+/// Fetch the progress of a member prune.
+#[openapi(tag = "Server Members")]
+#[get("/<target>/prune/<job_id>")]
+pub async fn fetch_prune(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    job_id: String,
+) -> Result<Json<v0::PruneJob>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::KickMembers)?;
+
+    let job = db.fetch_prune_job(&job_id).await?;
+    if job.server != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    Ok(Json(job.into()))
+}
@@ -0,0 +1,47 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, Member, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Preview Member Prune
+///
+/// Count the members which would be kicked by a prune with the given options.
+#[openapi(tag = "Server Members")]
+#[get("/<target>/prune?<options..>")]
+pub async fn preview_prune(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    options: v0::OptionsMemberPrune,
+) -> Result<Json<v0::MemberPruneResponse>> {
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::KickMembers)?;
+
+    let members = Member::fetch_prunable(
+        db,
+        &server,
+        options.days,
+        &options.roles,
+        query.get_member_rank().unwrap_or(i64::MIN),
+    )
+    .await?;
+
+    Ok(Json(v0::MemberPruneResponse {
+        count: members.len(),
+        job: None,
+    }))
+}
@@ -1,6 +1,7 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, RemovalIntention, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogEntry, Database, ModerationAction, ModerationRecord, RemovalIntention,
+    User,
 };
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
 use revolt_result::{create_error, Result};
@@ -17,6 +18,7 @@ pub async fn kick(
     user: User,
     target: Reference,
     member: Reference,
+    reason: AuditLogReason,
 ) -> Result<EmptyResponse> {
     let server = target.as_server(db).await?;
 
@@ -40,8 +42,33 @@ pub async fn kick(
         return Err(create_error!(NotElevated));
     }
 
+    let member_id = member.id.user.clone();
     member
         .remove(db, &server, RemovalIntention::Kick, false)
-        .await
-        .map(|_| EmptyResponse)
+        .await?;
+
+    let reason = reason.into_inner();
+    ModerationRecord::create(
+        db,
+        &server.id,
+        &member_id,
+        Some(&user.id),
+        ModerationAction::Kick,
+        reason.clone(),
+        None,
+    )
+    .await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::MemberKick,
+        Some(&member_id),
+        vec![],
+        reason,
+    )
+    .await;
+
+    Ok(EmptyResponse)
 }
@@ -0,0 +1,49 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    voice::disconnect_voice_state,
+    Database, User,
+};
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_presence::get_voice_state;
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+use super::member_voice_edit::fetch_server_voice_channel;
+
+/// # Disconnect Member
+///
+/// Disconnect a member from the call they are in.
+#[openapi(tag = "Server Members")]
+#[delete("/<server>/members/<member>/voice")]
+pub async fn disconnect_voice(
+    db: &State<Database>,
+    user: User,
+    server: Reference,
+    member: Reference,
+) -> Result<EmptyResponse> {
+    let server = server.as_server(db).await?;
+    let member = member.as_member(db, &server.id).await?;
+    let state = get_voice_state(&member.id.user)
+        .await
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    let channel = fetch_server_voice_channel(db, &server, &state.channel_id).await?;
+
+    let mut query = DatabasePermissionQuery::new(db, &user)
+        .server(&server)
+        .channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::MoveMembers)?;
+
+    if member.id.user != user.id
+        && member.get_ranking(query.server_ref().as_ref().unwrap())
+            <= query.get_member_rank().unwrap_or(i64::MIN)
+    {
+        return Err(create_error!(NotElevated));
+    }
+
+    disconnect_voice_state(db, &member.id.user).await;
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,142 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    voice::{
+        remove_voice_participant, update_voice_state, voice_backend, voice_state_model, VoiceGrants,
+    },
+    Channel, Database, Server, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_presence::{get_voice_state, VoiceState};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// Fetch a voice channel, ensuring it belongs to the given server
+pub async fn fetch_server_voice_channel(
+    db: &Database,
+    server: &Server,
+    channel_id: &str,
+) -> Result<Channel> {
+    let channel = db.fetch_channel(channel_id).await?;
+    match &channel {
+        Channel::VoiceChannel { server: id, .. } if id == &server.id => Ok(channel),
+        _ => Err(create_error!(NotFound)),
+    }
+}
+
+/// # Edit Member Voice State
+///
+/// Mute, deafen or move a member connected to a call in this server.
+#[openapi(tag = "Server Members")]
+#[patch("/<server>/members/<member>/voice", data = "<data>")]
+pub async fn edit_voice(
+    db: &State<Database>,
+    user: User,
+    server: Reference,
+    member: Reference,
+    data: Json<v0::DataMemberVoiceEdit>,
+) -> Result<Json<v0::VoiceState>> {
+    let data = data.into_inner();
+
+    // Fetch server, member and the call they are in
+    let server = server.as_server(db).await?;
+    let member = member.as_member(db, &server.id).await?;
+    let state = get_voice_state(&member.id.user)
+        .await
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    let channel = fetch_server_voice_channel(db, &server, &state.channel_id).await?;
+
+    // Check permissions in the current call
+    let mut query = DatabasePermissionQuery::new(db, &user)
+        .server(&server)
+        .channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+
+    if data.muted.is_some() {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::MuteMembers)?;
+    }
+
+    if data.deafened.is_some() {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::DeafenMembers)?;
+    }
+
+    if data.channel.is_some() {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::MoveMembers)?;
+    }
+
+    // Check that we have permissions to act against this member
+    if member.id.user != user.id
+        && member.get_ranking(query.server_ref().as_ref().unwrap())
+            <= query.get_member_rank().unwrap_or(i64::MIN)
+    {
+        return Err(create_error!(NotElevated));
+    }
+
+    // Resolve the call the member should end up in
+    let target = if let Some(channel_id) = &data.channel {
+        let target = fetch_server_voice_channel(db, &server, channel_id).await?;
+
+        let mut query = DatabasePermissionQuery::new(db, &user)
+            .server(&server)
+            .channel(&target);
+        calculate_channel_permissions(&mut query)
+            .await
+            .throw_if_lacking_channel_permission(ChannelPermission::MoveMembers)?;
+
+        // The member must be able to connect to the call they are moved to
+        let target_user = db.fetch_user(&member.id.user).await?;
+        let mut query = DatabasePermissionQuery::new(db, &target_user)
+            .server(&server)
+            .channel(&target)
+            .member(&member);
+        calculate_channel_permissions(&mut query)
+            .await
+            .throw_if_lacking_channel_permission(ChannelPermission::Connect)?;
+
+        target
+    } else {
+        channel
+    };
+
+    // Drop moved members from their current call so they reconnect to the target
+    if state.channel_id != target.id() {
+        remove_voice_participant(&state).await;
+    }
+
+    let state = VoiceState {
+        channel_id: target.id().to_string(),
+        muted: data.muted.unwrap_or(state.muted),
+        deafened: data.deafened.unwrap_or(state.deafened),
+        ..state
+    };
+
+    // Apply mute and deafen to the member's live session, moved members rejoin with them
+    if data.channel.is_none() && (data.muted.is_some() || data.deafened.is_some()) {
+        let target_user = db.fetch_user(&member.id.user).await?;
+        let mut query = DatabasePermissionQuery::new(db, &target_user)
+            .server(&server)
+            .channel(&target)
+            .member(&member);
+
+        let mut grants = VoiceGrants::from_permissions(
+            target.id(),
+            &calculate_channel_permissions(&mut query).await,
+        );
+        grants.apply_voice_state(&state);
+
+        if let Err(error) = voice_backend()
+            .await?
+            .update_grants(&member.id.user, grants, state.region.as_deref())
+            .await
+        {
+            log::error!(
+                "Failed to update voice grants for {}: {error:?}",
+                member.id.user
+            );
+        }
+    }
+
+    update_voice_state(db, &target, state.clone()).await;
+    Ok(Json(voice_state_model(state)))
+}
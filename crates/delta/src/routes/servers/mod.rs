@@ -1,28 +1,67 @@
 use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
 use rocket::Route;
 
+mod audit_log_fetch;
+mod automod_create;
+mod automod_delete;
+mod automod_edit;
+mod automod_list;
 mod ban_create;
 mod ban_list;
 mod ban_remove;
+mod category_create;
+mod category_delete;
+mod category_edit;
+mod category_permissions_set;
+mod category_permissions_set_default;
+mod category_reorder;
 mod channel_create;
+mod channel_reorder;
 mod emoji_list;
+mod event_create;
+mod event_delete;
+mod event_edit;
+mod event_fetch;
+mod event_interest_add;
+mod event_interest_remove;
+mod event_list;
+mod insights_fetch;
 mod invites_fetch;
+mod member_accept_rules;
 mod member_edit;
 mod member_experimental_query;
 mod member_fetch;
 mod member_fetch_all;
+mod member_moderation_history;
+mod member_prune;
+mod member_prune_fetch;
+mod member_prune_preview;
 mod member_remove;
+mod member_voice_disconnect;
+mod member_voice_edit;
+mod ownership_transfer;
 mod permissions_set;
 mod permissions_set_default;
+mod roles_assign_bulk;
+mod roles_assignment_fetch;
 mod roles_create;
 mod roles_delete;
 mod roles_edit;
 mod roles_fetch;
+mod roles_member_counts;
+mod roles_reorder;
 mod server_ack;
 mod server_create;
 mod server_delete;
 mod server_edit;
 mod server_fetch;
+mod server_restore;
+mod template_create;
+mod template_delete;
+mod template_list;
+mod template_sync;
+mod vanity_delete;
+mod vanity_set;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
     openapi_get_routes_spec![
@@ -30,23 +69,62 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         server_delete::delete,
         server_fetch::fetch,
         server_edit::edit,
+        server_restore::restore,
         server_ack::ack,
+        ownership_transfer::transfer_ownership,
+        audit_log_fetch::fetch_audit_log,
+        insights_fetch::fetch_insights,
         channel_create::create_server_channel,
+        channel_reorder::reorder_channels,
+        category_create::create_category,
+        category_edit::edit_category,
+        category_delete::delete_category,
+        category_reorder::reorder_categories,
+        category_permissions_set::set_category_role_permission,
+        category_permissions_set_default::set_category_default_permissions,
         member_fetch_all::fetch_all,
         member_remove::kick,
         member_fetch::fetch,
         member_edit::edit,
+        member_voice_edit::edit_voice,
+        member_voice_disconnect::disconnect_voice,
         member_experimental_query::member_experimental_query,
+        member_accept_rules::accept_rules,
+        member_moderation_history::fetch_moderation_history,
+        member_prune_preview::preview_prune,
+        member_prune::prune,
+        member_prune_fetch::fetch_prune,
         ban_create::ban,
         ban_remove::unban,
         ban_list::list,
         invites_fetch::invites,
         roles_create::create,
         roles_edit::edit,
+        roles_reorder::reorder_roles,
         roles_fetch::fetch,
+        roles_member_counts::fetch_member_counts,
         roles_delete::delete,
+        roles_assign_bulk::assign_role_bulk,
+        roles_assignment_fetch::fetch_role_assignment,
         permissions_set::set_role_permission,
         permissions_set_default::set_default_permissions,
-        emoji_list::list_emoji
+        emoji_list::list_emoji,
+        event_list::fetch_server_events,
+        event_create::create_server_event,
+        event_fetch::fetch_server_event,
+        event_edit::edit_server_event,
+        event_delete::delete_server_event,
+        event_interest_add::add_interest,
+        event_interest_remove::remove_interest,
+        template_create::create_template,
+        template_list::fetch_templates,
+        template_sync::sync_template,
+        template_delete::delete_template,
+        automod_list::fetch_automod_rules,
+        automod_create::create_automod_rule,
+        automod_edit::edit_automod_rule,
+        automod_delete::delete_automod_rule,
+        vanity_set::set_vanity,
+        vanity_delete::delete_vanity
     ]
 }
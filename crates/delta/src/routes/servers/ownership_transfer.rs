@@ -0,0 +1,209 @@
+use authifier::models::ValidatedTicket;
+use revolt_database::{
+    util::{audit_log::AuditLogReason, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, PartialServer, SystemMessage, User,
+};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Transfer Server Ownership
+///
+/// Transfer ownership of a server to another member.
+///
+/// Requires the current owner to provide a valid MFA ticket.
+#[openapi(tag = "Server Information")]
+#[put("/<target>/owner", data = "<data>")]
+pub async fn transfer_ownership(
+    db: &State<Database>,
+    user: User,
+    ticket: ValidatedTicket,
+    target: Reference,
+    reason: AuditLogReason,
+    data: Json<v0::DataTransferServerOwnership>,
+) -> Result<Json<v0::Server>> {
+    let data = data.into_inner();
+
+    // Ticket must belong to the authenticated user
+    if ticket.0.account_id != user.id {
+        return Err(create_error!(InvalidCredentials));
+    }
+
+    let mut server = target.as_server(db).await?;
+    if server.owner != user.id {
+        return Err(create_error!(NotOwner));
+    }
+
+    if data.user == user.id {
+        return Err(create_error!(NoEffect));
+    }
+
+    // New owner must be a member of the server and not a bot
+    let member = db.fetch_member(&server.id, &data.user).await?;
+    let new_owner = db.fetch_user(&member.id.user).await?;
+    if new_owner.bot.is_some() {
+        return Err(create_error!(IsBot));
+    }
+
+    let before = server.clone();
+
+    server
+        .update(
+            db,
+            PartialServer {
+                owner: Some(new_owner.id.clone()),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::ServerUpdate,
+        Some(&server.id),
+        AuditLogChange::diff(Some(&before), Some(&server)),
+        reason.into_inner(),
+    )
+    .await;
+
+    if let Some(id) = server
+        .system_messages
+        .as_ref()
+        .and_then(|x| x.user_joined.as_ref())
+    {
+        SystemMessage::ServerOwnershipChanged {
+            from: user.id.clone(),
+            to: new_owner.id.clone(),
+        }
+        .into_message(id.to_string())
+        .send_without_notifications(db, None, None, false, false, false)
+        .await
+        .ok();
+    }
+
+    Ok(Json(server.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Bot, Member, Server};
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn transfer_ownership() {
+        let harness = TestHarness::new().await;
+        let (account, session, owner) = harness.new_user().await;
+        let (other_account, other_session, user) = harness.new_user().await;
+
+        let (server, channels) = Server::create(
+            &harness.db,
+            v0::DataCreateServer {
+                name: "Test Server".to_string(),
+                ..Default::default()
+            },
+            &owner,
+            true,
+        )
+        .await
+        .expect("Failed to create test server");
+
+        Member::create(&harness.db, &server, &owner, Some(channels.clone()))
+            .await
+            .expect("Failed to create owner member");
+        Member::create(&harness.db, &server, &user, Some(channels.clone()))
+            .await
+            .expect("Failed to create member");
+
+        let (bot, bot_user) = Bot::create(&harness.db, TestHarness::rand_string(), &owner, None)
+            .await
+            .expect("`Bot`");
+        Member::create(&harness.db, &server, &bot_user, Some(channels))
+            .await
+            .expect("Failed to create bot member");
+
+        let url = format!("/servers/{}/owner", server.id);
+
+        // Ticket must belong to the authenticated account
+        let ticket = harness.new_mfa_ticket(&other_account).await;
+        let response = harness
+            .client
+            .put(url.clone())
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(Header::new("x-mfa-ticket", ticket.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataTransferServerOwnership {
+                    user: user.id.clone()
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Unauthorized);
+        drop(response);
+
+        // Only the owner may transfer the server
+        let ticket = harness.new_mfa_ticket(&other_account).await;
+        let response = harness
+            .client
+            .put(url.clone())
+            .header(Header::new(
+                "x-session-token",
+                other_session.token.to_string(),
+            ))
+            .header(Header::new("x-mfa-ticket", ticket.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataTransferServerOwnership {
+                    user: user.id.clone()
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        drop(response);
+
+        // Bots cannot own servers
+        let ticket = harness.new_mfa_ticket(&account).await;
+        let response = harness
+            .client
+            .put(url.clone())
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(Header::new("x-mfa-ticket", ticket.token.to_string()))
+            .header(ContentType::JSON)
+            .body(json!(v0::DataTransferServerOwnership { user: bot.id }).to_string())
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+        drop(response);
+
+        let ticket = harness.new_mfa_ticket(&account).await;
+        let response = harness
+            .client
+            .put(url)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(Header::new("x-mfa-ticket", ticket.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataTransferServerOwnership {
+                    user: user.id.clone()
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let server: v0::Server = response.into_json().await.expect("`Server`");
+        assert_eq!(server.owner, user.id);
+    }
+}
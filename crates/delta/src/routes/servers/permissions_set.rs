@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, User,
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_server_permissions, ChannelPermission, Override};
@@ -16,6 +16,7 @@ pub async fn set_role_permission(
     db: &State<Database>,
     user: User,
     target: Reference,
+    reason: AuditLogReason,
     role_id: String,
     data: Json<v0::DataSetServerRolePermission>,
 ) -> Result<Json<v0::Server>> {
@@ -40,10 +41,22 @@ pub async fn set_role_permission(
             .throw_permission_override(current_value, &data.permissions)
             .await?;
 
+        let before = server.roles.get(&role_id).cloned();
         server
             .set_role_permission(db, &role_id, data.permissions.into())
             .await?;
 
+        AuditLogEntry::record(
+            db,
+            &server.id,
+            &user.id,
+            AuditLogAction::RoleUpdate,
+            Some(&role_id),
+            AuditLogChange::diff(before.as_ref(), server.roles.get(&role_id)),
+            reason.into_inner(),
+        )
+        .await;
+
         Ok(Json(server.into()))
     } else {
         Err(create_error!(NotFound))
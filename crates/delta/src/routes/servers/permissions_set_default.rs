@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, PartialServer, Server, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, PartialServer, Server, User,
 };
 use revolt_models::v0;
 use revolt_permissions::{
@@ -18,6 +18,7 @@ pub async fn set_default_permissions(
     db: &State<Database>,
     user: User,
     target: Reference,
+    reason: AuditLogReason,
     data: Json<DataPermissionsValue>,
 ) -> Result<Json<v0::Server>> {
     let data = data.into_inner();
@@ -39,6 +40,7 @@ pub async fn set_default_permissions(
         )
         .await?;
 
+    let before = server.clone();
     server
         .update(
             db,
@@ -50,5 +52,16 @@ pub async fn set_default_permissions(
         )
         .await?;
 
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::ServerUpdate,
+        Some(&server.id),
+        AuditLogChange::diff(Some(&before), Some(&server)),
+        reason.into_inner(),
+    )
+    .await;
+
     Ok(Json(server.into()))
 }
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, RoleAssignmentAction,
+    RoleAssignmentJob, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// Maximum number of members which can be listed explicitly
+static MAX_TARGET_MEMBERS: usize = 1000;
+
+/// # Bulk Assign Role
+///
+/// Assign a role to or remove a role from many members at once.
+///
+/// Members are updated in batches in the background, fetch the returned job to follow its progress.
+/// Members ranked at or above you are skipped.
+#[openapi(tag = "Server Permissions")]
+#[post("/<target>/roles/<role_id>/assignments", data = "<data>")]
+pub async fn assign_role_bulk(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    role_id: String,
+    reason: AuditLogReason,
+    data: Json<v0::DataCreateRoleAssignment>,
+) -> Result<Json<v0::RoleAssignmentJob>> {
+    let data = data.into_inner();
+    if let v0::RoleAssignmentTarget::Members { ids } = &data.target {
+        if ids.is_empty() || ids.len() > MAX_TARGET_MEMBERS {
+            return Err(create_error!(FailedValidation {
+                error: format!("must list between 1 and {MAX_TARGET_MEMBERS} members")
+            }));
+        }
+    }
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::AssignRoles)?;
+
+    // Check we can act on this role once, up front
+    let our_ranking = query.get_member_rank().unwrap_or(i64::MIN);
+    let role = server
+        .roles
+        .get(&role_id)
+        .ok_or_else(|| create_error!(InvalidRole))?;
+
+    if role.rank <= our_ranking {
+        return Err(create_error!(NotElevated));
+    }
+
+    let explicit: HashSet<&String> = match &data.target {
+        v0::RoleAssignmentTarget::Members { ids } => ids.iter().collect(),
+        _ => HashSet::new(),
+    };
+
+    let action: RoleAssignmentAction = data.action.into();
+    let members: Vec<String> = db
+        .fetch_all_members(&server.id)
+        .await?
+        .into_iter()
+        .filter(|member| match &data.target {
+            v0::RoleAssignmentTarget::Everyone => true,
+            v0::RoleAssignmentTarget::Role { id } => member.roles.contains(id),
+            v0::RoleAssignmentTarget::Members { .. } => explicit.contains(&member.id.user),
+        })
+        .filter(|member| member.id.user == user.id || member.get_ranking(&server) > our_ranking)
+        .filter(|member| action.changes(member, &role_id))
+        .map(|member| member.id.user)
+        .collect();
+
+    let count = members.len();
+    let job =
+        RoleAssignmentJob::create(db, &server.id, &role_id, &user.id, action, members).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::RoleAssignmentCreate,
+        Some(&role_id),
+        vec![AuditLogChange {
+            key: "members".to_string(),
+            before: None,
+            after: Some(count.to_string()),
+        }],
+        reason.into_inner(),
+    )
+    .await;
+
+    // Jobs interrupted before completing are resumed by crond
+    let db = db.inner().clone();
+    let runner = job.clone();
+    async_std::task::spawn(async move {
+        let id = runner.id.clone();
+        if let Err(err) = runner.run(&db).await {
+            log::error!("Role assignment job {id} failed: {err:?}");
+        }
+    });
+
+    Ok(Json(job.into()))
+}
@@ -0,0 +1,34 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Role Assignment Job
+///
+/// Fetch the progress of a bulk role assignment.
+#[openapi(tag = "Server Permissions")]
+#[get("/<target>/roles/<role_id>/assignments/<job_id>")]
+pub async fn fetch_role_assignment(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    role_id: String,
+    job_id: String,
+) -> Result<Json<v0::RoleAssignmentJob>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::AssignRoles)?;
+
+    let job = db.fetch_role_assignment_job(&job_id).await?;
+    if job.server != server.id || job.role != role_id {
+        return Err(create_error!(NotFound));
+    }
+
+    Ok(Json(job.into()))
+}
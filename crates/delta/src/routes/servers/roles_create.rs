@@ -1,7 +1,7 @@
 use revolt_config::config;
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, Role, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, Role, User,
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
@@ -18,6 +18,7 @@ pub async fn create(
     db: &State<Database>,
     user: User,
     target: Reference,
+    reason: AuditLogReason,
     data: Json<v0::DataCreateRole>,
 ) -> Result<Json<v0::NewRoleResponse>> {
     let data = data.into_inner();
@@ -57,10 +58,26 @@ pub async fn create(
         colour: None,
         hoist: false,
         permissions: Default::default(),
+        icon: None,
+        emoji: None,
+        mentionable: false,
     };
 
+    let id = role.create(db, &server.id).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::RoleCreate,
+        Some(&id),
+        AuditLogChange::diff(None, Some(&role)),
+        reason.into_inner(),
+    )
+    .await;
+
     Ok(Json(v0::NewRoleResponse {
-        id: role.create(db, &server.id).await?,
+        id,
         role: role.into(),
     }))
 }
@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, User,
 };
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
 use revolt_result::{create_error, Result};
@@ -16,6 +16,7 @@ pub async fn delete(
     db: &State<Database>,
     user: User,
     target: Reference,
+    reason: AuditLogReason,
     role_id: String,
 ) -> Result<EmptyResponse> {
     let mut server = target.as_server(db).await?;
@@ -31,9 +32,21 @@ pub async fn delete(
             return Err(create_error!(NotElevated));
         }
 
-        role.delete(db, &server.id, &role_id)
-            .await
-            .map(|_| EmptyResponse)
+        let changes = AuditLogChange::diff(Some(&role), None);
+        role.delete(db, &server.id, &role_id).await?;
+
+        AuditLogEntry::record(
+            db,
+            &server.id,
+            &user.id,
+            AuditLogAction::RoleDelete,
+            Some(&role_id),
+            changes,
+            reason.into_inner(),
+        )
+        .await;
+
+        Ok(EmptyResponse)
     } else {
         Err(create_error!(NotFound))
     }
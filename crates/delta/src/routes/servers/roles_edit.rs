@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, PartialRole, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, Emoji, File, PartialRole, User,
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
@@ -13,11 +13,12 @@ use validator::Validate;
 ///
 /// Edit a role by its id.
 #[openapi(tag = "Server Permissions")]
-#[patch("/<target>/roles/<role_id>", data = "<data>")]
+#[patch("/<target>/roles/<role_id>", data = "<data>", rank = 2)]
 pub async fn edit(
     db: &State<Database>,
     user: User,
     target: Reference,
+    reason: AuditLogReason,
     role_id: String,
     data: Json<v0::DataEditRole>,
 ) -> Result<Json<v0::Role>> {
@@ -46,10 +47,19 @@ pub async fn edit(
             name,
             colour,
             hoist,
+            icon,
+            emoji,
+            mentionable,
             rank,
             remove,
         } = data;
 
+        if let Some(emoji) = &emoji {
+            if !Emoji::is_unicode(emoji) {
+                return Err(create_error!(InvalidProperty));
+            }
+        }
+
         // Prevent us from moving a role above other roles
         if let Some(rank) = &rank {
             if rank <= &member_rank {
@@ -57,14 +67,35 @@ pub async fn edit(
             }
         }
 
-        let partial = PartialRole {
+        // Remove the existing icon if requested
+        if let Some(fields) = &remove {
+            if fields.contains(&v0::FieldsRole::Icon) {
+                if let Some(icon) = &role.icon {
+                    db.mark_attachment_as_deleted(&icon.id).await?;
+                }
+            }
+        }
+
+        let before = role.clone();
+        let mut partial = PartialRole {
             name,
             colour,
             hoist,
             rank,
+            emoji,
+            mentionable,
             ..Default::default()
         };
 
+        if let Some(icon) = icon {
+            partial.icon = Some(File::use_role_icon(db, &icon, &role_id, &user.id).await?);
+
+            // The new icon replaces the existing one
+            if let Some(icon) = &role.icon {
+                db.mark_attachment_as_deleted(&icon.id).await?;
+            }
+        }
+
         role.update(
             db,
             &server.id,
@@ -76,6 +107,17 @@ pub async fn edit(
         )
         .await?;
 
+        AuditLogEntry::record(
+            db,
+            &server.id,
+            &user.id,
+            AuditLogAction::RoleUpdate,
+            Some(&role_id),
+            AuditLogChange::diff(Some(&before), Some(&role)),
+            reason.into_inner(),
+        )
+        .await;
+
         Ok(Json(role.into()))
     } else {
         Err(create_error!(NotFound))
@@ -9,9 +9,9 @@ use rocket::{serde::json::Json, State};
 
 /// # Fetch Role
 ///
-/// Fetch a role by its id.
+/// Fetch a role by its id, including the number of members who have it.
 #[openapi(tag = "Server Permissions")]
-#[get("/<target>/roles/<role_id>")]
+#[get("/<target>/roles/<role_id>", rank = 2)]
 pub async fn fetch(
     db: &State<Database>,
     user: User,
@@ -27,7 +27,9 @@ pub async fn fetch(
     let role = server.roles.remove(&role_id);
 
     if let Some(role) = role {
-        Ok(Json(role.into()))
+        let mut role: v0::Role = role.into();
+        role.member_count = Some(db.fetch_role_member_count(&server.id, &role_id).await?);
+        Ok(Json(role))
     } else {
         Err(create_error!(NotFound))
     }
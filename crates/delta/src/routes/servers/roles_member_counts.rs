@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_permissions::PermissionQuery;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Role Member Counts
+///
+/// Fetch the number of members who have each role in a server.
+///
+/// Roles which no member has are omitted.
+#[openapi(tag = "Server Permissions")]
+#[get("/<target>/roles/member_counts", rank = 1)]
+pub async fn fetch_member_counts(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<HashMap<String, usize>>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    if !query.are_we_a_member().await {
+        return Err(create_error!(NotFound));
+    }
+
+    Ok(Json(
+        db.fetch_role_member_counts(&[server.id.clone()])
+            .await?
+            .remove(&server.id)
+            .unwrap_or_default(),
+    ))
+}
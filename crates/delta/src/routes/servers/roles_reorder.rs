@@ -0,0 +1,86 @@
+use std::collections::{HashMap, HashSet};
+
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Edit Role Ranks
+///
+/// Reorder several roles at once.
+///
+/// The given roles swap the ranks they already hold between them,
+/// so only roles below your own may be listed.
+#[openapi(tag = "Server Permissions")]
+#[patch("/<target>/roles/ranks", data = "<data>", rank = 1)]
+pub async fn reorder_roles(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    data: Json<v0::DataEditRoleRanks>,
+) -> Result<Json<v0::Server>> {
+    let data = data.into_inner();
+
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageRole)?;
+
+    let member_rank = query.get_member_rank().unwrap_or(i64::MIN);
+
+    let ids: HashSet<&String> = data.ranks.iter().collect();
+    if ids.len() != data.ranks.len() {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    let mut ranks = Vec::with_capacity(data.ranks.len());
+    for role_id in &data.ranks {
+        let role = server.roles.get(role_id).ok_or(create_error!(NotFound))?;
+
+        // Prevent us from moving roles above or equal to us
+        if role.rank <= member_rank {
+            return Err(create_error!(NotElevated));
+        }
+
+        ranks.push(role.rank);
+    }
+
+    ranks.sort_unstable();
+
+    let before = server.roles.clone();
+    server
+        .set_role_ranks(
+            db,
+            data.ranks
+                .iter()
+                .cloned()
+                .zip(ranks)
+                .collect::<HashMap<String, i64>>(),
+        )
+        .await?;
+
+    let reason = reason.into_inner();
+    for role_id in &data.ranks {
+        let changes = AuditLogChange::diff(before.get(role_id), server.roles.get(role_id));
+        if !changes.is_empty() {
+            AuditLogEntry::record(
+                db,
+                &server.id,
+                &user.id,
+                AuditLogAction::RoleUpdate,
+                Some(role_id),
+                changes,
+                reason.clone(),
+            )
+            .await;
+        }
+    }
+
+    Ok(Json(server.into()))
+}
@@ -8,7 +8,7 @@ use validator::Validate;
 
 /// # Create Server
 ///
-/// Create a new server.
+/// Create a new server, optionally from a template.
 #[openapi(tag = "Server Information")]
 #[post("/create", data = "<data>")]
 pub async fn create_server(
@@ -29,7 +29,12 @@ pub async fn create_server(
 
     user.can_acquire_server(db).await?;
 
-    let (server, channels) = Server::create(db, data, &user, true).await?;
+    let (server, channels) = if let Some(code) = &data.template {
+        let template = db.fetch_server_template(code).await?;
+        template.create_server(db, data, &user).await?
+    } else {
+        Server::create(db, data, &user, true).await?
+    };
     let (_, channels) = Member::create(db, &server, &user, Some(channels)).await?;
 
     Ok(Json(v0::CreateServerLegacyResponse {
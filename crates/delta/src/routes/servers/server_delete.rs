@@ -8,6 +8,9 @@ use rocket_empty::EmptyResponse;
 /// # Delete / Leave Server
 ///
 /// Deletes a server if owner otherwise leaves.
+///
+/// Deleted servers are hidden from members and may be restored by the owner
+/// until the grace period has passed, after which they are permanently removed.
 #[openapi(tag = "Server Information")]
 #[delete("/<target>?<options..>")]
 pub async fn delete(
@@ -16,11 +19,11 @@ pub async fn delete(
     target: Reference,
     options: v0::OptionsServerDelete,
 ) -> Result<EmptyResponse> {
-    let server = target.as_server(db).await?;
+    let mut server = target.as_server(db).await?;
     let member = db.fetch_member(&target.id, &user.id).await?;
 
     if server.owner == user.id {
-        server.delete(db).await
+        server.schedule_deletion(db).await
     } else {
         member
             .remove(
@@ -1,8 +1,8 @@
 use std::collections::HashSet;
 
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, File, PartialServer, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, File, PartialServer, User,
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_server_permissions, ChannelPermission};
@@ -19,6 +19,7 @@ pub async fn edit(
     db: &State<Database>,
     user: User,
     target: Reference,
+    reason: AuditLogReason,
     data: Json<v0::DataEditServer>,
 ) -> Result<Json<v0::Server>> {
     let data = data.into_inner();
@@ -38,11 +39,17 @@ pub async fn edit(
         && data.icon.is_none()
         && data.banner.is_none()
         && data.system_messages.is_none()
+        && data.rules.is_none()
+        && data.verification.is_none()
+        && data.locked.is_none()
         && data.categories.is_none()
         // && data.nsfw.is_none()
         && data.flags.is_none()
         && data.analytics.is_none()
         && data.discoverable.is_none()
+        && data.tags.is_none()
+        && data.category.is_none()
+        && data.language.is_none()
         && data.remove.is_none()
     {
         return Ok(Json(server.into()));
@@ -51,7 +58,13 @@ pub async fn edit(
         || data.icon.is_some()
         || data.banner.is_some()
         || data.system_messages.is_some()
+        || data.rules.is_some()
+        || data.verification.is_some()
+        || data.locked.is_some()
         || data.analytics.is_some()
+        || data.tags.is_some()
+        || data.category.is_some()
+        || data.language.is_some()
         || data.remove.is_some()
     {
         permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
@@ -64,6 +77,15 @@ pub async fn edit(
         return Err(create_error!(NotPrivileged));
     }
 
+    // Pending deletion may only be cancelled by restoring the server
+    if data
+        .remove
+        .as_ref()
+        .is_some_and(|fields| fields.contains(&v0::FieldsServer::ScheduledDeletion))
+    {
+        return Err(create_error!(InvalidOperation));
+    }
+
     // Changing categories requires manage channel
     if data.categories.is_some() {
         permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
@@ -76,10 +98,16 @@ pub async fn edit(
         banner,
         categories,
         system_messages,
+        rules,
+        verification,
+        locked,
         flags,
         // nsfw,
         discoverable,
         analytics,
+        tags,
+        category,
+        language,
         remove,
     } = data;
 
@@ -88,10 +116,16 @@ pub async fn edit(
         description,
         categories: categories.map(|v| v.into_iter().map(Into::into).collect()),
         system_messages: system_messages.map(Into::into),
+        rules,
+        verification: verification.map(Into::into),
+        locked,
         flags,
         // nsfw,
         discoverable,
         analytics,
+        tags,
+        category,
+        language,
         ..Default::default()
     };
 
@@ -119,6 +153,24 @@ pub async fn edit(
         }
     }
 
+    if let Some(tags) = &mut partial.tags {
+        let mut normalised: Vec<String> = vec![];
+        for tag in tags.iter() {
+            let tag = tag.trim().to_lowercase();
+            if tag.is_empty() || tag.len() > 24 {
+                return Err(create_error!(FailedValidation {
+                    error: "tags must be between 1 and 24 characters".to_string()
+                }));
+            }
+
+            if !normalised.contains(&tag) {
+                normalised.push(tag);
+            }
+        }
+
+        *tags = normalised;
+    }
+
     if let Some(categories) = &mut partial.categories {
         let mut channel_ids = HashSet::new();
         for category in categories {
@@ -133,9 +185,18 @@ pub async fn edit(
             category
                 .channels
                 .retain(|item| server.channels.contains(item));
+
+            // Permissions can only be changed through the category permission routes
+            let existing = server.category(&category.id);
+            category.default_permissions = existing.and_then(|c| c.default_permissions);
+            category.role_permissions = existing
+                .map(|c| c.role_permissions.clone())
+                .unwrap_or_default();
         }
     }
 
+    let before = server.clone();
+
     // 3. Apply new icon
     if let Some(icon) = icon {
         partial.icon = Some(File::use_server_icon(db, &icon, &server.id, &user.id).await?);
@@ -158,5 +219,16 @@ pub async fn edit(
         )
         .await?;
 
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::ServerUpdate,
+        Some(&server.id),
+        AuditLogChange::diff(Some(&before), Some(&server)),
+        reason.into_inner(),
+    )
+    .await;
+
     Ok(Json(server.into()))
 }
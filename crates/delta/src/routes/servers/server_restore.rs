@@ -0,0 +1,25 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Restore Server
+///
+/// Restore a server which was deleted and has yet to be permanently removed.
+///
+/// Only the server owner may restore a server.
+#[openapi(tag = "Server Information")]
+#[post("/<target>/restore")]
+pub async fn restore(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<v0::Server>> {
+    let mut server = target.as_server(db).await?;
+    if server.owner != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    server.restore(db).await?;
+    Ok(Json(server.into()))
+}
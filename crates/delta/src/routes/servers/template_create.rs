@@ -0,0 +1,38 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, ServerTemplate, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Create Template
+///
+/// Capture the server's roles, categories, channels and system message settings into a new template.
+#[openapi(tag = "Server Templates")]
+#[post("/<target>/templates", data = "<data>")]
+pub async fn create_template(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataCreateServerTemplate>,
+) -> Result<Json<v0::ServerTemplate>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    ServerTemplate::create(db, &server, &user, data.name, data.description)
+        .await
+        .map(|template| Json(template.into()))
+}
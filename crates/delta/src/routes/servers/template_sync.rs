@@ -0,0 +1,34 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Sync Template
+///
+/// Update a template with the server's current structure.
+#[openapi(tag = "Server Templates")]
+#[put("/<target>/templates/<code>")]
+pub async fn sync_template(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    code: String,
+) -> Result<Json<v0::ServerTemplate>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let mut template = db.fetch_server_template(&code).await?;
+    if template.server_id != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    template.sync(db, &server).await?;
+    Ok(Json(template.into()))
+}
@@ -0,0 +1,42 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, Invite, User,
+};
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Delete Vanity Invite
+///
+/// Release this server's custom invite code.
+#[openapi(tag = "Server Information")]
+#[delete("/<target>/vanity")]
+pub async fn delete_vanity(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let invite = Invite::fetch_vanity(db, &server).await?;
+    db.delete_invite(invite.code()).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::VanityInviteDelete,
+        Some(invite.code()),
+        AuditLogChange::diff(Some(&invite), None),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(EmptyResponse)
+}
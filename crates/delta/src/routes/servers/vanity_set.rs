@@ -0,0 +1,51 @@
+use revolt_database::{
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, Invite, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Set Vanity Invite
+///
+/// Claim a custom invite code for this server, replacing any existing one.
+#[openapi(tag = "Server Information")]
+#[put("/<target>/vanity", data = "<data>")]
+pub async fn set_vanity(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+    data: Json<v0::DataSetVanityInvite>,
+) -> Result<Json<v0::Invite>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let previous = Invite::fetch_vanity(db, &server).await.ok();
+    let invite = Invite::set_vanity(db, &server, &user, &data.channel, data.code).await?;
+
+    AuditLogEntry::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::VanityInviteUpdate,
+        Some(invite.code()),
+        AuditLogChange::diff(previous.as_ref(), Some(&invite)),
+        reason.into_inner(),
+    )
+    .await;
+
+    Ok(Json(invite.into()))
+}
@@ -0,0 +1,16 @@
+use revolt_database::{Database, User};
+use revolt_models::v0;
+use revolt_result::Result;
+use rocket::serde::json::Json;
+use rocket::State;
+
+/// # Fetch Notification Settings
+///
+/// Fetch your notification preferences for servers and channels.
+#[openapi(tag = "Sync")]
+#[get("/notifications")]
+pub async fn fetch(db: &State<Database>, user: User) -> Result<Json<v0::NotificationSettings>> {
+    db.fetch_notification_settings(&user.id)
+        .await
+        .map(|settings| Json(settings.into()))
+}
@@ -1,10 +1,18 @@
 use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
 use rocket::Route;
 
+mod get_notifications;
 mod get_settings;
 mod get_unreads;
+mod set_notifications;
 mod set_settings;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
-    openapi_get_routes_spec![get_settings::fetch, set_settings::set, get_unreads::unreads]
+    openapi_get_routes_spec![
+        get_settings::fetch,
+        set_settings::set,
+        get_unreads::unreads,
+        get_notifications::fetch,
+        set_notifications::edit
+    ]
 }
@@ -0,0 +1,102 @@
+use revolt_database::{Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// Maximum number of servers and channels a user may have preferences for
+const MAX_NOTIFICATION_SETTINGS: usize = 1_000;
+
+/// # Edit Notification Settings
+///
+/// Change notification preferences for servers and channels.
+///
+/// Only the given servers and channels are changed, setting a preference to null removes it.
+#[openapi(tag = "Sync")]
+#[patch("/notifications", data = "<data>")]
+pub async fn edit(
+    db: &State<Database>,
+    user: User,
+    data: Json<v0::DataEditNotificationSettings>,
+) -> Result<Json<v0::NotificationSettings>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let mut settings = db.fetch_notification_settings(&user.id).await?;
+
+    for (id, preference) in data.servers {
+        if let Some(preference) = preference {
+            settings.servers.insert(id, preference.into());
+        } else {
+            settings.servers.remove(&id);
+        }
+    }
+
+    for (id, preference) in data.channels {
+        if let Some(preference) = preference {
+            settings.channels.insert(id, preference.into());
+        } else {
+            settings.channels.remove(&id);
+        }
+    }
+
+    if settings.servers.len() + settings.channels.len() > MAX_NOTIFICATION_SETTINGS {
+        return Err(create_error!(TooManyNotificationSettings {
+            max: MAX_NOTIFICATION_SETTINGS
+        }));
+    }
+
+    settings.clone().set(db).await?;
+    Ok(Json(settings.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn edit_notification_settings() {
+        let harness = TestHarness::new().await;
+        let (_, session, _) = harness.new_user().await;
+
+        // Preferences must be keyed by ids
+        let response = harness
+            .client
+            .patch("/sync/notifications")
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(json!({ "channels": { "not an id": { "level": "All" } } }).to_string())
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+        drop(response);
+
+        let channel = ulid::Ulid::new().to_string();
+        let response = harness
+            .client
+            .patch("/sync/notifications")
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(json!({ "channels": { &channel: { "level": "All" } } }).to_string())
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let settings: v0::NotificationSettings =
+            response.into_json().await.expect("`NotificationSettings`");
+        assert_eq!(
+            settings
+                .channels
+                .get(&channel)
+                .and_then(|p| p.level.clone()),
+            Some(v0::NotificationLevel::All)
+        );
+    }
+}
@@ -4,31 +4,136 @@ use revolt_database::{
 };
 use revolt_models::v0;
 use revolt_permissions::{calculate_user_permissions, UserPermission};
-use revolt_result::Result;
+use revolt_result::{create_error, Result};
 use rocket::{serde::json::Json, State};
 
 /// # Fetch User Profile
 ///
 /// Retrieve a user's profile data.
 ///
+/// If a server is given, the user's profile within that server is included.
+///
 /// Will fail if you do not have permission to access the other user's profile.
 #[openapi(tag = "User Information")]
-#[get("/<target>/profile")]
+#[get("/<target>/profile?<options..>")]
 pub async fn profile(
     db: &State<Database>,
     user: User,
     target: Reference,
+    options: v0::OptionsFetchProfile,
 ) -> Result<Json<v0::UserProfile>> {
-    if user.id == target.id {
-        return Ok(Json(user.profile.map(Into::into).unwrap_or_default()));
+    let (target_id, profile) = if user.id == target.id {
+        (user.id.clone(), user.profile.clone())
+    } else {
+        let target = target.as_user(db).await?;
+
+        let mut query = DatabasePermissionQuery::new(db, &user).user(&target);
+        calculate_user_permissions(&mut query)
+            .await
+            .throw_if_lacking_user_permission(UserPermission::ViewProfile)?;
+
+        (target.id, target.profile)
+    };
+
+    let mut profile: v0::UserProfile = profile.map(Into::into).unwrap_or_default();
+
+    if let Some(server) = options.server {
+        // Only members may view profiles within a server
+        db.fetch_member(&server, &user.id)
+            .await
+            .map_err(|_| create_error!(NotFound))?;
+
+        profile.member = db
+            .fetch_member(&server, &target_id)
+            .await
+            .ok()
+            .map(Into::into);
     }
 
-    let target = target.as_user(db).await?;
+    Ok(Json(profile))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Member, PartialMember, Server};
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+
+    #[rocket::async_test]
+    async fn fetch_server_profile() {
+        let harness = TestHarness::new().await;
+        let (_, session, owner) = harness.new_user().await;
+        let (_, _, user) = harness.new_user().await;
+        let (_, other_session, other) = harness.new_user().await;
 
-    let mut query = DatabasePermissionQuery::new(db, &user).user(&target);
-    calculate_user_permissions(&mut query)
+        let (server, channels) = Server::create(
+            &harness.db,
+            v0::DataCreateServer {
+                name: "Test Server".to_string(),
+                ..Default::default()
+            },
+            &owner,
+            true,
+        )
         .await
-        .throw_if_lacking_user_permission(UserPermission::ViewProfile)?;
+        .expect("Failed to create test server");
 
-    Ok(Json(target.profile.map(Into::into).unwrap_or_default()))
+        Member::create(&harness.db, &server, &owner, Some(channels.clone()))
+            .await
+            .expect("Failed to create owner member");
+        let (mut member, _) = Member::create(&harness.db, &server, &user, Some(channels))
+            .await
+            .expect("Failed to create member");
+
+        member
+            .update(
+                &harness.db,
+                PartialMember {
+                    bio: Some("Server bio".to_string()),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to update member");
+
+        let response = harness
+            .client
+            .get(format!("/users/{}/profile?server={}", user.id, server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let profile: v0::UserProfile = response.into_json().await.expect("`UserProfile`");
+        assert_eq!(
+            profile.member.and_then(|member| member.bio).as_deref(),
+            Some("Server bio")
+        );
+
+        let response = harness
+            .client
+            .get(format!("/users/{}/profile", user.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let profile: v0::UserProfile = response.into_json().await.expect("`UserProfile`");
+        assert!(profile.member.is_none());
+
+        // Only members may view profiles within a server
+        let response = harness
+            .client
+            .get(format!("/users/{}/profile?server={}", other.id, server.id))
+            .header(Header::new(
+                "x-session-token",
+                other_session.token.to_string(),
+            ))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NotFound);
+    }
 }
@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, User,
 };
 use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
 use revolt_result::Result;
@@ -16,6 +16,7 @@ pub async fn webhook_delete(
     db: &State<Database>,
     user: User,
     webhook_id: Reference,
+    reason: AuditLogReason,
 ) -> Result<EmptyResponse> {
     let webhook = webhook_id.as_webhook(db).await?;
     let channel = db.fetch_channel(&webhook.channel_id).await?;
@@ -25,5 +26,20 @@ pub async fn webhook_delete(
         .await
         .throw_if_lacking_channel_permission(ChannelPermission::ManageWebhooks)?;
 
-    webhook.delete(db).await.map(|_| EmptyResponse)
+    webhook.delete(db).await?;
+
+    if let Some(server) = channel.server() {
+        AuditLogEntry::record(
+            db,
+            server,
+            &user.id,
+            AuditLogAction::WebhookDelete,
+            Some(&webhook.id),
+            AuditLogChange::diff(Some(&webhook), None),
+            reason.into_inner(),
+        )
+        .await;
+    }
+
+    Ok(EmptyResponse)
 }
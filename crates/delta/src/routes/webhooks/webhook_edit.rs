@@ -1,6 +1,6 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Database, File, PartialWebhook, User,
+    util::{audit_log::AuditLogReason, permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogAction, AuditLogChange, AuditLogEntry, Database, File, PartialWebhook, User,
 };
 use revolt_models::v0::{DataEditWebhook, Webhook};
 use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
@@ -17,6 +17,7 @@ pub async fn webhook_edit(
     db: &State<Database>,
     webhook_id: Reference,
     user: User,
+    reason: AuditLogReason,
     data: Json<DataEditWebhook>,
 ) -> Result<Json<Webhook>> {
     let data = data.into_inner();
@@ -56,9 +57,23 @@ pub async fn webhook_edit(
         partial.avatar = Some(file)
     }
 
+    let before = webhook.clone();
     webhook
         .update(db, partial, remove.into_iter().map(|v| v.into()).collect())
         .await?;
 
+    if let Some(server) = channel.server() {
+        AuditLogEntry::record(
+            db,
+            server,
+            &user.id,
+            AuditLogAction::WebhookUpdate,
+            Some(&webhook.id),
+            AuditLogChange::diff(Some(&before), Some(&webhook)),
+            reason.into_inner(),
+        )
+        .await;
+    }
+
     Ok(Json(webhook.into()))
 }
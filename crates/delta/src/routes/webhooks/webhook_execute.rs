@@ -54,6 +54,10 @@ pub async fn webhook_execute(
 
     let channel = db.fetch_channel(&webhook.channel_id).await?;
 
+    if channel.is_archived() {
+        return Err(create_error!(ChannelArchived));
+    }
+
     Ok(Json(
         Message::create_from_api(
             db,
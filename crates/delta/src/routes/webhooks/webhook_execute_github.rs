@@ -760,6 +760,11 @@ pub async fn webhook_execute_github(
     webhook.assert_token(&token)?;
 
     let channel = db.fetch_channel(&webhook.channel_id).await?;
+
+    if channel.is_archived() {
+        return Err(create_error!(ChannelArchived));
+    }
+
     let event = convert_event(&data, &event)?;
 
     let sendable_embed = match event.event {
@@ -1089,3 +1094,71 @@ pub async fn webhook_execute_github(
         )
         .await
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{PartialChannel, Server, Webhook};
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+
+    #[rocket::async_test]
+    async fn reject_archived_channel() {
+        let harness = TestHarness::new().await;
+        let (_, _, user) = harness.new_user().await;
+
+        let (_, mut channels) = Server::create(
+            &harness.db,
+            v0::DataCreateServer {
+                name: "Test Server".to_string(),
+                ..Default::default()
+            },
+            &user,
+            true,
+        )
+        .await
+        .expect("Failed to create test server");
+
+        let channel = &mut channels[0];
+        channel
+            .update(
+                &harness.db,
+                PartialChannel {
+                    archived: Some(true),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to archive channel");
+
+        let webhook = Webhook {
+            id: TestHarness::rand_string(),
+            name: "Test Webhook".to_string(),
+            avatar: None,
+            creator_id: user.id.clone(),
+            channel_id: channel.id().to_string(),
+            permissions: u64::MAX,
+            token: Some(TestHarness::rand_string()),
+        };
+
+        webhook
+            .create(&harness.db)
+            .await
+            .expect("Failed to create webhook");
+
+        let response = harness
+            .client
+            .post(format!(
+                "/webhooks/{}/{}/github",
+                webhook.id,
+                webhook.token.as_ref().unwrap()
+            ))
+            .header(Header::new("X-GitHub-Event", "star"))
+            .body("{}")
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+    }
+}
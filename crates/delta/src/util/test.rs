@@ -1,5 +1,5 @@
 use authifier::{
-    models::{Account, Session},
+    models::{Account, MFATicket, Session},
     Authifier,
 };
 use futures::StreamExt;
@@ -104,6 +104,12 @@ impl TestHarness {
         (account, session, user)
     }
 
+    pub async fn new_mfa_ticket(&self, account: &Account) -> MFATicket {
+        let ticket = MFATicket::new(account.id.to_string(), true);
+        ticket.save(&self.authifier).await.expect("`MFATicket`");
+        ticket
+    }
+
     pub async fn wait_for_event<F>(&mut self, topic: &str, predicate: F) -> EventV1
     where
         F: Fn(&EventV1) -> bool,
@@ -119,6 +119,7 @@ pub enum Tag {
     icons,
     banners,
     emojis,
+    role_icons,
 }
 
 /// Request body for upload
@@ -149,6 +150,7 @@ pub struct UploadResponse {
 /// | icons | 2.5 MB | 40 MP or 10,000px | Image |
 /// | banners | 6 MB | 40 MP or 10,000px | Image |
 /// | emojis | 500 KB | 40 MP or 10,000px | Image |
+/// | role_icons | 500 KB | 40 MP or 10,000px | Image |
 #[utoipa::path(
     post,
     path = "/{tag}",
@@ -340,6 +342,7 @@ pub static CACHE_CONTROL: &str = "public, max-age=604800, must-revalidate";
 /// | icons | Up to 128px on any axis | ✅ |
 /// | banners | Up to 480px on any axis | ❌ |
 /// | emojis | Up to 128px on any axis | ❌ |
+/// | role_icons | Up to 64px on any axis | ✅ |
 ///
 /// <sup>†</sup> aspect ratio will always be preserved
 ///
@@ -378,7 +381,7 @@ async fn fetch_preview(
 
     // Only process image files and don't process GIFs if not avatar or icon
     if !matches!(hash.metadata, Metadata::Image { .. })
-        || (is_animated && !matches!(tag, Tag::avatars | Tag::icons))
+        || (is_animated && !matches!(tag, Tag::avatars | Tag::icons | Tag::role_icons))
     {
         return Ok(
             Redirect::permanent(&format!("/{tag_str}/{file_id}/{}", file.filename)).into_response(),